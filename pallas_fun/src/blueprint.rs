@@ -0,0 +1,549 @@
+// CIP-57 Plutus blueprint (plutus.json) loader
+// https://cips.cardano.org/cip/CIP-0057
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use pallas::ledger::primitives::conway::ScriptRef;
+use pallas::ledger::primitives::{BigInt, Constr, Fragment, PlutusData};
+use pallas::ledger::traverse::ComputeHash;
+use serde::Deserialize;
+
+use crate::error::PallasFunError;
+pub use crate::plutus::PlutusVersion;
+use crate::utils::{IntoInner, read_file};
+use crate::wrapper::{DatumOptionKind, DatumOptionWrapper, ScriptRefKind, ScriptRefWrapper};
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Compiler {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Preamble {
+    pub title: String,
+    pub description: Option<String>,
+    pub version: String,
+    pub plutus_version: Option<PlutusVersion>,
+    pub compiler: Option<Compiler>,
+    pub license: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DataType {
+    #[serde(rename = "integer")]
+    Integer,
+    #[serde(rename = "bytes")]
+    Bytes,
+    #[serde(rename = "list")]
+    List,
+    #[serde(rename = "map")]
+    Map,
+    #[serde(rename = "constructor")]
+    Constructor,
+    // builtin (non-data) types, only meaningful for raw UPLC arguments
+    #[serde(rename = "#unit")]
+    BuiltinUnit,
+    #[serde(rename = "#boolean")]
+    BuiltinBoolean,
+    #[serde(rename = "#integer")]
+    BuiltinInteger,
+    #[serde(rename = "#bytes")]
+    BuiltinBytes,
+    #[serde(rename = "#string")]
+    BuiltinString,
+    #[serde(rename = "#pair")]
+    BuiltinPair,
+    #[serde(rename = "#list")]
+    BuiltinList,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum SchemaItems {
+    One(Box<Schema>),
+    Tuple(Vec<Schema>),
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Schema {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub data_type: Option<DataType>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+
+    pub any_of: Option<Vec<Schema>>,
+    pub one_of: Option<Vec<Schema>>,
+    pub all_of: Option<Vec<Schema>>,
+    pub not: Option<Box<Schema>>,
+
+    // constructor
+    pub index: Option<u64>,
+    pub fields: Option<Vec<Schema>>,
+
+    // list / map
+    pub items: Option<SchemaItems>,
+    pub keys: Option<Box<Schema>>,
+    pub values: Option<Box<Schema>>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub unique_items: Option<bool>,
+
+    // integer
+    pub multiple_of: Option<i128>,
+    pub minimum: Option<i128>,
+    pub maximum: Option<i128>,
+    pub exclusive_minimum: Option<i128>,
+    pub exclusive_maximum: Option<i128>,
+
+    // bytes
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<String>>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Argument {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub schema: Schema,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlueprintValidator {
+    pub title: String,
+    pub description: Option<String>,
+    pub datum: Option<Argument>,
+    pub redeemer: Option<Argument>,
+    #[serde(default)]
+    pub parameters: Vec<Argument>,
+    pub compiled_code: Option<String>,
+    pub hash: Option<String>,
+    // copied from the preamble on load, validators do not carry it themselves
+    #[serde(skip)]
+    pub plutus_version: Option<PlutusVersion>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Blueprint {
+    pub preamble: Preamble,
+    pub validators: Vec<BlueprintValidator>,
+    #[serde(default)]
+    pub definitions: BTreeMap<String, Schema>,
+}
+
+impl Blueprint {
+    pub fn from_json(json: &str) -> Result<Self, PallasFunError> {
+        let mut blueprint: Blueprint =
+            serde_json::from_str(json).map_err(|e| PallasFunError::Json(e.to_string()))?;
+        for validator in blueprint.validators.iter_mut() {
+            validator.plutus_version = blueprint.preamble.plutus_version;
+        }
+        Ok(blueprint)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, PallasFunError> {
+        Self::from_json(&read_file(path)?)
+    }
+
+    pub fn validator(&self, title: &str) -> Option<&BlueprintValidator> {
+        self.validators.iter().find(|v| v.title == title)
+    }
+
+    // resolves a `#/definitions/...` JSON pointer
+    pub fn resolve(&self, reference: &str) -> Result<&Schema, PallasFunError> {
+        let key = reference
+            .strip_prefix("#/definitions/")
+            .ok_or_else(|| PallasFunError::SchemaReference(reference.to_string()))?
+            .replace("~1", "/")
+            .replace("~0", "~");
+        self.definitions
+            .get(&key)
+            .ok_or_else(|| PallasFunError::SchemaReference(reference.to_string()))
+    }
+
+    pub fn validate(&self, schema: &Schema, data: &PlutusData) -> Result<(), PallasFunError> {
+        if let Some(reference) = &schema.reference {
+            return self.validate(self.resolve(reference)?, data);
+        }
+
+        if let Some(any_of) = &schema.any_of
+            && !any_of.iter().any(|s| self.validate(s, data).is_ok())
+        {
+            return Err(PallasFunError::SchemaMismatch(
+                match data_constructor_index(data) {
+                    Some(index) => format!("no anyOf alternative matches constructor {}", index),
+                    None => "no anyOf alternative matches".to_string(),
+                },
+            ));
+        }
+        if let Some(one_of) = &schema.one_of {
+            let matches = one_of
+                .iter()
+                .filter(|s| self.validate(s, data).is_ok())
+                .count();
+            if matches != 1 {
+                return Err(PallasFunError::SchemaMismatch(format!(
+                    "expected exactly one oneOf alternative to match, {} matched",
+                    matches
+                )));
+            }
+        }
+        if let Some(all_of) = &schema.all_of {
+            for s in all_of {
+                self.validate(s, data)?;
+            }
+        }
+        if let Some(not) = &schema.not
+            && self.validate(not, data).is_ok()
+        {
+            return Err(PallasFunError::SchemaMismatch(
+                "value matches a `not` schema".to_string(),
+            ));
+        }
+
+        match schema.data_type {
+            // no data type means opaque `Data`
+            None => Ok(()),
+            Some(DataType::Integer) => self.validate_integer(schema, data),
+            Some(DataType::Bytes) => self.validate_bytes(schema, data),
+            Some(DataType::List) => self.validate_list(schema, data),
+            Some(DataType::Map) => self.validate_map(schema, data),
+            Some(DataType::Constructor) => self.validate_constructor(schema, data),
+            Some(builtin) => Err(PallasFunError::SchemaMismatch(format!(
+                "builtin type {:?} cannot be represented as PlutusData",
+                builtin
+            ))),
+        }
+    }
+
+    pub fn validate_datum(
+        &self,
+        validator: &BlueprintValidator,
+        data: &PlutusData,
+    ) -> Result<(), PallasFunError> {
+        let datum = validator.datum.as_ref().ok_or_else(|| {
+            PallasFunError::MissingField(format!("datum of validator {}", validator.title))
+        })?;
+        self.validate(&datum.schema, data)
+    }
+
+    pub fn validate_redeemer(
+        &self,
+        validator: &BlueprintValidator,
+        data: &PlutusData,
+    ) -> Result<(), PallasFunError> {
+        let redeemer = validator.redeemer.as_ref().ok_or_else(|| {
+            PallasFunError::MissingField(format!("redeemer of validator {}", validator.title))
+        })?;
+        self.validate(&redeemer.schema, data)
+    }
+
+    pub fn validate_parameter(
+        &self,
+        validator: &BlueprintValidator,
+        index: usize,
+        data: &PlutusData,
+    ) -> Result<(), PallasFunError> {
+        let parameter = validator.parameters.get(index).ok_or_else(|| {
+            PallasFunError::MissingField(format!(
+                "parameter {} of validator {}",
+                index, validator.title
+            ))
+        })?;
+        self.validate(&parameter.schema, data)
+    }
+
+    // validates against the validator's datum schema before building an inline datum
+    pub fn inline_datum(
+        &self,
+        validator: &BlueprintValidator,
+        data: &PlutusData,
+    ) -> Result<DatumOptionWrapper, PallasFunError> {
+        self.validate_datum(validator, data)?;
+        let bytes = data
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        DatumOptionWrapper::new(DatumOptionKind::Data {
+            plutus_data_hex: hex::encode(bytes),
+        })
+    }
+
+    fn validate_integer(&self, schema: &Schema, data: &PlutusData) -> Result<(), PallasFunError> {
+        let value = match data {
+            PlutusData::BigInt(BigInt::Int(int)) => i128::from(*int),
+            // bignums are out of range of any bound a blueprint can express
+            PlutusData::BigInt(BigInt::BigUInt(_)) => i128::MAX,
+            PlutusData::BigInt(BigInt::BigNInt(_)) => i128::MIN,
+            _ => {
+                return Err(PallasFunError::SchemaMismatch(
+                    "expected integer".to_string(),
+                ));
+            }
+        };
+        if let Some(multiple_of) = schema.multiple_of
+            && multiple_of != 0
+            && value % multiple_of != 0
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "integer {} is not a multiple of {}",
+                value, multiple_of
+            )));
+        }
+        if let Some(minimum) = schema.minimum
+            && value < minimum
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "integer {} is below minimum {}",
+                value, minimum
+            )));
+        }
+        if let Some(maximum) = schema.maximum
+            && value > maximum
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "integer {} is above maximum {}",
+                value, maximum
+            )));
+        }
+        if let Some(exclusive_minimum) = schema.exclusive_minimum
+            && value <= exclusive_minimum
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "integer {} is not above exclusive minimum {}",
+                value, exclusive_minimum
+            )));
+        }
+        if let Some(exclusive_maximum) = schema.exclusive_maximum
+            && value >= exclusive_maximum
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "integer {} is not below exclusive maximum {}",
+                value, exclusive_maximum
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_bytes(&self, schema: &Schema, data: &PlutusData) -> Result<(), PallasFunError> {
+        let PlutusData::BoundedBytes(bytes) = data else {
+            return Err(PallasFunError::SchemaMismatch("expected bytes".to_string()));
+        };
+        if let Some(min_length) = schema.min_length
+            && bytes.len() < min_length
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "expected at least {} bytes, found {}",
+                min_length,
+                bytes.len()
+            )));
+        }
+        if let Some(max_length) = schema.max_length
+            && bytes.len() > max_length
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "expected at most {} bytes, found {}",
+                max_length,
+                bytes.len()
+            )));
+        }
+        if let Some(enum_values) = &schema.enum_values {
+            let hex_bytes = hex::encode(bytes.as_slice());
+            if !enum_values
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(&hex_bytes))
+            {
+                return Err(PallasFunError::SchemaMismatch(format!(
+                    "bytes {} are not one of the allowed values",
+                    hex_bytes
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_list(&self, schema: &Schema, data: &PlutusData) -> Result<(), PallasFunError> {
+        let PlutusData::Array(items) = data else {
+            return Err(PallasFunError::SchemaMismatch("expected list".to_string()));
+        };
+        Self::check_item_count(schema, items.len())?;
+        match &schema.items {
+            Some(SchemaItems::One(item_schema)) => {
+                for item in items.iter() {
+                    self.validate(item_schema, item)?;
+                }
+            }
+            Some(SchemaItems::Tuple(item_schemas)) => {
+                if item_schemas.len() != items.len() {
+                    return Err(PallasFunError::SchemaMismatch(format!(
+                        "expected tuple of {} elements, found {}",
+                        item_schemas.len(),
+                        items.len()
+                    )));
+                }
+                for (item_schema, item) in item_schemas.iter().zip(items.iter()) {
+                    self.validate(item_schema, item)?;
+                }
+            }
+            None => {}
+        }
+        if schema.unique_items == Some(true) {
+            let mut seen = HashSet::new();
+            for item in items.iter() {
+                let bytes = item
+                    .encode_fragment()
+                    .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+                if !seen.insert(bytes) {
+                    return Err(PallasFunError::SchemaMismatch(
+                        "expected unique list items".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_map(&self, schema: &Schema, data: &PlutusData) -> Result<(), PallasFunError> {
+        let PlutusData::Map(entries) = data else {
+            return Err(PallasFunError::SchemaMismatch("expected map".to_string()));
+        };
+        Self::check_item_count(schema, entries.len())?;
+        for (key, value) in entries.iter() {
+            if let Some(keys) = &schema.keys {
+                self.validate(keys, key)?;
+            }
+            if let Some(values) = &schema.values {
+                self.validate(values, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_constructor(
+        &self,
+        schema: &Schema,
+        data: &PlutusData,
+    ) -> Result<(), PallasFunError> {
+        let PlutusData::Constr(constr) = data else {
+            return Err(PallasFunError::SchemaMismatch(
+                "expected constructor".to_string(),
+            ));
+        };
+        let index = constructor_index(constr).ok_or_else(|| {
+            PallasFunError::SchemaMismatch(format!("invalid constructor tag {}", constr.tag))
+        })?;
+        if let Some(expected) = schema.index
+            && index != expected
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "expected constructor index {}, found {}",
+                expected, index
+            )));
+        }
+        if let Some(fields) = &schema.fields {
+            if fields.len() != constr.fields.len() {
+                return Err(PallasFunError::SchemaMismatch(format!(
+                    "expected {} constructor fields, found {}",
+                    fields.len(),
+                    constr.fields.len()
+                )));
+            }
+            for (field_schema, field) in fields.iter().zip(constr.fields.iter()) {
+                self.validate(field_schema, field)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_item_count(schema: &Schema, count: usize) -> Result<(), PallasFunError> {
+        if let Some(min_items) = schema.min_items
+            && count < min_items
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "expected at least {} items, found {}",
+                min_items, count
+            )));
+        }
+        if let Some(max_items) = schema.max_items
+            && count > max_items
+        {
+            return Err(PallasFunError::SchemaMismatch(format!(
+                "expected at most {} items, found {}",
+                max_items, count
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl BlueprintValidator {
+    pub fn to_script_ref(&self) -> Result<ScriptRefWrapper, PallasFunError> {
+        let compiled_code = self.compiled_code.clone().ok_or_else(|| {
+            PallasFunError::MissingField(format!("compiled code of validator {}", self.title))
+        })?;
+        let plutus_version = self.plutus_version.ok_or_else(|| {
+            PallasFunError::MissingField(format!("plutus version of validator {}", self.title))
+        })?;
+
+        ScriptRefWrapper::new(match plutus_version {
+            PlutusVersion::V1 => ScriptRefKind::PlutusV1Script {
+                plutus_v1_script: compiled_code,
+            },
+            PlutusVersion::V2 => ScriptRefKind::PlutusV2Script {
+                plutus_v2_script: compiled_code,
+            },
+            PlutusVersion::V3 => ScriptRefKind::PlutusV3Script {
+                plutus_v3_script: compiled_code,
+            },
+        })
+    }
+
+    // script hash computed from the compiled code, not the `hash` field
+    pub fn script_hash(&self) -> Result<String, PallasFunError> {
+        let hash = match self.to_script_ref()?.into_inner() {
+            ScriptRef::PlutusV1Script(script) => script.compute_hash(),
+            ScriptRef::PlutusV2Script(script) => script.compute_hash(),
+            ScriptRef::PlutusV3Script(script) => script.compute_hash(),
+            ScriptRef::NativeScript(script) => script.compute_hash(),
+        };
+        Ok(hash.to_string())
+    }
+
+    // checks the declared `hash` against the compiled code
+    pub fn verify_hash(&self) -> Result<(), PallasFunError> {
+        let computed = self.script_hash()?;
+        match &self.hash {
+            Some(declared) if declared.eq_ignore_ascii_case(&computed) => Ok(()),
+            Some(declared) => Err(PallasFunError::ScriptHashMismatch {
+                declared: declared.clone(),
+                computed,
+            }),
+            None => Err(PallasFunError::MissingField(format!(
+                "hash of validator {}",
+                self.title
+            ))),
+        }
+    }
+}
+
+fn constructor_index(constr: &Constr<PlutusData>) -> Option<u64> {
+    match constr.tag {
+        121..=127 => Some(constr.tag - 121),
+        1280..=1400 => Some(constr.tag - 1280 + 7),
+        102 => constr.any_constructor,
+        _ => None,
+    }
+}
+
+fn data_constructor_index(data: &PlutusData) -> Option<u64> {
+    match data {
+        PlutusData::Constr(constr) => constructor_index(constr),
+        _ => None,
+    }
+}
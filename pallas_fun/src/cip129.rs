@@ -0,0 +1,183 @@
+// CIP-129 bech32 ids for governance credentials and actions, plus the
+// headerless CIP-105 forms they replace
+use bech32::{FromBase32, ToBase32, Variant};
+use pallas::crypto::hash::Hash;
+use pallas::ledger::primitives::conway::GovActionId;
+use pallas::ledger::primitives::{PoolKeyhash, StakeCredential};
+
+use crate::error::PallasFunError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GovCredentialRole {
+    CommitteeHot,
+    CommitteeCold,
+    DRep,
+}
+
+impl GovCredentialRole {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::CommitteeHot => "cc_hot",
+            Self::CommitteeCold => "cc_cold",
+            Self::DRep => "drep",
+        }
+    }
+
+    // high nibble of the CIP-129 header
+    fn key_type(&self) -> u8 {
+        match self {
+            Self::CommitteeHot => 0b0000,
+            Self::CommitteeCold => 0b0001,
+            Self::DRep => 0b0010,
+        }
+    }
+}
+
+// low nibble of the CIP-129 header
+const KEY_HASH: u8 = 0b0010;
+const SCRIPT_HASH: u8 = 0b0011;
+
+pub fn encode_credential(role: GovCredentialRole, credential: &StakeCredential) -> String {
+    let (credential_type, hash) = match credential {
+        StakeCredential::AddrKeyhash(hash) => (KEY_HASH, hash),
+        StakeCredential::ScriptHash(hash) => (SCRIPT_HASH, hash),
+    };
+    let mut bytes = vec![role.key_type() << 4 | credential_type];
+    bytes.extend_from_slice(hash.as_ref());
+    encode(role.prefix(), &bytes)
+}
+
+// CIP-105: `drep1`, `cc_hot1`, `cc_cold1` for key hashes and `_script1` for
+// script hashes, no header byte
+pub fn encode_legacy_credential(role: GovCredentialRole, credential: &StakeCredential) -> String {
+    match credential {
+        StakeCredential::AddrKeyhash(hash) => encode(role.prefix(), hash.as_ref()),
+        StakeCredential::ScriptHash(hash) => {
+            encode(&format!("{}_script", role.prefix()), hash.as_ref())
+        }
+    }
+}
+
+// accepts the CIP-129 form and the CIP-105 `drep1`, `drep_vkh1` and
+// `drep_script1` forms (and their committee equivalents)
+pub fn decode_credential(
+    role: GovCredentialRole,
+    bech32: &str,
+) -> Result<StakeCredential, PallasFunError> {
+    let (hrp, bytes) = decode(bech32)?;
+    let prefix = role.prefix();
+    let credential = if hrp == prefix && bytes.len() == 29 {
+        if bytes[0] >> 4 != role.key_type() {
+            return Err(PallasFunError::Bech32(format!(
+                "header {:#04x} is not a {} credential",
+                bytes[0], prefix
+            )));
+        }
+        match bytes[0] & 0x0f {
+            KEY_HASH => StakeCredential::AddrKeyhash(hash_28(&bytes[1..])?),
+            SCRIPT_HASH => StakeCredential::ScriptHash(hash_28(&bytes[1..])?),
+            other => {
+                return Err(PallasFunError::Bech32(format!(
+                    "unknown credential type {:#03x}",
+                    other
+                )));
+            }
+        }
+    } else if hrp == prefix || hrp == format!("{}_vkh", prefix) {
+        StakeCredential::AddrKeyhash(hash_28(&bytes)?)
+    } else if hrp == format!("{}_script", prefix) {
+        StakeCredential::ScriptHash(hash_28(&bytes)?)
+    } else {
+        return Err(PallasFunError::Bech32(format!(
+            "unexpected prefix {}, expected {}",
+            hrp, prefix
+        )));
+    };
+    Ok(credential)
+}
+
+// transaction id followed by the index as a single byte, or two big-endian
+// bytes once it no longer fits
+pub fn encode_gov_action_id(id: &GovActionId) -> Result<String, PallasFunError> {
+    let mut bytes = id.transaction_id.to_vec();
+    match u8::try_from(id.action_index) {
+        Ok(index) => bytes.push(index),
+        Err(_) => {
+            let index = u16::try_from(id.action_index)
+                .map_err(|_| PallasFunError::Overflow("gov action index"))?;
+            bytes.extend_from_slice(&index.to_be_bytes());
+        }
+    }
+    Ok(encode("gov_action", &bytes))
+}
+
+pub fn decode_gov_action_id(bech32: &str) -> Result<GovActionId, PallasFunError> {
+    let (hrp, bytes) = decode(bech32)?;
+    if hrp != "gov_action" {
+        return Err(PallasFunError::Bech32(format!(
+            "unexpected prefix {}, expected gov_action",
+            hrp
+        )));
+    }
+    let action_index = match &bytes[32.min(bytes.len())..] {
+        [index] => *index as u32,
+        // an index below 256 has a single canonical byte
+        [0, low] => {
+            return Err(PallasFunError::Bech32(format!(
+                "non-canonical gov action index {}, expected a single byte",
+                low
+            )));
+        }
+        [high, low] => u16::from_be_bytes([*high, *low]) as u32,
+        _ => {
+            return Err(PallasFunError::InvalidLength {
+                kind: "gov action id",
+                expected: 33,
+                actual: bytes.len(),
+            });
+        }
+    };
+    Ok(GovActionId {
+        transaction_id: Hash::from(&bytes[..32]),
+        action_index,
+    })
+}
+
+// CIP-5 `pool1...`, pool voters are not covered by CIP-129
+pub fn encode_pool_id(pool: &PoolKeyhash) -> String {
+    encode("pool", pool.as_ref())
+}
+
+pub fn decode_pool_id(bech32: &str) -> Result<PoolKeyhash, PallasFunError> {
+    let (hrp, bytes) = decode(bech32)?;
+    if hrp != "pool" {
+        return Err(PallasFunError::Bech32(format!(
+            "unexpected prefix {}, expected pool",
+            hrp
+        )));
+    }
+    hash_28(&bytes)
+}
+
+fn encode(hrp: &str, bytes: &[u8]) -> String {
+    // only fails on invalid prefixes, ours are fixed
+    bech32::encode(hrp, bytes.to_base32(), Variant::Bech32).expect("valid bech32 prefix")
+}
+
+fn decode(bech32: &str) -> Result<(String, Vec<u8>), PallasFunError> {
+    let (hrp, data, _) =
+        bech32::decode(bech32).map_err(|e| PallasFunError::Bech32(e.to_string()))?;
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| PallasFunError::Bech32(e.to_string()))?;
+    Ok((hrp, bytes))
+}
+
+fn hash_28(bytes: &[u8]) -> Result<Hash<28>, PallasFunError> {
+    if bytes.len() != 28 {
+        return Err(PallasFunError::InvalidLength {
+            kind: "credential",
+            expected: 28,
+            actual: bytes.len(),
+        });
+    }
+    Ok(Hash::from(bytes))
+}
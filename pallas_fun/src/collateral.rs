@@ -0,0 +1,402 @@
+// Collateral selection and collateral-return computation
+use std::collections::BTreeMap;
+
+use pallas::ledger::addresses::{Address, ShelleyPaymentPart};
+use pallas::ledger::primitives::conway::{PseudoTransactionOutput, TransactionOutput, Value};
+use pallas::ledger::primitives::{AssetName, PolicyId, alonzo};
+
+use crate::error::PallasFunError;
+use crate::utils::IntoInner;
+use crate::wrapper::{
+    MultiassetPositiveCoinWrapper, TransactionInputWrapper, TransactionOutputWrapper, ValueWrapper,
+};
+
+// `collateral`, `collateral_return` and `total_collateral` arguments of
+// `TransactionBodyWrapper::new`, computed together so they agree.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CollateralSelection {
+    pub collateral: Vec<TransactionInputWrapper>,
+    pub collateral_return: Option<TransactionOutputWrapper>,
+    pub total_collateral: u64,
+}
+
+// collateral_percentage of the fee, rounded up
+pub fn required_collateral(fee: u64, collateral_percentage: u64) -> u64 {
+    let required = (fee as u128 * collateral_percentage as u128).div_ceil(100);
+    u64::try_from(required).unwrap_or(u64::MAX)
+}
+
+// Picks ada-only, key-locked UTxOs (largest first, to stay within
+// `max_collateral_inputs`) and computes the matching return output. Inputs
+// are added until the excess over `required` is either zero or enough for a
+// return output; if the inputs run out first, the dust is collateral too.
+pub fn select_collateral(
+    utxos: &[(TransactionInputWrapper, TransactionOutputWrapper)],
+    fee: u64,
+    collateral_percentage: u64,
+    max_collateral_inputs: u64,
+    ada_per_utxo_byte: u64,
+    return_address: String,
+) -> Result<CollateralSelection, PallasFunError> {
+    let required = required_collateral(fee, collateral_percentage);
+
+    let mut candidates: Vec<(&TransactionInputWrapper, &TransactionOutputWrapper, u64)> = utxos
+        .iter()
+        .filter_map(|(input, output)| {
+            let output_inner = output.into_inner();
+            match (ada_only_coin(&output_inner), is_key_locked(&output_inner)) {
+                (Some(coin), true) => Some((input, output, coin)),
+                _ => None,
+            }
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(b.0)));
+
+    let mut selected = Vec::new();
+    let mut selected_coin: u64 = 0;
+    for (input, output, coin) in candidates {
+        if selected_coin >= required
+            && !selected.is_empty()
+            && return_fits(&return_address, selected_coin - required, ada_per_utxo_byte)?
+        {
+            break;
+        }
+        if selected.len() as u64 >= max_collateral_inputs {
+            break;
+        }
+        selected.push((input.clone(), output.clone()));
+        selected_coin = selected_coin
+            .checked_add(coin)
+            .ok_or(PallasFunError::Overflow("collateral amount"))?;
+    }
+
+    if selected_coin < required {
+        return Err(PallasFunError::InsufficientCollateral {
+            required,
+            available: selected_coin,
+        });
+    }
+
+    collateral_with_return(&selected, required, ada_per_utxo_byte, return_address)
+}
+
+// Builds the collateral return for already chosen collateral inputs: every
+// token and all ada above `required` goes back to `return_address`. The return
+// must meet the min-UTxO rule; ada-only dust below it is added to
+// `total_collateral` instead, while tokens without enough ada are an error.
+pub fn collateral_with_return(
+    collateral: &[(TransactionInputWrapper, TransactionOutputWrapper)],
+    required: u64,
+    ada_per_utxo_byte: u64,
+    return_address: String,
+) -> Result<CollateralSelection, PallasFunError> {
+    let mut coin: u64 = 0;
+    let mut tokens: BTreeMap<(PolicyId, AssetName), u64> = BTreeMap::new();
+
+    for (_, output) in collateral {
+        let (output_coin, output_tokens) = output_value(&output.into_inner());
+        coin = coin
+            .checked_add(output_coin)
+            .ok_or(PallasFunError::Overflow("collateral amount"))?;
+        for (key, amount) in output_tokens {
+            let total = tokens.entry(key).or_insert(0);
+            *total = total
+                .checked_add(amount)
+                .ok_or(PallasFunError::Overflow("collateral token amount"))?;
+        }
+    }
+
+    if coin < required {
+        return Err(PallasFunError::InsufficientCollateral {
+            required,
+            available: coin,
+        });
+    }
+
+    let return_coin = coin - required;
+    let (collateral_return, total_collateral) = if return_coin == 0 && tokens.is_empty() {
+        (None, required)
+    } else {
+        let output = return_output(&return_address, return_coin, tokens.clone())?;
+        if return_coin >= output.min_ada(ada_per_utxo_byte)? {
+            (Some(output), required)
+        } else if tokens.is_empty() {
+            (None, coin)
+        } else {
+            let min_return = min_return_coin(&return_address, tokens, ada_per_utxo_byte)?;
+            return Err(PallasFunError::InsufficientCollateral {
+                required: required.saturating_add(min_return),
+                available: coin,
+            });
+        }
+    };
+
+    Ok(CollateralSelection {
+        collateral: collateral.iter().map(|(input, _)| input.clone()).collect(),
+        collateral_return,
+        total_collateral,
+    })
+}
+
+fn return_output(
+    return_address: &str,
+    coin: u64,
+    tokens: BTreeMap<(PolicyId, AssetName), u64>,
+) -> Result<TransactionOutputWrapper, PallasFunError> {
+    let multiasset_wrapper = if tokens.is_empty() {
+        None
+    } else {
+        let mut grouped: BTreeMap<PolicyId, Vec<(String, u64)>> = BTreeMap::new();
+        for ((policy_id, asset_name), amount) in tokens {
+            grouped
+                .entry(policy_id)
+                .or_default()
+                .push((hex::encode(asset_name.to_vec()), amount));
+        }
+        Some(MultiassetPositiveCoinWrapper::new(
+            grouped
+                .into_iter()
+                .map(|(policy_id, assets)| (policy_id.to_string(), assets))
+                .collect(),
+        )?)
+    };
+    TransactionOutputWrapper::new(
+        return_address.to_string(),
+        ValueWrapper::new(coin, multiasset_wrapper),
+        None,
+        None,
+    )
+}
+
+// whether an ada-only excess of `coin` needs no return output or can have one
+fn return_fits(
+    return_address: &str,
+    coin: u64,
+    ada_per_utxo_byte: u64,
+) -> Result<bool, PallasFunError> {
+    if coin == 0 {
+        return Ok(true);
+    }
+    let output = return_output(return_address, coin, BTreeMap::new())?;
+    Ok(coin >= output.min_ada(ada_per_utxo_byte)?)
+}
+
+// Smallest coin for which the return output meets the min-UTxO rule; the
+// coin's own encoded size feeds back into the minimum, so iterate until stable.
+fn min_return_coin(
+    return_address: &str,
+    tokens: BTreeMap<(PolicyId, AssetName), u64>,
+    ada_per_utxo_byte: u64,
+) -> Result<u64, PallasFunError> {
+    let mut coin = 0;
+    loop {
+        let min =
+            return_output(return_address, coin, tokens.clone())?.min_ada(ada_per_utxo_byte)?;
+        if min <= coin {
+            return Ok(coin);
+        }
+        coin = min;
+    }
+}
+
+type AssetAmounts = Vec<((PolicyId, AssetName), u64)>;
+
+fn output_value(output: &TransactionOutput) -> (u64, AssetAmounts) {
+    match output {
+        PseudoTransactionOutput::PostAlonzo(o) => match &o.value {
+            Value::Coin(coin) => (*coin, Vec::new()),
+            Value::Multiasset(coin, multiasset) => (
+                *coin,
+                multiasset
+                    .iter()
+                    .flat_map(|(policy_id, assets)| {
+                        assets.iter().map(move |(asset_name, amount)| {
+                            ((*policy_id, asset_name.clone()), u64::from(amount))
+                        })
+                    })
+                    .collect(),
+            ),
+        },
+        PseudoTransactionOutput::Legacy(o) => match &o.amount {
+            alonzo::Value::Coin(coin) => (*coin, Vec::new()),
+            alonzo::Value::Multiasset(coin, multiasset) => (
+                *coin,
+                multiasset
+                    .iter()
+                    .flat_map(|(policy_id, assets)| {
+                        assets.iter().map(move |(asset_name, amount)| {
+                            ((*policy_id, asset_name.clone()), *amount)
+                        })
+                    })
+                    .collect(),
+            ),
+        },
+    }
+}
+
+fn ada_only_coin(output: &TransactionOutput) -> Option<u64> {
+    match output_value(output) {
+        (coin, tokens) if tokens.is_empty() => Some(coin),
+        _ => None,
+    }
+}
+
+// collateral must be spendable by a key witness
+fn is_key_locked(output: &TransactionOutput) -> bool {
+    let address = match output {
+        PseudoTransactionOutput::PostAlonzo(o) => &o.address,
+        PseudoTransactionOutput::Legacy(o) => &o.address,
+    };
+    match Address::from_bytes(address) {
+        Ok(Address::Shelley(shelley)) => matches!(shelley.payment(), ShelleyPaymentPart::Key(_)),
+        Ok(Address::Byron(_)) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // enterprise addresses (header 0x61 key, 0x71 script) on mainnet
+    const KEY_ADDRESS: &str = "61276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+    const SCRIPT_ADDRESS: &str = "71276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+    const ADA_PER_UTXO_BYTE: u64 = 4_310;
+
+    fn utxo(
+        index: u64,
+        address: &str,
+        coin: u64,
+        tokens: Option<MultiassetPositiveCoinWrapper>,
+    ) -> (TransactionInputWrapper, TransactionOutputWrapper) {
+        (
+            TransactionInputWrapper::new(
+                "aabbccddeeff00112233445566778899aabbccddeeff00112233445566778899",
+                index,
+            )
+            .unwrap(),
+            TransactionOutputWrapper::new(
+                address.to_string(),
+                ValueWrapper::new(coin, tokens),
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_select_collateral_skips_tokens_and_scripts() {
+        let tokens = MultiassetPositiveCoinWrapper::new(vec![(
+            "00000000000000000000000000000000000000000000000000000000".to_string(),
+            vec![("".to_string(), 1)],
+        )])
+        .unwrap();
+        let utxos = vec![
+            utxo(0, KEY_ADDRESS, 50_000_000, Some(tokens)),
+            utxo(1, SCRIPT_ADDRESS, 40_000_000, None),
+            utxo(2, KEY_ADDRESS, 1_000_000, None),
+            utxo(3, KEY_ADDRESS, 2_000_000, None),
+        ];
+
+        // 499_998 left over from the 2 ada input is below the min-UTxO, so the
+        // 1 ada input is added as well
+        let selection = select_collateral(
+            &utxos,
+            1_000_001,
+            150,
+            3,
+            ADA_PER_UTXO_BYTE,
+            KEY_ADDRESS.to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(selection.total_collateral, 1_500_002);
+        assert_eq!(
+            selection.collateral,
+            vec![utxos[3].0.clone(), utxos[2].0.clone()]
+        );
+        let expected_return = TransactionOutputWrapper::new(
+            KEY_ADDRESS.to_string(),
+            ValueWrapper::new(1_499_998, None),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(selection.collateral_return, Some(expected_return));
+    }
+
+    #[test]
+    fn test_select_collateral_respects_max_inputs() {
+        let utxos = vec![
+            utxo(0, KEY_ADDRESS, 1_000_000, None),
+            utxo(1, KEY_ADDRESS, 1_000_000, None),
+        ];
+        let select = |max_inputs| {
+            select_collateral(
+                &utxos,
+                1_000_000,
+                150,
+                max_inputs,
+                ADA_PER_UTXO_BYTE,
+                KEY_ADDRESS.to_string(),
+            )
+        };
+        assert!(select(1).is_err());
+        assert!(select(2).is_ok());
+    }
+
+    #[test]
+    fn test_select_collateral_folds_dust() {
+        // one input allowed, the 100_000 lovelace excess cannot be returned
+        let utxos = vec![utxo(0, KEY_ADDRESS, 1_600_000, None)];
+        let selection = select_collateral(
+            &utxos,
+            1_000_000,
+            150,
+            1,
+            ADA_PER_UTXO_BYTE,
+            KEY_ADDRESS.to_string(),
+        )
+        .unwrap();
+        assert_eq!(selection.collateral_return, None);
+        assert_eq!(selection.total_collateral, 1_600_000);
+    }
+
+    #[test]
+    fn test_collateral_return_tokens_need_ada() {
+        let tokens = MultiassetPositiveCoinWrapper::new(vec![(
+            "00000000000000000000000000000000000000000000000000000000".to_string(),
+            vec![("".to_string(), 1)],
+        )])
+        .unwrap();
+        let collateral = vec![utxo(0, KEY_ADDRESS, 1_500_000, Some(tokens))];
+
+        let err = collateral_with_return(
+            &collateral,
+            1_500_000,
+            ADA_PER_UTXO_BYTE,
+            KEY_ADDRESS.to_string(),
+        )
+        .unwrap_err();
+        let PallasFunError::InsufficientCollateral {
+            required,
+            available,
+        } = err
+        else {
+            panic!("unexpected error {:?}", err);
+        };
+        assert_eq!(available, 1_500_000);
+        assert!(required > 1_500_000 + 1_000_000);
+
+        let selection = collateral_with_return(
+            &collateral,
+            100_000,
+            ADA_PER_UTXO_BYTE,
+            KEY_ADDRESS.to_string(),
+        )
+        .unwrap();
+        assert_eq!(selection.total_collateral, 100_000);
+        assert!(selection.collateral_return.is_some());
+    }
+}
@@ -0,0 +1,837 @@
+// Canonical Plutus cost model parameter names, so the bare arrays of
+// `CostModelsWrapper` can be read, built and compared by name
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::PallasFunError;
+pub use crate::plutus::PlutusVersion;
+use crate::wrapper::CostModelsWrapper;
+
+impl PlutusVersion {
+    // parameter names in cost model order, as of protocol version 10
+    pub fn parameter_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::V1 => &PLUTUS_V1_PARAMETER_NAMES,
+            Self::V2 => &PLUTUS_V2_PARAMETER_NAMES,
+            Self::V3 => &PLUTUS_V3_PARAMETER_NAMES,
+        }
+    }
+}
+
+// Same shape as the `costModels` object of cardano-cli and the `cost_models`
+// object of Blockfrost
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct NamedCostModels {
+    #[serde(rename = "PlutusV1", default, skip_serializing_if = "Option::is_none")]
+    pub plutus_v1: Option<BTreeMap<String, i64>>,
+    #[serde(rename = "PlutusV2", default, skip_serializing_if = "Option::is_none")]
+    pub plutus_v2: Option<BTreeMap<String, i64>>,
+    #[serde(rename = "PlutusV3", default, skip_serializing_if = "Option::is_none")]
+    pub plutus_v3: Option<BTreeMap<String, i64>>,
+}
+
+// `None` on one side when the parameter is beyond the end of that cost model
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct CostModelChange {
+    pub version: PlutusVersion,
+    pub name: &'static str,
+    pub current: Option<i64>,
+    pub proposed: Option<i64>,
+}
+
+// Names the values in cost model order. Models from before a hard fork that
+// extended them (e.g. the 175 PlutusV2 parameters before protocol version 10)
+// are a prefix of the canonical list, so only extra values are rejected.
+pub fn named_cost_model(
+    version: PlutusVersion,
+    values: &[i64],
+) -> Result<BTreeMap<String, i64>, PallasFunError> {
+    let names = version.parameter_names();
+    if values.len() > names.len() {
+        return Err(PallasFunError::CostModelLength {
+            language: version.name(),
+            expected: names.len(),
+            actual: values.len(),
+        });
+    }
+    Ok(names
+        .iter()
+        .zip(values)
+        .map(|(name, value)| (name.to_string(), *value))
+        .collect())
+}
+
+// Values in cost model order. The names must form a prefix of the canonical
+// list, anything else is a missing or unknown parameter.
+pub fn cost_model_from_named(
+    version: PlutusVersion,
+    named: &BTreeMap<String, i64>,
+) -> Result<Vec<i64>, PallasFunError> {
+    let names = version.parameter_names();
+    if let Some(unknown) = named.keys().find(|name| !names.contains(&name.as_str())) {
+        return Err(PallasFunError::UnknownParameter(unknown.clone()));
+    }
+    let values = &names[..named.len()];
+    values
+        .iter()
+        .map(|name| {
+            named
+                .get(*name)
+                .copied()
+                .ok_or_else(|| PallasFunError::MissingField(name.to_string()))
+        })
+        .collect()
+}
+
+// exactly as many values as the current protocol version has parameters
+pub fn check_cost_model_length(
+    version: PlutusVersion,
+    values: &[i64],
+) -> Result<(), PallasFunError> {
+    let expected = version.parameter_names().len();
+    if values.len() != expected {
+        return Err(PallasFunError::CostModelLength {
+            language: version.name(),
+            expected,
+            actual: values.len(),
+        });
+    }
+    Ok(())
+}
+
+impl CostModelsWrapper {
+    fn models(&self) -> [(PlutusVersion, &Option<Vec<i64>>); 3] {
+        [
+            (PlutusVersion::V1, &self.plutus_v1),
+            (PlutusVersion::V2, &self.plutus_v2),
+            (PlutusVersion::V3, &self.plutus_v3),
+        ]
+    }
+
+    pub fn to_named(&self) -> Result<NamedCostModels, PallasFunError> {
+        let named = |version: PlutusVersion, values: &Option<Vec<i64>>| {
+            values
+                .as_ref()
+                .map(|values| named_cost_model(version, values).map_err(|e| e.at(version.name())))
+                .transpose()
+        };
+        Ok(NamedCostModels {
+            plutus_v1: named(PlutusVersion::V1, &self.plutus_v1)?,
+            plutus_v2: named(PlutusVersion::V2, &self.plutus_v2)?,
+            plutus_v3: named(PlutusVersion::V3, &self.plutus_v3)?,
+        })
+    }
+
+    pub fn from_named(named: &NamedCostModels) -> Result<Self, PallasFunError> {
+        let values = |version: PlutusVersion, named: &Option<BTreeMap<String, i64>>| {
+            named
+                .as_ref()
+                .map(|named| {
+                    cost_model_from_named(version, named).map_err(|e| e.at(version.name()))
+                })
+                .transpose()
+        };
+        Ok(Self {
+            plutus_v1: values(PlutusVersion::V1, &named.plutus_v1)?,
+            plutus_v2: values(PlutusVersion::V2, &named.plutus_v2)?,
+            plutus_v3: values(PlutusVersion::V3, &named.plutus_v3)?,
+        })
+    }
+
+    // Changed parameters by name. A language missing from `proposed` is left
+    // as is, the same way a parameter update merges cost models.
+    pub fn diff(&self, proposed: &Self) -> Result<Vec<CostModelChange>, PallasFunError> {
+        let mut changes = Vec::new();
+        for ((version, current), (_, proposed)) in self.models().into_iter().zip(proposed.models())
+        {
+            let Some(proposed) = proposed else {
+                continue;
+            };
+            let current = current.as_deref().unwrap_or_default();
+            let names = version.parameter_names();
+            let len = current.len().max(proposed.len());
+            if len > names.len() {
+                return Err(PallasFunError::CostModelLength {
+                    language: version.name(),
+                    expected: names.len(),
+                    actual: len,
+                }
+                .at(version.name()));
+            }
+            for (index, name) in names.iter().take(len).enumerate() {
+                let (current, proposed) = (current.get(index), proposed.get(index));
+                if current != proposed {
+                    changes.push(CostModelChange {
+                        version,
+                        name,
+                        current: current.copied(),
+                        proposed: proposed.copied(),
+                    });
+                }
+            }
+        }
+        Ok(changes)
+    }
+}
+
+pub const PLUTUS_V1_PARAMETER_NAMES: [&str; 166] = [
+    "addInteger-cpu-arguments-intercept",
+    "addInteger-cpu-arguments-slope",
+    "addInteger-memory-arguments-intercept",
+    "addInteger-memory-arguments-slope",
+    "appendByteString-cpu-arguments-intercept",
+    "appendByteString-cpu-arguments-slope",
+    "appendByteString-memory-arguments-intercept",
+    "appendByteString-memory-arguments-slope",
+    "appendString-cpu-arguments-intercept",
+    "appendString-cpu-arguments-slope",
+    "appendString-memory-arguments-intercept",
+    "appendString-memory-arguments-slope",
+    "bData-cpu-arguments",
+    "bData-memory-arguments",
+    "blake2b_256-cpu-arguments-intercept",
+    "blake2b_256-cpu-arguments-slope",
+    "blake2b_256-memory-arguments",
+    "cekApplyCost-exBudgetCPU",
+    "cekApplyCost-exBudgetMemory",
+    "cekBuiltinCost-exBudgetCPU",
+    "cekBuiltinCost-exBudgetMemory",
+    "cekConstCost-exBudgetCPU",
+    "cekConstCost-exBudgetMemory",
+    "cekDelayCost-exBudgetCPU",
+    "cekDelayCost-exBudgetMemory",
+    "cekForceCost-exBudgetCPU",
+    "cekForceCost-exBudgetMemory",
+    "cekLamCost-exBudgetCPU",
+    "cekLamCost-exBudgetMemory",
+    "cekStartupCost-exBudgetCPU",
+    "cekStartupCost-exBudgetMemory",
+    "cekVarCost-exBudgetCPU",
+    "cekVarCost-exBudgetMemory",
+    "chooseData-cpu-arguments",
+    "chooseData-memory-arguments",
+    "chooseList-cpu-arguments",
+    "chooseList-memory-arguments",
+    "chooseUnit-cpu-arguments",
+    "chooseUnit-memory-arguments",
+    "consByteString-cpu-arguments-intercept",
+    "consByteString-cpu-arguments-slope",
+    "consByteString-memory-arguments-intercept",
+    "consByteString-memory-arguments-slope",
+    "constrData-cpu-arguments",
+    "constrData-memory-arguments",
+    "decodeUtf8-cpu-arguments-intercept",
+    "decodeUtf8-cpu-arguments-slope",
+    "decodeUtf8-memory-arguments-intercept",
+    "decodeUtf8-memory-arguments-slope",
+    "divideInteger-cpu-arguments-constant",
+    "divideInteger-cpu-arguments-model-arguments-intercept",
+    "divideInteger-cpu-arguments-model-arguments-slope",
+    "divideInteger-memory-arguments-intercept",
+    "divideInteger-memory-arguments-minimum",
+    "divideInteger-memory-arguments-slope",
+    "encodeUtf8-cpu-arguments-intercept",
+    "encodeUtf8-cpu-arguments-slope",
+    "encodeUtf8-memory-arguments-intercept",
+    "encodeUtf8-memory-arguments-slope",
+    "equalsByteString-cpu-arguments-constant",
+    "equalsByteString-cpu-arguments-intercept",
+    "equalsByteString-cpu-arguments-slope",
+    "equalsByteString-memory-arguments",
+    "equalsData-cpu-arguments-intercept",
+    "equalsData-cpu-arguments-slope",
+    "equalsData-memory-arguments",
+    "equalsInteger-cpu-arguments-intercept",
+    "equalsInteger-cpu-arguments-slope",
+    "equalsInteger-memory-arguments",
+    "equalsString-cpu-arguments-constant",
+    "equalsString-cpu-arguments-intercept",
+    "equalsString-cpu-arguments-slope",
+    "equalsString-memory-arguments",
+    "fstPair-cpu-arguments",
+    "fstPair-memory-arguments",
+    "headList-cpu-arguments",
+    "headList-memory-arguments",
+    "iData-cpu-arguments",
+    "iData-memory-arguments",
+    "ifThenElse-cpu-arguments",
+    "ifThenElse-memory-arguments",
+    "indexByteString-cpu-arguments",
+    "indexByteString-memory-arguments",
+    "lengthOfByteString-cpu-arguments",
+    "lengthOfByteString-memory-arguments",
+    "lessThanByteString-cpu-arguments-intercept",
+    "lessThanByteString-cpu-arguments-slope",
+    "lessThanByteString-memory-arguments",
+    "lessThanEqualsByteString-cpu-arguments-intercept",
+    "lessThanEqualsByteString-cpu-arguments-slope",
+    "lessThanEqualsByteString-memory-arguments",
+    "lessThanEqualsInteger-cpu-arguments-intercept",
+    "lessThanEqualsInteger-cpu-arguments-slope",
+    "lessThanEqualsInteger-memory-arguments",
+    "lessThanInteger-cpu-arguments-intercept",
+    "lessThanInteger-cpu-arguments-slope",
+    "lessThanInteger-memory-arguments",
+    "listData-cpu-arguments",
+    "listData-memory-arguments",
+    "mapData-cpu-arguments",
+    "mapData-memory-arguments",
+    "mkCons-cpu-arguments",
+    "mkCons-memory-arguments",
+    "mkNilData-cpu-arguments",
+    "mkNilData-memory-arguments",
+    "mkNilPairData-cpu-arguments",
+    "mkNilPairData-memory-arguments",
+    "mkPairData-cpu-arguments",
+    "mkPairData-memory-arguments",
+    "modInteger-cpu-arguments-constant",
+    "modInteger-cpu-arguments-model-arguments-intercept",
+    "modInteger-cpu-arguments-model-arguments-slope",
+    "modInteger-memory-arguments-intercept",
+    "modInteger-memory-arguments-minimum",
+    "modInteger-memory-arguments-slope",
+    "multiplyInteger-cpu-arguments-intercept",
+    "multiplyInteger-cpu-arguments-slope",
+    "multiplyInteger-memory-arguments-intercept",
+    "multiplyInteger-memory-arguments-slope",
+    "nullList-cpu-arguments",
+    "nullList-memory-arguments",
+    "quotientInteger-cpu-arguments-constant",
+    "quotientInteger-cpu-arguments-model-arguments-intercept",
+    "quotientInteger-cpu-arguments-model-arguments-slope",
+    "quotientInteger-memory-arguments-intercept",
+    "quotientInteger-memory-arguments-minimum",
+    "quotientInteger-memory-arguments-slope",
+    "remainderInteger-cpu-arguments-constant",
+    "remainderInteger-cpu-arguments-model-arguments-intercept",
+    "remainderInteger-cpu-arguments-model-arguments-slope",
+    "remainderInteger-memory-arguments-intercept",
+    "remainderInteger-memory-arguments-minimum",
+    "remainderInteger-memory-arguments-slope",
+    "sha2_256-cpu-arguments-intercept",
+    "sha2_256-cpu-arguments-slope",
+    "sha2_256-memory-arguments",
+    "sha3_256-cpu-arguments-intercept",
+    "sha3_256-cpu-arguments-slope",
+    "sha3_256-memory-arguments",
+    "sliceByteString-cpu-arguments-intercept",
+    "sliceByteString-cpu-arguments-slope",
+    "sliceByteString-memory-arguments-intercept",
+    "sliceByteString-memory-arguments-slope",
+    "sndPair-cpu-arguments",
+    "sndPair-memory-arguments",
+    "subtractInteger-cpu-arguments-intercept",
+    "subtractInteger-cpu-arguments-slope",
+    "subtractInteger-memory-arguments-intercept",
+    "subtractInteger-memory-arguments-slope",
+    "tailList-cpu-arguments",
+    "tailList-memory-arguments",
+    "trace-cpu-arguments",
+    "trace-memory-arguments",
+    "unBData-cpu-arguments",
+    "unBData-memory-arguments",
+    "unConstrData-cpu-arguments",
+    "unConstrData-memory-arguments",
+    "unIData-cpu-arguments",
+    "unIData-memory-arguments",
+    "unListData-cpu-arguments",
+    "unListData-memory-arguments",
+    "unMapData-cpu-arguments",
+    "unMapData-memory-arguments",
+    "verifyEd25519Signature-cpu-arguments-intercept",
+    "verifyEd25519Signature-cpu-arguments-slope",
+    "verifyEd25519Signature-memory-arguments",
+];
+
+// PlutusV1 plus the Vasil builtins, then the integer/bytestring conversions of
+// protocol version 10
+pub const PLUTUS_V2_PARAMETER_NAMES: [&str; 185] = [
+    "addInteger-cpu-arguments-intercept",
+    "addInteger-cpu-arguments-slope",
+    "addInteger-memory-arguments-intercept",
+    "addInteger-memory-arguments-slope",
+    "appendByteString-cpu-arguments-intercept",
+    "appendByteString-cpu-arguments-slope",
+    "appendByteString-memory-arguments-intercept",
+    "appendByteString-memory-arguments-slope",
+    "appendString-cpu-arguments-intercept",
+    "appendString-cpu-arguments-slope",
+    "appendString-memory-arguments-intercept",
+    "appendString-memory-arguments-slope",
+    "bData-cpu-arguments",
+    "bData-memory-arguments",
+    "blake2b_256-cpu-arguments-intercept",
+    "blake2b_256-cpu-arguments-slope",
+    "blake2b_256-memory-arguments",
+    "cekApplyCost-exBudgetCPU",
+    "cekApplyCost-exBudgetMemory",
+    "cekBuiltinCost-exBudgetCPU",
+    "cekBuiltinCost-exBudgetMemory",
+    "cekConstCost-exBudgetCPU",
+    "cekConstCost-exBudgetMemory",
+    "cekDelayCost-exBudgetCPU",
+    "cekDelayCost-exBudgetMemory",
+    "cekForceCost-exBudgetCPU",
+    "cekForceCost-exBudgetMemory",
+    "cekLamCost-exBudgetCPU",
+    "cekLamCost-exBudgetMemory",
+    "cekStartupCost-exBudgetCPU",
+    "cekStartupCost-exBudgetMemory",
+    "cekVarCost-exBudgetCPU",
+    "cekVarCost-exBudgetMemory",
+    "chooseData-cpu-arguments",
+    "chooseData-memory-arguments",
+    "chooseList-cpu-arguments",
+    "chooseList-memory-arguments",
+    "chooseUnit-cpu-arguments",
+    "chooseUnit-memory-arguments",
+    "consByteString-cpu-arguments-intercept",
+    "consByteString-cpu-arguments-slope",
+    "consByteString-memory-arguments-intercept",
+    "consByteString-memory-arguments-slope",
+    "constrData-cpu-arguments",
+    "constrData-memory-arguments",
+    "decodeUtf8-cpu-arguments-intercept",
+    "decodeUtf8-cpu-arguments-slope",
+    "decodeUtf8-memory-arguments-intercept",
+    "decodeUtf8-memory-arguments-slope",
+    "divideInteger-cpu-arguments-constant",
+    "divideInteger-cpu-arguments-model-arguments-intercept",
+    "divideInteger-cpu-arguments-model-arguments-slope",
+    "divideInteger-memory-arguments-intercept",
+    "divideInteger-memory-arguments-minimum",
+    "divideInteger-memory-arguments-slope",
+    "encodeUtf8-cpu-arguments-intercept",
+    "encodeUtf8-cpu-arguments-slope",
+    "encodeUtf8-memory-arguments-intercept",
+    "encodeUtf8-memory-arguments-slope",
+    "equalsByteString-cpu-arguments-constant",
+    "equalsByteString-cpu-arguments-intercept",
+    "equalsByteString-cpu-arguments-slope",
+    "equalsByteString-memory-arguments",
+    "equalsData-cpu-arguments-intercept",
+    "equalsData-cpu-arguments-slope",
+    "equalsData-memory-arguments",
+    "equalsInteger-cpu-arguments-intercept",
+    "equalsInteger-cpu-arguments-slope",
+    "equalsInteger-memory-arguments",
+    "equalsString-cpu-arguments-constant",
+    "equalsString-cpu-arguments-intercept",
+    "equalsString-cpu-arguments-slope",
+    "equalsString-memory-arguments",
+    "fstPair-cpu-arguments",
+    "fstPair-memory-arguments",
+    "headList-cpu-arguments",
+    "headList-memory-arguments",
+    "iData-cpu-arguments",
+    "iData-memory-arguments",
+    "ifThenElse-cpu-arguments",
+    "ifThenElse-memory-arguments",
+    "indexByteString-cpu-arguments",
+    "indexByteString-memory-arguments",
+    "lengthOfByteString-cpu-arguments",
+    "lengthOfByteString-memory-arguments",
+    "lessThanByteString-cpu-arguments-intercept",
+    "lessThanByteString-cpu-arguments-slope",
+    "lessThanByteString-memory-arguments",
+    "lessThanEqualsByteString-cpu-arguments-intercept",
+    "lessThanEqualsByteString-cpu-arguments-slope",
+    "lessThanEqualsByteString-memory-arguments",
+    "lessThanEqualsInteger-cpu-arguments-intercept",
+    "lessThanEqualsInteger-cpu-arguments-slope",
+    "lessThanEqualsInteger-memory-arguments",
+    "lessThanInteger-cpu-arguments-intercept",
+    "lessThanInteger-cpu-arguments-slope",
+    "lessThanInteger-memory-arguments",
+    "listData-cpu-arguments",
+    "listData-memory-arguments",
+    "mapData-cpu-arguments",
+    "mapData-memory-arguments",
+    "mkCons-cpu-arguments",
+    "mkCons-memory-arguments",
+    "mkNilData-cpu-arguments",
+    "mkNilData-memory-arguments",
+    "mkNilPairData-cpu-arguments",
+    "mkNilPairData-memory-arguments",
+    "mkPairData-cpu-arguments",
+    "mkPairData-memory-arguments",
+    "modInteger-cpu-arguments-constant",
+    "modInteger-cpu-arguments-model-arguments-intercept",
+    "modInteger-cpu-arguments-model-arguments-slope",
+    "modInteger-memory-arguments-intercept",
+    "modInteger-memory-arguments-minimum",
+    "modInteger-memory-arguments-slope",
+    "multiplyInteger-cpu-arguments-intercept",
+    "multiplyInteger-cpu-arguments-slope",
+    "multiplyInteger-memory-arguments-intercept",
+    "multiplyInteger-memory-arguments-slope",
+    "nullList-cpu-arguments",
+    "nullList-memory-arguments",
+    "quotientInteger-cpu-arguments-constant",
+    "quotientInteger-cpu-arguments-model-arguments-intercept",
+    "quotientInteger-cpu-arguments-model-arguments-slope",
+    "quotientInteger-memory-arguments-intercept",
+    "quotientInteger-memory-arguments-minimum",
+    "quotientInteger-memory-arguments-slope",
+    "remainderInteger-cpu-arguments-constant",
+    "remainderInteger-cpu-arguments-model-arguments-intercept",
+    "remainderInteger-cpu-arguments-model-arguments-slope",
+    "remainderInteger-memory-arguments-intercept",
+    "remainderInteger-memory-arguments-minimum",
+    "remainderInteger-memory-arguments-slope",
+    "serialiseData-cpu-arguments-intercept",
+    "serialiseData-cpu-arguments-slope",
+    "serialiseData-memory-arguments-intercept",
+    "serialiseData-memory-arguments-slope",
+    "sha2_256-cpu-arguments-intercept",
+    "sha2_256-cpu-arguments-slope",
+    "sha2_256-memory-arguments",
+    "sha3_256-cpu-arguments-intercept",
+    "sha3_256-cpu-arguments-slope",
+    "sha3_256-memory-arguments",
+    "sliceByteString-cpu-arguments-intercept",
+    "sliceByteString-cpu-arguments-slope",
+    "sliceByteString-memory-arguments-intercept",
+    "sliceByteString-memory-arguments-slope",
+    "sndPair-cpu-arguments",
+    "sndPair-memory-arguments",
+    "subtractInteger-cpu-arguments-intercept",
+    "subtractInteger-cpu-arguments-slope",
+    "subtractInteger-memory-arguments-intercept",
+    "subtractInteger-memory-arguments-slope",
+    "tailList-cpu-arguments",
+    "tailList-memory-arguments",
+    "trace-cpu-arguments",
+    "trace-memory-arguments",
+    "unBData-cpu-arguments",
+    "unBData-memory-arguments",
+    "unConstrData-cpu-arguments",
+    "unConstrData-memory-arguments",
+    "unIData-cpu-arguments",
+    "unIData-memory-arguments",
+    "unListData-cpu-arguments",
+    "unListData-memory-arguments",
+    "unMapData-cpu-arguments",
+    "unMapData-memory-arguments",
+    "verifyEcdsaSecp256k1Signature-cpu-arguments",
+    "verifyEcdsaSecp256k1Signature-memory-arguments",
+    "verifyEd25519Signature-cpu-arguments-intercept",
+    "verifyEd25519Signature-cpu-arguments-slope",
+    "verifyEd25519Signature-memory-arguments",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-intercept",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-slope",
+    "verifySchnorrSecp256k1Signature-memory-arguments",
+    "integerToByteString-cpu-arguments-c0",
+    "integerToByteString-cpu-arguments-c1",
+    "integerToByteString-cpu-arguments-c2",
+    "integerToByteString-memory-arguments-intercept",
+    "integerToByteString-memory-arguments-slope",
+    "byteStringToInteger-cpu-arguments-c0",
+    "byteStringToInteger-cpu-arguments-c1",
+    "byteStringToInteger-cpu-arguments-c2",
+    "byteStringToInteger-memory-arguments-intercept",
+    "byteStringToInteger-memory-arguments-slope",
+];
+
+// Chang parameters (251) followed by the bitwise and RIPEMD-160 builtins of
+// protocol version 10
+pub const PLUTUS_V3_PARAMETER_NAMES: [&str; 297] = [
+    "addInteger-cpu-arguments-intercept",
+    "addInteger-cpu-arguments-slope",
+    "addInteger-memory-arguments-intercept",
+    "addInteger-memory-arguments-slope",
+    "appendByteString-cpu-arguments-intercept",
+    "appendByteString-cpu-arguments-slope",
+    "appendByteString-memory-arguments-intercept",
+    "appendByteString-memory-arguments-slope",
+    "appendString-cpu-arguments-intercept",
+    "appendString-cpu-arguments-slope",
+    "appendString-memory-arguments-intercept",
+    "appendString-memory-arguments-slope",
+    "bData-cpu-arguments",
+    "bData-memory-arguments",
+    "blake2b_256-cpu-arguments-intercept",
+    "blake2b_256-cpu-arguments-slope",
+    "blake2b_256-memory-arguments",
+    "cekApplyCost-exBudgetCPU",
+    "cekApplyCost-exBudgetMemory",
+    "cekBuiltinCost-exBudgetCPU",
+    "cekBuiltinCost-exBudgetMemory",
+    "cekConstCost-exBudgetCPU",
+    "cekConstCost-exBudgetMemory",
+    "cekDelayCost-exBudgetCPU",
+    "cekDelayCost-exBudgetMemory",
+    "cekForceCost-exBudgetCPU",
+    "cekForceCost-exBudgetMemory",
+    "cekLamCost-exBudgetCPU",
+    "cekLamCost-exBudgetMemory",
+    "cekStartupCost-exBudgetCPU",
+    "cekStartupCost-exBudgetMemory",
+    "cekVarCost-exBudgetCPU",
+    "cekVarCost-exBudgetMemory",
+    "chooseData-cpu-arguments",
+    "chooseData-memory-arguments",
+    "chooseList-cpu-arguments",
+    "chooseList-memory-arguments",
+    "chooseUnit-cpu-arguments",
+    "chooseUnit-memory-arguments",
+    "consByteString-cpu-arguments-intercept",
+    "consByteString-cpu-arguments-slope",
+    "consByteString-memory-arguments-intercept",
+    "consByteString-memory-arguments-slope",
+    "constrData-cpu-arguments",
+    "constrData-memory-arguments",
+    "decodeUtf8-cpu-arguments-intercept",
+    "decodeUtf8-cpu-arguments-slope",
+    "decodeUtf8-memory-arguments-intercept",
+    "decodeUtf8-memory-arguments-slope",
+    "divideInteger-cpu-arguments-constant",
+    "divideInteger-cpu-arguments-model-arguments-c00",
+    "divideInteger-cpu-arguments-model-arguments-c01",
+    "divideInteger-cpu-arguments-model-arguments-c02",
+    "divideInteger-cpu-arguments-model-arguments-c10",
+    "divideInteger-cpu-arguments-model-arguments-c11",
+    "divideInteger-cpu-arguments-model-arguments-c20",
+    "divideInteger-cpu-arguments-model-arguments-minimum",
+    "divideInteger-memory-arguments-intercept",
+    "divideInteger-memory-arguments-minimum",
+    "divideInteger-memory-arguments-slope",
+    "encodeUtf8-cpu-arguments-intercept",
+    "encodeUtf8-cpu-arguments-slope",
+    "encodeUtf8-memory-arguments-intercept",
+    "encodeUtf8-memory-arguments-slope",
+    "equalsByteString-cpu-arguments-constant",
+    "equalsByteString-cpu-arguments-intercept",
+    "equalsByteString-cpu-arguments-slope",
+    "equalsByteString-memory-arguments",
+    "equalsData-cpu-arguments-intercept",
+    "equalsData-cpu-arguments-slope",
+    "equalsData-memory-arguments",
+    "equalsInteger-cpu-arguments-intercept",
+    "equalsInteger-cpu-arguments-slope",
+    "equalsInteger-memory-arguments",
+    "equalsString-cpu-arguments-constant",
+    "equalsString-cpu-arguments-intercept",
+    "equalsString-cpu-arguments-slope",
+    "equalsString-memory-arguments",
+    "fstPair-cpu-arguments",
+    "fstPair-memory-arguments",
+    "headList-cpu-arguments",
+    "headList-memory-arguments",
+    "iData-cpu-arguments",
+    "iData-memory-arguments",
+    "ifThenElse-cpu-arguments",
+    "ifThenElse-memory-arguments",
+    "indexByteString-cpu-arguments",
+    "indexByteString-memory-arguments",
+    "lengthOfByteString-cpu-arguments",
+    "lengthOfByteString-memory-arguments",
+    "lessThanByteString-cpu-arguments-intercept",
+    "lessThanByteString-cpu-arguments-slope",
+    "lessThanByteString-memory-arguments",
+    "lessThanEqualsByteString-cpu-arguments-intercept",
+    "lessThanEqualsByteString-cpu-arguments-slope",
+    "lessThanEqualsByteString-memory-arguments",
+    "lessThanEqualsInteger-cpu-arguments-intercept",
+    "lessThanEqualsInteger-cpu-arguments-slope",
+    "lessThanEqualsInteger-memory-arguments",
+    "lessThanInteger-cpu-arguments-intercept",
+    "lessThanInteger-cpu-arguments-slope",
+    "lessThanInteger-memory-arguments",
+    "listData-cpu-arguments",
+    "listData-memory-arguments",
+    "mapData-cpu-arguments",
+    "mapData-memory-arguments",
+    "mkCons-cpu-arguments",
+    "mkCons-memory-arguments",
+    "mkNilData-cpu-arguments",
+    "mkNilData-memory-arguments",
+    "mkNilPairData-cpu-arguments",
+    "mkNilPairData-memory-arguments",
+    "mkPairData-cpu-arguments",
+    "mkPairData-memory-arguments",
+    "modInteger-cpu-arguments-constant",
+    "modInteger-cpu-arguments-model-arguments-c00",
+    "modInteger-cpu-arguments-model-arguments-c01",
+    "modInteger-cpu-arguments-model-arguments-c02",
+    "modInteger-cpu-arguments-model-arguments-c10",
+    "modInteger-cpu-arguments-model-arguments-c11",
+    "modInteger-cpu-arguments-model-arguments-c20",
+    "modInteger-cpu-arguments-model-arguments-minimum",
+    "modInteger-memory-arguments-intercept",
+    "modInteger-memory-arguments-slope",
+    "multiplyInteger-cpu-arguments-intercept",
+    "multiplyInteger-cpu-arguments-slope",
+    "multiplyInteger-memory-arguments-intercept",
+    "multiplyInteger-memory-arguments-slope",
+    "nullList-cpu-arguments",
+    "nullList-memory-arguments",
+    "quotientInteger-cpu-arguments-constant",
+    "quotientInteger-cpu-arguments-model-arguments-c00",
+    "quotientInteger-cpu-arguments-model-arguments-c01",
+    "quotientInteger-cpu-arguments-model-arguments-c02",
+    "quotientInteger-cpu-arguments-model-arguments-c10",
+    "quotientInteger-cpu-arguments-model-arguments-c11",
+    "quotientInteger-cpu-arguments-model-arguments-c20",
+    "quotientInteger-cpu-arguments-model-arguments-minimum",
+    "quotientInteger-memory-arguments-intercept",
+    "quotientInteger-memory-arguments-minimum",
+    "quotientInteger-memory-arguments-slope",
+    "remainderInteger-cpu-arguments-constant",
+    "remainderInteger-cpu-arguments-model-arguments-c00",
+    "remainderInteger-cpu-arguments-model-arguments-c01",
+    "remainderInteger-cpu-arguments-model-arguments-c02",
+    "remainderInteger-cpu-arguments-model-arguments-c10",
+    "remainderInteger-cpu-arguments-model-arguments-c11",
+    "remainderInteger-cpu-arguments-model-arguments-c20",
+    "remainderInteger-cpu-arguments-model-arguments-minimum",
+    "remainderInteger-memory-arguments-intercept",
+    "remainderInteger-memory-arguments-slope",
+    "serialiseData-cpu-arguments-intercept",
+    "serialiseData-cpu-arguments-slope",
+    "serialiseData-memory-arguments-intercept",
+    "serialiseData-memory-arguments-slope",
+    "sha2_256-cpu-arguments-intercept",
+    "sha2_256-cpu-arguments-slope",
+    "sha2_256-memory-arguments",
+    "sha3_256-cpu-arguments-intercept",
+    "sha3_256-cpu-arguments-slope",
+    "sha3_256-memory-arguments",
+    "sliceByteString-cpu-arguments-intercept",
+    "sliceByteString-cpu-arguments-slope",
+    "sliceByteString-memory-arguments-intercept",
+    "sliceByteString-memory-arguments-slope",
+    "sndPair-cpu-arguments",
+    "sndPair-memory-arguments",
+    "subtractInteger-cpu-arguments-intercept",
+    "subtractInteger-cpu-arguments-slope",
+    "subtractInteger-memory-arguments-intercept",
+    "subtractInteger-memory-arguments-slope",
+    "tailList-cpu-arguments",
+    "tailList-memory-arguments",
+    "trace-cpu-arguments",
+    "trace-memory-arguments",
+    "unBData-cpu-arguments",
+    "unBData-memory-arguments",
+    "unConstrData-cpu-arguments",
+    "unConstrData-memory-arguments",
+    "unIData-cpu-arguments",
+    "unIData-memory-arguments",
+    "unListData-cpu-arguments",
+    "unListData-memory-arguments",
+    "unMapData-cpu-arguments",
+    "unMapData-memory-arguments",
+    "verifyEcdsaSecp256k1Signature-cpu-arguments",
+    "verifyEcdsaSecp256k1Signature-memory-arguments",
+    "verifyEd25519Signature-cpu-arguments-intercept",
+    "verifyEd25519Signature-cpu-arguments-slope",
+    "verifyEd25519Signature-memory-arguments",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-intercept",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-slope",
+    "verifySchnorrSecp256k1Signature-memory-arguments",
+    "cekConstrCost-exBudgetCPU",
+    "cekConstrCost-exBudgetMemory",
+    "cekCaseCost-exBudgetCPU",
+    "cekCaseCost-exBudgetMemory",
+    "bls12_381_G1_add-cpu-arguments",
+    "bls12_381_G1_add-memory-arguments",
+    "bls12_381_G1_compress-cpu-arguments",
+    "bls12_381_G1_compress-memory-arguments",
+    "bls12_381_G1_equal-cpu-arguments",
+    "bls12_381_G1_equal-memory-arguments",
+    "bls12_381_G1_hashToGroup-cpu-arguments-intercept",
+    "bls12_381_G1_hashToGroup-cpu-arguments-slope",
+    "bls12_381_G1_hashToGroup-memory-arguments",
+    "bls12_381_G1_neg-cpu-arguments",
+    "bls12_381_G1_neg-memory-arguments",
+    "bls12_381_G1_scalarMul-cpu-arguments-intercept",
+    "bls12_381_G1_scalarMul-cpu-arguments-slope",
+    "bls12_381_G1_scalarMul-memory-arguments",
+    "bls12_381_G1_uncompress-cpu-arguments",
+    "bls12_381_G1_uncompress-memory-arguments",
+    "bls12_381_G2_add-cpu-arguments",
+    "bls12_381_G2_add-memory-arguments",
+    "bls12_381_G2_compress-cpu-arguments",
+    "bls12_381_G2_compress-memory-arguments",
+    "bls12_381_G2_equal-cpu-arguments",
+    "bls12_381_G2_equal-memory-arguments",
+    "bls12_381_G2_hashToGroup-cpu-arguments-intercept",
+    "bls12_381_G2_hashToGroup-cpu-arguments-slope",
+    "bls12_381_G2_hashToGroup-memory-arguments",
+    "bls12_381_G2_neg-cpu-arguments",
+    "bls12_381_G2_neg-memory-arguments",
+    "bls12_381_G2_scalarMul-cpu-arguments-intercept",
+    "bls12_381_G2_scalarMul-cpu-arguments-slope",
+    "bls12_381_G2_scalarMul-memory-arguments",
+    "bls12_381_G2_uncompress-cpu-arguments",
+    "bls12_381_G2_uncompress-memory-arguments",
+    "bls12_381_finalVerify-cpu-arguments",
+    "bls12_381_finalVerify-memory-arguments",
+    "bls12_381_millerLoop-cpu-arguments",
+    "bls12_381_millerLoop-memory-arguments",
+    "bls12_381_mulMlResult-cpu-arguments",
+    "bls12_381_mulMlResult-memory-arguments",
+    "keccak_256-cpu-arguments-intercept",
+    "keccak_256-cpu-arguments-slope",
+    "keccak_256-memory-arguments",
+    "blake2b_224-cpu-arguments-intercept",
+    "blake2b_224-cpu-arguments-slope",
+    "blake2b_224-memory-arguments",
+    "integerToByteString-cpu-arguments-c0",
+    "integerToByteString-cpu-arguments-c1",
+    "integerToByteString-cpu-arguments-c2",
+    "integerToByteString-memory-arguments-intercept",
+    "integerToByteString-memory-arguments-slope",
+    "byteStringToInteger-cpu-arguments-c0",
+    "byteStringToInteger-cpu-arguments-c1",
+    "byteStringToInteger-cpu-arguments-c2",
+    "byteStringToInteger-memory-arguments-intercept",
+    "byteStringToInteger-memory-arguments-slope",
+    "andByteString-cpu-arguments-intercept",
+    "andByteString-cpu-arguments-slope1",
+    "andByteString-cpu-arguments-slope2",
+    "andByteString-memory-arguments-intercept",
+    "andByteString-memory-arguments-slope",
+    "orByteString-cpu-arguments-intercept",
+    "orByteString-cpu-arguments-slope1",
+    "orByteString-cpu-arguments-slope2",
+    "orByteString-memory-arguments-intercept",
+    "orByteString-memory-arguments-slope",
+    "xorByteString-cpu-arguments-intercept",
+    "xorByteString-cpu-arguments-slope1",
+    "xorByteString-cpu-arguments-slope2",
+    "xorByteString-memory-arguments-intercept",
+    "xorByteString-memory-arguments-slope",
+    "complementByteString-cpu-arguments-intercept",
+    "complementByteString-cpu-arguments-slope",
+    "complementByteString-memory-arguments-intercept",
+    "complementByteString-memory-arguments-slope",
+    "readBit-cpu-arguments",
+    "readBit-memory-arguments",
+    "writeBits-cpu-arguments-intercept",
+    "writeBits-cpu-arguments-slope",
+    "writeBits-memory-arguments-intercept",
+    "writeBits-memory-arguments-slope",
+    "replicateByte-cpu-arguments-intercept",
+    "replicateByte-cpu-arguments-slope",
+    "replicateByte-memory-arguments-intercept",
+    "replicateByte-memory-arguments-slope",
+    "shiftByteString-cpu-arguments-intercept",
+    "shiftByteString-cpu-arguments-slope",
+    "shiftByteString-memory-arguments-intercept",
+    "shiftByteString-memory-arguments-slope",
+    "rotateByteString-cpu-arguments-intercept",
+    "rotateByteString-cpu-arguments-slope",
+    "rotateByteString-memory-arguments-intercept",
+    "rotateByteString-memory-arguments-slope",
+    "countSetBits-cpu-arguments-intercept",
+    "countSetBits-cpu-arguments-slope",
+    "countSetBits-memory-arguments",
+    "findFirstSetBit-cpu-arguments-intercept",
+    "findFirstSetBit-cpu-arguments-slope",
+    "findFirstSetBit-memory-arguments",
+    "ripemd_160-cpu-arguments-intercept",
+    "ripemd_160-cpu-arguments-slope",
+    "ripemd_160-memory-arguments",
+];
@@ -0,0 +1,217 @@
+// Certificate checks against a local snapshot of registered stake
+// credentials, pools and DReps, catching what the node would otherwise
+// reject
+use std::collections::{BTreeMap, BTreeSet};
+
+use pallas::ledger::primitives::conway::{Certificate, DRep};
+use pallas::ledger::primitives::{PoolKeyhash, StakeCredential};
+
+use crate::error::PallasFunError;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::{IntoInner, credential_hash};
+use crate::wrapper::{CertificateWrapper, StakeCredentialWrapper};
+
+// Registered credentials map to the deposit paid when they registered.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct DelegationState {
+    pub stake_credentials: BTreeMap<StakeCredentialWrapper, u64>,
+    pub pools: BTreeSet<PoolKeyhash>,
+    pub dreps: BTreeMap<StakeCredentialWrapper, u64>,
+}
+
+impl DelegationState {
+    // Applies `certificates` in order, so a credential registered earlier in
+    // the same transaction can delegate and a second registration fails.
+    // Returns the state after the transaction. Committee certificates are
+    // not checked.
+    pub fn apply(
+        &self,
+        certificates: &[CertificateWrapper],
+        params: &ProtocolParams,
+        current_epoch: u64,
+    ) -> Result<Self, PallasFunError> {
+        let mut state = self.clone();
+        for (i, certificate) in certificates.iter().enumerate() {
+            state
+                .apply_certificate(certificate.into_inner(), params, current_epoch)
+                .map_err(|e| e.at(format!("certificates[{}]", i)))?;
+        }
+        Ok(state)
+    }
+
+    fn apply_certificate(
+        &mut self,
+        certificate: Certificate,
+        params: &ProtocolParams,
+        current_epoch: u64,
+    ) -> Result<(), PallasFunError> {
+        match certificate {
+            Certificate::StakeRegistration(credential) => {
+                self.register_stake(credential, params.key_deposit, params)
+            }
+            Certificate::Reg(credential, deposit) => {
+                self.register_stake(credential, deposit, params)
+            }
+            Certificate::StakeDeregistration(credential) => self.deregister_stake(credential, None),
+            Certificate::UnReg(credential, refund) => {
+                self.deregister_stake(credential, Some(refund))
+            }
+            Certificate::StakeDelegation(credential, pool) => {
+                self.check_stake(&credential)?;
+                self.check_pool(&pool)
+            }
+            Certificate::VoteDeleg(credential, drep) => {
+                self.check_stake(&credential)?;
+                self.check_drep_delegation(&drep)
+            }
+            Certificate::StakeVoteDeleg(credential, pool, drep) => {
+                self.check_stake(&credential)?;
+                self.check_pool(&pool)?;
+                self.check_drep_delegation(&drep)
+            }
+            Certificate::StakeRegDeleg(credential, pool, deposit) => {
+                self.check_pool(&pool)?;
+                self.register_stake(credential, deposit, params)
+            }
+            Certificate::VoteRegDeleg(credential, drep, deposit) => {
+                self.check_drep_delegation(&drep)?;
+                self.register_stake(credential, deposit, params)
+            }
+            Certificate::StakeVoteRegDeleg(credential, pool, drep, deposit) => {
+                self.check_pool(&pool)?;
+                self.check_drep_delegation(&drep)?;
+                self.register_stake(credential, deposit, params)
+            }
+            // registering an existing pool updates its parameters
+            Certificate::PoolRegistration { operator, .. } => {
+                self.pools.insert(operator);
+                Ok(())
+            }
+            Certificate::PoolRetirement(pool, epoch) => {
+                self.check_pool(&pool)?;
+                let max = current_epoch.saturating_add(params.maximum_epoch);
+                if epoch <= current_epoch || epoch > max {
+                    return Err(PallasFunError::InvalidRetirementEpoch {
+                        epoch,
+                        min: current_epoch + 1,
+                        max,
+                    });
+                }
+                Ok(())
+            }
+            Certificate::RegDRepCert(credential, deposit, _) => {
+                check_deposit("DRep deposit", params.drep_deposit, deposit)?;
+                let credential = StakeCredentialWrapper::from(credential);
+                if self.dreps.contains_key(&credential) {
+                    return Err(PallasFunError::DuplicateEntry {
+                        kind: "DRep registration",
+                        entry: credential_hash(&credential.into_inner()),
+                    });
+                }
+                self.dreps.insert(credential, deposit);
+                Ok(())
+            }
+            Certificate::UnRegDRepCert(credential, refund) => {
+                let credential = StakeCredentialWrapper::from(credential);
+                let deposit = self
+                    .dreps
+                    .remove(&credential)
+                    .ok_or_else(|| unregistered("DRep", &credential))?;
+                check_deposit("DRep refund", deposit, refund)
+            }
+            Certificate::UpdateDRepCert(credential, _) => {
+                let credential = StakeCredentialWrapper::from(credential);
+                if !self.dreps.contains_key(&credential) {
+                    return Err(unregistered("DRep", &credential));
+                }
+                Ok(())
+            }
+            Certificate::AuthCommitteeHot(..) | Certificate::ResignCommitteeCold(..) => Ok(()),
+        }
+    }
+
+    fn register_stake(
+        &mut self,
+        credential: StakeCredential,
+        deposit: u64,
+        params: &ProtocolParams,
+    ) -> Result<(), PallasFunError> {
+        check_deposit("stake deposit", params.key_deposit, deposit)?;
+        let credential = StakeCredentialWrapper::from(credential);
+        if self.stake_credentials.contains_key(&credential) {
+            return Err(PallasFunError::DuplicateEntry {
+                kind: "stake registration",
+                entry: credential_hash(&credential.into_inner()),
+            });
+        }
+        self.stake_credentials.insert(credential, deposit);
+        Ok(())
+    }
+
+    // the legacy deregistration certificate carries no refund to check
+    fn deregister_stake(
+        &mut self,
+        credential: StakeCredential,
+        refund: Option<u64>,
+    ) -> Result<(), PallasFunError> {
+        let credential = StakeCredentialWrapper::from(credential);
+        let deposit = self
+            .stake_credentials
+            .remove(&credential)
+            .ok_or_else(|| unregistered("stake credential", &credential))?;
+        match refund {
+            Some(refund) => check_deposit("stake refund", deposit, refund),
+            None => Ok(()),
+        }
+    }
+
+    fn check_stake(&self, credential: &StakeCredential) -> Result<(), PallasFunError> {
+        let credential = StakeCredentialWrapper::from(credential.clone());
+        if !self.stake_credentials.contains_key(&credential) {
+            return Err(unregistered("stake credential", &credential));
+        }
+        Ok(())
+    }
+
+    fn check_pool(&self, pool: &PoolKeyhash) -> Result<(), PallasFunError> {
+        if !self.pools.contains(pool) {
+            return Err(PallasFunError::Unregistered {
+                kind: "pool",
+                entry: pool.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    // always abstain and always no confidence need no registration
+    fn check_drep_delegation(&self, drep: &DRep) -> Result<(), PallasFunError> {
+        let credential = match drep {
+            DRep::Key(hash) => StakeCredential::AddrKeyhash(*hash),
+            DRep::Script(hash) => StakeCredential::ScriptHash(*hash),
+            DRep::Abstain | DRep::NoConfidence => return Ok(()),
+        };
+        let credential = StakeCredentialWrapper::from(credential);
+        if !self.dreps.contains_key(&credential) {
+            return Err(unregistered("DRep", &credential));
+        }
+        Ok(())
+    }
+}
+
+fn check_deposit(kind: &'static str, expected: u64, actual: u64) -> Result<(), PallasFunError> {
+    if expected != actual {
+        return Err(PallasFunError::DepositMismatch {
+            kind,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+fn unregistered(kind: &'static str, credential: &StakeCredentialWrapper) -> PallasFunError {
+    PallasFunError::Unregistered {
+        kind,
+        entry: credential_hash(&credential.into_inner()),
+    }
+}
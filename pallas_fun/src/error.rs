@@ -0,0 +1,358 @@
+use std::error::Error;
+use std::fmt;
+
+use pallas::ledger::primitives::conway::RedeemerTag;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PallasFunError {
+    HexDecode(String),
+    Bech32(String),
+    InvalidLength {
+        kind: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    CborDecode(String),
+    CborEncode(String),
+    EmptySet(&'static str),
+    ZeroAmount(&'static str),
+    InvalidRational {
+        numerator: u64,
+        denominator: u64,
+    },
+    Overflow(&'static str),
+    InvalidDecimal(String),
+    InvalidAddress(String),
+    Json(String),
+    Io(String),
+    MissingField(String),
+    SchemaReference(String),
+    SchemaMismatch(String),
+    ScriptHashMismatch {
+        declared: String,
+        computed: String,
+    },
+    RedeemerTargetNotFound(RedeemerTag),
+    DuplicateRedeemer {
+        tag: RedeemerTag,
+        index: u32,
+    },
+    InsufficientCollateral {
+        required: u64,
+        available: u64,
+    },
+    UnresolvedInput(String),
+    DuplicateEntry {
+        kind: &'static str,
+        entry: String,
+    },
+    InconsistentCollateral(&'static str),
+    CostModelLength {
+        language: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    UnknownParameter(String),
+    PrerequisiteMismatch {
+        purpose: &'static str,
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+    InvalidHardForkVersion {
+        current: (u64, u64),
+        proposed: (u64, u64),
+    },
+    TooLong {
+        kind: &'static str,
+        max: usize,
+        actual: usize,
+    },
+    GuardrailScriptMismatch {
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+    AnchorHashMismatch {
+        declared: String,
+        computed: String,
+    },
+    InvalidAuthorWitness(String),
+    InvalidVote(String),
+    UnexpectedGovAction(&'static str),
+    InvalidCommitteeExpiry {
+        expiry: u64,
+        min: u64,
+        max: u64,
+    },
+    CommitteeTooSmall {
+        size: usize,
+        min: u64,
+    },
+    Unregistered {
+        kind: &'static str,
+        entry: String,
+    },
+    DepositMismatch {
+        kind: &'static str,
+        expected: u64,
+        actual: u64,
+    },
+    InvalidRetirementEpoch {
+        epoch: u64,
+        min: u64,
+        max: u64,
+    },
+    UnexpectedCertificate(&'static str),
+    BelowMinimum {
+        kind: &'static str,
+        min: u64,
+        actual: u64,
+    },
+    MissingOwner(String),
+    InvalidPoolMetadata {
+        field: &'static str,
+        reason: String,
+    },
+    // where in a nested structure the error happened, e.g.
+    // `certificates[0].PoolRegistration.pool_owners[3]`
+    Context {
+        path: String,
+        source: Box<PallasFunError>,
+    },
+}
+
+impl PallasFunError {
+    // prefixes the error path with the enclosing field, `[i]` segments attach
+    // without a dot
+    pub fn at(self, segment: impl Into<String>) -> Self {
+        let segment = segment.into();
+        match self {
+            Self::Context { path, source } => {
+                let path = if path.starts_with('[') {
+                    format!("{}{}", segment, path)
+                } else {
+                    format!("{}.{}", segment, path)
+                };
+                Self::Context { path, source }
+            }
+            error => Self::Context {
+                path: segment,
+                source: Box::new(error),
+            },
+        }
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::Context { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    // the error without its path
+    pub fn root_cause(&self) -> &PallasFunError {
+        match self {
+            Self::Context { source, .. } => source.root_cause(),
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for PallasFunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HexDecode(e) => write!(f, "Hex decode error: {}", e),
+            Self::Bech32(e) => write!(f, "Bech32 error: {}", e),
+            Self::InvalidLength {
+                kind,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Invalid {} length: expected {} bytes, got {}",
+                kind, expected, actual
+            ),
+            Self::CborDecode(e) => write!(f, "Fragment decode error: {}", e),
+            Self::CborEncode(e) => write!(f, "Fragment encode error: {}", e),
+            Self::EmptySet(kind) => write!(f, "{} cannot be empty", kind),
+            Self::ZeroAmount(kind) => write!(f, "{} cannot be zero", kind),
+            Self::InvalidRational {
+                numerator,
+                denominator,
+            } => write!(f, "Invalid rational number {}/{}", numerator, denominator),
+            Self::Overflow(kind) => write!(f, "{} overflow", kind),
+            Self::InvalidDecimal(decimal) => write!(f, "Invalid decimal {}", decimal),
+            Self::InvalidAddress(e) => write!(f, "Invalid address: {}", e),
+            Self::Json(e) => write!(f, "JSON error: {}", e),
+            Self::Io(e) => write!(f, "IO error: {}", e),
+            Self::MissingField(field) => write!(f, "Missing {}", field),
+            Self::SchemaReference(reference) => {
+                write!(f, "Unresolved schema reference: {}", reference)
+            }
+            Self::SchemaMismatch(e) => write!(f, "Schema mismatch: {}", e),
+            Self::ScriptHashMismatch { declared, computed } => write!(
+                f,
+                "Script hash mismatch: declared {}, computed {}",
+                declared, computed
+            ),
+            Self::RedeemerTargetNotFound(tag) => {
+                write!(
+                    f,
+                    "Redeemer target ({:?}) not found in transaction body",
+                    tag
+                )
+            }
+            Self::DuplicateRedeemer { tag, index } => {
+                write!(f, "Duplicate redeemer for ({:?}, {})", tag, index)
+            }
+            Self::InsufficientCollateral {
+                required,
+                available,
+            } => write!(
+                f,
+                "Insufficient collateral: required {}, available {}",
+                required, available
+            ),
+            Self::UnresolvedInput(input) => write!(f, "Unresolved input {}", input),
+            Self::DuplicateEntry { kind, entry } => write!(f, "Duplicate {} {}", kind, entry),
+            Self::InconsistentCollateral(e) => write!(f, "Inconsistent collateral: {}", e),
+            Self::CostModelLength {
+                language,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Invalid {} cost model length: expected {} parameters, got {}",
+                language, expected, actual
+            ),
+            Self::UnknownParameter(name) => write!(f, "Unknown parameter {}", name),
+            Self::PrerequisiteMismatch {
+                purpose,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Wrong {} prerequisite: expected {}, got {}",
+                purpose,
+                expected.as_deref().unwrap_or("none"),
+                actual.as_deref().unwrap_or("none")
+            ),
+            Self::InvalidHardForkVersion { current, proposed } => write!(
+                f,
+                "Protocol version {}.{} cannot follow {}.{}",
+                proposed.0, proposed.1, current.0, current.1
+            ),
+            Self::TooLong { kind, max, actual } => write!(
+                f,
+                "{} too long: {} bytes, at most {} allowed",
+                kind, actual, max
+            ),
+            Self::GuardrailScriptMismatch { expected, actual } => write!(
+                f,
+                "Guardrail script hash mismatch: constitution has {}, action has {}",
+                expected.as_deref().unwrap_or("none"),
+                actual.as_deref().unwrap_or("none")
+            ),
+            Self::AnchorHashMismatch { declared, computed } => write!(
+                f,
+                "Anchor content hash mismatch: declared {}, computed {}",
+                declared, computed
+            ),
+            Self::InvalidAuthorWitness(e) => write!(f, "Invalid author witness: {}", e),
+            Self::InvalidVote(vote) => {
+                write!(f, "Invalid vote {}, expected yes, no or abstain", vote)
+            }
+            Self::UnexpectedGovAction(expected) => {
+                write!(f, "Expected a {} governance action", expected)
+            }
+            Self::InvalidCommitteeExpiry { expiry, min, max } => write!(
+                f,
+                "Committee member expiry epoch {} outside {}..={}",
+                expiry, min, max
+            ),
+            Self::CommitteeTooSmall { size, min } => write!(
+                f,
+                "Committee of {} members is below the minimum of {}",
+                size, min
+            ),
+            Self::Unregistered { kind, entry } => write!(f, "Unregistered {} {}", kind, entry),
+            Self::DepositMismatch {
+                kind,
+                expected,
+                actual,
+            } => write!(f, "Invalid {}: expected {}, got {}", kind, expected, actual),
+            Self::InvalidRetirementEpoch { epoch, min, max } => write!(
+                f,
+                "Pool retirement epoch {} outside {}..={}",
+                epoch, min, max
+            ),
+            Self::UnexpectedCertificate(expected) => {
+                write!(f, "Expected a {} certificate", expected)
+            }
+            Self::BelowMinimum { kind, min, actual } => {
+                write!(f, "{} {} is below the minimum of {}", kind, actual, min)
+            }
+            Self::MissingOwner(owner) => {
+                write!(f, "Reward account key hash {} is not a pool owner", owner)
+            }
+            Self::InvalidPoolMetadata { field, reason } => {
+                write!(f, "Invalid pool metadata {}: {}", field, reason)
+            }
+            Self::Context { path, source } => write!(f, "{}: {}", path, source),
+        }
+    }
+}
+
+// `Context` already prints its source after the path, so it is not exposed
+// through `source()` as well
+impl Error for PallasFunError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wrapper::{TransactionBodyBuilder, TransactionInputWrapper};
+
+    #[test]
+    fn test_error_path_through_outputs() {
+        let address = "61276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+        let policy = "00".repeat(28);
+        let payout = |amount| {
+            vec![(
+                policy.clone(),
+                vec![("aa".to_string(), 1), ("bb".to_string(), amount)],
+            )]
+        };
+        let err = TransactionBodyBuilder::new()
+            .add_input(
+                TransactionInputWrapper::new(&"11".repeat(32), 0)
+                    .expect("should build transaction input"),
+            )
+            .add_output_parts(address, 2_000_000, vec![])
+            .add_output_parts(address, 2_000_000, payout(1))
+            .add_output_parts(address, 2_000_000, payout(0))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err.path(),
+            Some(
+                "outputs[2].value.multiasset[policy=00000000000000000000000000000000000000000000000000000000].asset[1]"
+            )
+        );
+        assert_eq!(
+            err.root_cause(),
+            &PallasFunError::ZeroAmount("asset amount")
+        );
+        assert!(err.to_string().ends_with(": asset amount cannot be zero"));
+
+        // a bad policy id is tagged the same way as its assets
+        let err = TransactionBodyBuilder::new()
+            .add_input(
+                TransactionInputWrapper::new(&"11".repeat(32), 0)
+                    .expect("should build transaction input"),
+            )
+            .add_output_parts(address, 2_000_000, vec![("zz".to_string(), vec![])])
+            .build()
+            .unwrap_err();
+        assert_eq!(err.path(), Some("outputs[0].value.multiasset[policy=zz]"));
+    }
+}
@@ -0,0 +1,241 @@
+// A voter's votes on many governance actions, loaded from a CSV or JSON
+// ballot file and turned into `voting_procedures` for one or more
+// transactions
+use std::path::Path;
+
+use pallas::ledger::primitives::Fragment;
+use serde::Deserialize;
+
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, read_file};
+use crate::wrapper::{
+    AnchorWrapper, GovActionIdWrapper, VoteKind, VoterWrapper, VotingProcedureWrapper,
+    VotingProceduresInput,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BallotEntry {
+    pub gov_action_id: GovActionIdWrapper,
+    pub vote: VoteKind,
+    pub anchor: Option<AnchorWrapper>,
+}
+
+// Entries are sorted by gov action id; repeated identical entries are kept
+// once, differing votes on the same action are an error.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Ballot {
+    pub voter: VoterWrapper,
+    pub entries: Vec<BallotEntry>,
+}
+
+// one row of a ballot file, `gov_action_id` is `txhash#index` or `gov_action1...`
+#[derive(Deserialize)]
+struct BallotRow {
+    gov_action_id: String,
+    vote: String,
+    #[serde(default)]
+    anchor_url: Option<String>,
+    #[serde(default)]
+    anchor_hash: Option<String>,
+}
+
+impl Ballot {
+    pub fn new(voter: VoterWrapper, entries: Vec<BallotEntry>) -> Result<Self, PallasFunError> {
+        let mut entries = entries;
+        entries.sort_by_key(|entry| sort_key(&entry.gov_action_id));
+        entries.dedup();
+        for pair in entries.windows(2) {
+            if pair[0].gov_action_id == pair[1].gov_action_id {
+                let id = pair[0].gov_action_id.into_inner();
+                return Err(PallasFunError::DuplicateEntry {
+                    kind: "vote",
+                    entry: format!("{}#{}", id.transaction_id, id.action_index),
+                });
+            }
+        }
+        Ok(Self { voter, entries })
+    }
+
+    // `gov_action_id,vote,anchor_url,anchor_hash` with an optional header
+    // line; blank lines are skipped and the anchor columns may be left empty. Fields are not quoted.
+    pub fn from_csv(voter: VoterWrapper, csv: &str) -> Result<Self, PallasFunError> {
+        let mut entries = Vec::new();
+        let mut first_row = true;
+        for (i, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // the header, if any, is the first non-empty line
+            let header = first_row && line.starts_with("gov_action_id");
+            first_row = false;
+            if header {
+                continue;
+            }
+            let columns: Vec<&str> = line.split(',').map(str::trim).collect();
+            // fields are unquoted, so an extra column (e.g. a comma in the
+            // url) cannot be told apart from a misplaced one
+            if columns.len() > 4 {
+                return Err(PallasFunError::InvalidLength {
+                    kind: "ballot csv columns",
+                    expected: 4,
+                    actual: columns.len(),
+                }
+                .at(format!("line {}", i + 1)));
+            }
+            let mut columns = columns.into_iter();
+            let mut column = || columns.next().filter(|c| !c.is_empty()).map(str::to_string);
+            let row = BallotRow {
+                gov_action_id: column().unwrap_or_default(),
+                vote: column().unwrap_or_default(),
+                anchor_url: column(),
+                anchor_hash: column(),
+            };
+            // errors point at the 1-based line
+            entries.push(row.parse().map_err(|e| e.at(format!("line {}", i + 1)))?);
+        }
+        Self::new(voter, entries)
+    }
+
+    // array of `{ "gov_action_id", "vote", "anchor_url"?, "anchor_hash"? }`
+    pub fn from_json(voter: VoterWrapper, json: &str) -> Result<Self, PallasFunError> {
+        let rows: Vec<BallotRow> =
+            serde_json::from_str(json).map_err(|e| PallasFunError::Json(e.to_string()))?;
+        let entries = rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| row.parse().map_err(|e| e.at(format!("[{}]", i))))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(voter, entries)
+    }
+
+    pub fn from_csv_file(
+        voter: VoterWrapper,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, PallasFunError> {
+        Self::from_csv(voter, &read_file(path)?)
+    }
+
+    pub fn from_json_file(
+        voter: VoterWrapper,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, PallasFunError> {
+        Self::from_json(voter, &read_file(path)?)
+    }
+
+    pub fn voting_procedures(&self) -> Result<VotingProceduresInput, PallasFunError> {
+        let procedures = self
+            .entries
+            .iter()
+            .map(BallotEntry::procedure)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(vec![(self.voter.clone(), procedures)])
+    }
+
+    // Splits the ballot so the CBOR `voting_procedures` of each transaction
+    // stays within `max_bytes`, keeping the entries in order.
+    pub fn split(&self, max_bytes: usize) -> Result<Vec<VotingProceduresInput>, PallasFunError> {
+        let voter_size = encoded_size(&self.voter.into_inner())?;
+        // outer map header for the single voter
+        let fixed_size = 1 + voter_size;
+
+        let mut chunks = Vec::new();
+        let mut chunk = Vec::new();
+        let mut chunk_size = 0;
+        for entry in &self.entries {
+            let procedure = entry.procedure()?;
+            let size =
+                encoded_size(&procedure.0.into_inner())? + encoded_size(&procedure.1.into_inner())?;
+            let total = |count: usize, size: usize| fixed_size + map_header_size(count) + size;
+            if total(1, size) > max_bytes {
+                return Err(PallasFunError::TooLong {
+                    kind: "vote",
+                    max: max_bytes,
+                    actual: total(1, size),
+                });
+            }
+            if !chunk.is_empty() && total(chunk.len() + 1, chunk_size + size) > max_bytes {
+                chunks.push(vec![(self.voter.clone(), std::mem::take(&mut chunk))]);
+                chunk_size = 0;
+            }
+            chunk.push(procedure);
+            chunk_size += size;
+        }
+        if !chunk.is_empty() {
+            chunks.push(vec![(self.voter.clone(), chunk)]);
+        }
+        Ok(chunks)
+    }
+}
+
+impl BallotEntry {
+    fn procedure(&self) -> Result<(GovActionIdWrapper, VotingProcedureWrapper), PallasFunError> {
+        Ok((
+            self.gov_action_id.clone(),
+            VotingProcedureWrapper::new(self.vote.clone(), self.anchor.clone())?,
+        ))
+    }
+}
+
+impl BallotRow {
+    fn parse(self) -> Result<BallotEntry, PallasFunError> {
+        let gov_action_id =
+            parse_gov_action_id(&self.gov_action_id).map_err(|e| e.at("gov_action_id"))?;
+        let vote = match self.vote.to_ascii_lowercase().as_str() {
+            "yes" => VoteKind::Yes,
+            "no" => VoteKind::No,
+            "abstain" => VoteKind::Abstain,
+            "" => return Err(PallasFunError::MissingField("vote".to_string())),
+            _ => return Err(PallasFunError::InvalidVote(self.vote)),
+        };
+        let anchor = match (self.anchor_url, self.anchor_hash) {
+            (Some(url), Some(hash)) => {
+                Some(AnchorWrapper::new(url, hash).map_err(|e| e.at("anchor"))?)
+            }
+            (None, None) => None,
+            (Some(_), None) => return Err(PallasFunError::MissingField("anchor_hash".to_string())),
+            (None, Some(_)) => return Err(PallasFunError::MissingField("anchor_url".to_string())),
+        };
+        Ok(BallotEntry {
+            gov_action_id,
+            vote,
+            anchor,
+        })
+    }
+}
+
+fn parse_gov_action_id(id: &str) -> Result<GovActionIdWrapper, PallasFunError> {
+    if id.is_empty() {
+        return Err(PallasFunError::MissingField("gov_action_id".to_string()));
+    }
+    match id.split_once('#') {
+        Some((transaction_id, index)) => {
+            let index = index
+                .parse()
+                .map_err(|_| PallasFunError::InvalidDecimal(index.to_string()))?;
+            GovActionIdWrapper::new(transaction_id, index)
+        }
+        None => GovActionIdWrapper::from_bech32(id),
+    }
+}
+
+fn sort_key(id: &GovActionIdWrapper) -> ([u8; 32], u32) {
+    let id = id.into_inner();
+    (*id.transaction_id, id.action_index)
+}
+
+fn encoded_size<T: for<'a> Fragment<'a>>(value: &T) -> Result<usize, PallasFunError> {
+    value
+        .encode_fragment()
+        .map(|bytes| bytes.len())
+        .map_err(|e| PallasFunError::CborEncode(e.to_string()))
+}
+
+fn map_header_size(len: usize) -> usize {
+    match len {
+        0..24 => 1,
+        24..256 => 2,
+        256..65536 => 3,
+        _ => 5,
+    }
+}
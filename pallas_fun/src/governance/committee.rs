@@ -0,0 +1,86 @@
+// Checks on constitutional committee updates against the current committee
+// and protocol parameters
+use std::collections::BTreeSet;
+
+use pallas::ledger::primitives::StakeCredential;
+use pallas::ledger::primitives::conway::GovAction;
+use serde::Serialize;
+
+use crate::error::PallasFunError;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::{IntoInner, credential_hash, rational_pair};
+use crate::wrapper::{GovActionWrapper, StakeCredentialWrapper};
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CommitteeUpdateSummary {
+    pub added: usize,
+    pub removed: usize,
+    // committee size once the update is enacted
+    pub size: usize,
+    pub quorum: (u64, u64),
+}
+
+impl CommitteeUpdateSummary {
+    // `current_members` are the cold credentials of the committee in
+    // `current_epoch`. New members must expire after `current_epoch` and at
+    // most `committee_term_limit` epochs later, no member may be both added
+    // and removed, the quorum must be at most 1 and the resulting committee
+    // at least `min_committee_size`.
+    pub fn new(
+        action: &GovActionWrapper,
+        current_members: &[StakeCredentialWrapper],
+        current_epoch: u64,
+        params: &ProtocolParams,
+    ) -> Result<Self, PallasFunError> {
+        let GovAction::UpdateCommittee(_, removed, added, quorum) = action.into_inner() else {
+            return Err(PallasFunError::UnexpectedGovAction("update committee"));
+        };
+
+        if quorum.denominator == 0 || quorum.numerator > quorum.denominator {
+            return Err(PallasFunError::InvalidRational {
+                numerator: quorum.numerator,
+                denominator: quorum.denominator,
+            }
+            .at("quorum"));
+        }
+
+        let removed: BTreeSet<StakeCredential> = removed.iter().cloned().collect();
+        let max_expiry = current_epoch.saturating_add(params.committee_term_limit);
+        for (i, (member, expiry)) in added.iter().enumerate() {
+            let at = |e: PallasFunError| e.at(format!("members[{}]", i));
+            if removed.contains(member) {
+                return Err(at(PallasFunError::DuplicateEntry {
+                    kind: "committee member added and removed",
+                    entry: credential_hash(member),
+                }));
+            }
+            if *expiry <= current_epoch || *expiry > max_expiry {
+                return Err(at(PallasFunError::InvalidCommitteeExpiry {
+                    expiry: *expiry,
+                    min: current_epoch + 1,
+                    max: max_expiry,
+                }));
+            }
+        }
+
+        let mut members: BTreeSet<StakeCredential> = current_members
+            .iter()
+            .map(|member| member.into_inner())
+            .collect();
+        members.retain(|member| !removed.contains(member));
+        members.extend(added.iter().map(|(member, _)| member.clone()));
+        if (members.len() as u64) < params.min_committee_size {
+            return Err(PallasFunError::CommitteeTooSmall {
+                size: members.len(),
+                min: params.min_committee_size,
+            });
+        }
+
+        Ok(Self {
+            added: added.len(),
+            removed: removed.len(),
+            size: members.len(),
+            quorum: rational_pair(&quorum),
+        })
+    }
+}
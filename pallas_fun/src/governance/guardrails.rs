@@ -0,0 +1,443 @@
+// Offline checks of a parameter update against the guardrails of the Cardano
+// constitution (Appendix I). Rule ids follow the constitution.
+use pallas::ledger::primitives::RationalNumber;
+use serde::Serialize;
+
+use crate::cost_models::{PlutusVersion, check_cost_model_length};
+use crate::protocol_params::ProtocolParams;
+use crate::utils::{IntoInner, compare_rationals, rational_pair};
+use crate::wrapper::ProtocolParamUpdateWrapper;
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct GuardrailViolation {
+    pub rule: &'static str,
+    pub field: &'static str,
+    pub message: String,
+}
+
+// Checks every parameter set in `update`. Rules relating two parameters are
+// checked against `current` with the update applied.
+pub fn check_guardrails(
+    update: &ProtocolParamUpdateWrapper,
+    current: &ProtocolParams,
+) -> Vec<GuardrailViolation> {
+    let u = update.into_inner();
+    let next = current.apply(update);
+    let mut checks = Checks::default();
+
+    if let Some(v) = u.minfee_a {
+        checks.range(("TFB-01", "TFB-02"), "minfee_a", v, 30, 1_000);
+    }
+    if let Some(v) = u.minfee_b {
+        checks.range(("TFF-01", "TFF-02"), "minfee_b", v, 100_000, 1_000_000);
+    }
+    if let Some(v) = u.max_block_body_size {
+        checks.range(
+            ("MBBS-02", "MBBS-01"),
+            "max_block_body_size",
+            v,
+            24_576,
+            122_880,
+        );
+        checks.at_most(
+            "MBBS-03",
+            "max_block_body_size",
+            v,
+            current.max_block_body_size.saturating_add(10_240),
+        );
+    }
+    if let Some(v) = u.max_transaction_size {
+        checks.at_most("MTS-01", "max_transaction_size", v, 32_768);
+        checks.at_least(
+            "MTS-03",
+            "max_transaction_size",
+            v,
+            current.max_transaction_size,
+        );
+    }
+    if u.max_transaction_size.is_some() || u.max_block_body_size.is_some() {
+        checks.at_most(
+            "MTS-04",
+            "max_transaction_size",
+            next.max_transaction_size,
+            next.max_block_body_size,
+        );
+    }
+    if let Some(v) = u.max_block_header_size {
+        checks.at_most("MBHS-01", "max_block_header_size", v, 5_000);
+    }
+    if let Some(v) = u.key_deposit {
+        checks.range(("SAD-01", "SAD-02"), "key_deposit", v, 1_000_000, 5_000_000);
+    }
+    if let Some(v) = u.pool_deposit {
+        checks.range(
+            ("SPD-01", "SPD-02"),
+            "pool_deposit",
+            v,
+            250_000_000,
+            500_000_000,
+        );
+    }
+    if let Some(v) = u.maximum_epoch {
+        // EMAX-02 (not negative) holds for any u64
+        checks.range(("EMAX-03", "EMAX-01"), "maximum_epoch", v, 21, 365);
+    }
+    if let Some(v) = u.desired_number_of_stake_pools {
+        checks.range(
+            ("NOPT-01", "NOPT-02"),
+            "desired_number_of_stake_pools",
+            v,
+            250,
+            2_000,
+        );
+    }
+    if let Some(v) = &u.pool_pledge_influence {
+        checks.ratio_range(
+            ("PPI-01", "PPI-02"),
+            "pool_pledge_influence",
+            v,
+            (1, 10),
+            (1, 1),
+        );
+    }
+    if let Some(v) = &u.expansion_rate {
+        checks.ratio_range(
+            ("ME-02", "ME-01"),
+            "expansion_rate",
+            v,
+            (1, 1_000),
+            (5, 1_000),
+        );
+    }
+    if let Some(v) = &u.treasury_growth_rate {
+        checks.ratio_range(
+            ("TC-01", "TC-02"),
+            "treasury_growth_rate",
+            v,
+            (1, 10),
+            (3, 10),
+        );
+    }
+    if let Some(v) = u.min_pool_cost {
+        checks.at_most("MPC-02", "min_pool_cost", v, 500_000_000);
+    }
+    if let Some(v) = u.ada_per_utxo_byte {
+        checks.range(("UCPB-01", "UCPB-02"), "ada_per_utxo_byte", v, 3_000, 6_500);
+    }
+    if let Some(cost_models) = &u.cost_models_for_script_languages {
+        let models = [
+            (
+                "cost_models_for_script_languages.plutus_v1",
+                PlutusVersion::V1,
+                &cost_models.plutus_v1,
+            ),
+            (
+                "cost_models_for_script_languages.plutus_v2",
+                PlutusVersion::V2,
+                &cost_models.plutus_v2,
+            ),
+            (
+                "cost_models_for_script_languages.plutus_v3",
+                PlutusVersion::V3,
+                &cost_models.plutus_v3,
+            ),
+        ];
+        // not a constitution rule: the ledger rejects a model of the wrong length
+        for (field, version, model) in models {
+            if let Some(model) = model
+                && let Err(e) = check_cost_model_length(version, model)
+            {
+                checks.violation("CMO-LEN", field, e.to_string());
+            }
+        }
+    }
+    if let Some(prices) = &u.execution_costs {
+        checks.ratio_range(
+            ("PM-02", "PM-01"),
+            "execution_costs.mem_price",
+            &prices.mem_price,
+            (400, 10_000),
+            (2_000, 10_000),
+        );
+        checks.ratio_range(
+            ("PS-02", "PS-01"),
+            "execution_costs.step_price",
+            &prices.step_price,
+            (500, 10_000_000),
+            (2_000, 10_000_000),
+        );
+    }
+    if let Some(v) = u.max_tx_ex_units {
+        checks.at_most("MTEU-M-01", "max_tx_ex_units.mem", v.mem, 40_000_000);
+        checks.at_most(
+            "MTEU-S-01",
+            "max_tx_ex_units.steps",
+            v.steps,
+            15_000_000_000,
+        );
+    }
+    if let Some(v) = u.max_block_ex_units {
+        checks.at_most("MBEU-M-01", "max_block_ex_units.mem", v.mem, 120_000_000);
+        checks.at_most(
+            "MBEU-S-01",
+            "max_block_ex_units.steps",
+            v.steps,
+            40_000_000_000,
+        );
+    }
+    if u.max_tx_ex_units.is_some() || u.max_block_ex_units.is_some() {
+        checks.at_most(
+            "MTEU-M-04",
+            "max_tx_ex_units.mem",
+            next.max_tx_ex_units.mem,
+            next.max_block_ex_units.mem,
+        );
+        checks.at_most(
+            "MTEU-S-04",
+            "max_tx_ex_units.steps",
+            next.max_tx_ex_units.steps,
+            next.max_block_ex_units.steps,
+        );
+    }
+    if let Some(v) = u.max_value_size {
+        checks.at_most("MVS-01", "max_value_size", v, 12_288);
+        checks.at_least("MVS-02", "max_value_size", v, current.max_value_size);
+    }
+    if let Some(v) = u.collateral_percentage {
+        checks.range(("CP-01", "CP-02"), "collateral_percentage", v, 100, 200);
+    }
+    if let Some(v) = u.max_collateral_inputs {
+        checks.at_least("MCI-01", "max_collateral_inputs", v, 1);
+    }
+    if let Some(t) = &u.pool_voting_thresholds {
+        let pool = [
+            (
+                "VT-NC-01",
+                "pool_voting_thresholds.motion_no_confidence",
+                &t.motion_no_confidence,
+            ),
+            (
+                "VT-CC-01",
+                "pool_voting_thresholds.committee_normal",
+                &t.committee_normal,
+            ),
+            (
+                "VT-CC-01",
+                "pool_voting_thresholds.committee_no_confidence",
+                &t.committee_no_confidence,
+            ),
+            (
+                "VT-HF-01",
+                "pool_voting_thresholds.hard_fork_initiation",
+                &t.hard_fork_initiation,
+            ),
+            (
+                "VT-GEN-01",
+                "pool_voting_thresholds.security_voting_threshold",
+                &t.security_voting_threshold,
+            ),
+        ];
+        for (rule, field, threshold) in pool {
+            checks.threshold(rule, field, threshold);
+        }
+    }
+    if let Some(t) = &u.drep_voting_thresholds {
+        let drep = [
+            (
+                "VT-NC-01",
+                "drep_voting_thresholds.motion_no_confidence",
+                &t.motion_no_confidence,
+            ),
+            (
+                "VT-CC-01",
+                "drep_voting_thresholds.committee_normal",
+                &t.committee_normal,
+            ),
+            (
+                "VT-CC-01",
+                "drep_voting_thresholds.committee_no_confidence",
+                &t.committee_no_confidence,
+            ),
+            (
+                "VT-CON-01",
+                "drep_voting_thresholds.update_constitution",
+                &t.update_constitution,
+            ),
+            (
+                "VT-HF-01",
+                "drep_voting_thresholds.hard_fork_initiation",
+                &t.hard_fork_initiation,
+            ),
+            (
+                "VT-GEN-02",
+                "drep_voting_thresholds.pp_network_group",
+                &t.pp_network_group,
+            ),
+            (
+                "VT-GEN-02",
+                "drep_voting_thresholds.pp_economic_group",
+                &t.pp_economic_group,
+            ),
+            (
+                "VT-GEN-02",
+                "drep_voting_thresholds.pp_technical_group",
+                &t.pp_technical_group,
+            ),
+            (
+                "VT-GOV-01",
+                "drep_voting_thresholds.pp_governance_group",
+                &t.pp_governance_group,
+            ),
+            (
+                "VT-GEN-01",
+                "drep_voting_thresholds.treasury_withdrawal",
+                &t.treasury_withdrawal,
+            ),
+        ];
+        for (rule, field, threshold) in drep {
+            checks.threshold(rule, field, threshold);
+        }
+    }
+    if let Some(v) = u.min_committee_size {
+        checks.range(("CMS-02", "CMS-03"), "min_committee_size", v, 3, 10);
+    }
+    if let Some(v) = u.committee_term_limit {
+        checks.range(("CMT-03", "CMT-04"), "committee_term_limit", v, 18, 293);
+    }
+    if let Some(v) = u.governance_action_validity_period {
+        checks.range(
+            ("GAL-01", "GAL-02"),
+            "governance_action_validity_period",
+            v,
+            1,
+            15,
+        );
+    }
+    if let Some(v) = u.governance_action_deposit {
+        checks.range(
+            ("GD-02", "GD-03"),
+            "governance_action_deposit",
+            v,
+            1_000_000,
+            10_000_000_000_000,
+        );
+    }
+    if let Some(v) = u.drep_deposit {
+        checks.range(
+            ("DRD-02", "DRD-03"),
+            "drep_deposit",
+            v,
+            1_000_000,
+            100_000_000_000,
+        );
+    }
+    if let Some(v) = u.drep_inactivity_period {
+        checks.range(("DRA-01", "DRA-02"), "drep_inactivity_period", v, 13, 37);
+    }
+    if (u.drep_inactivity_period.is_some() || u.governance_action_validity_period.is_some())
+        && next.drep_inactivity_period <= next.governance_action_validity_period
+    {
+        checks.violation(
+            "DRA-04",
+            "drep_inactivity_period",
+            format!(
+                "drep_inactivity_period {} must be greater than governance_action_validity_period {}",
+                next.drep_inactivity_period, next.governance_action_validity_period
+            ),
+        );
+    }
+    if let Some(v) = &u.minfee_refscript_cost_per_byte {
+        checks.ratio_range(
+            ("MFRS-02", "MFRS-01"),
+            "minfee_refscript_cost_per_byte",
+            v,
+            (0, 1),
+            (1_000, 1),
+        );
+    }
+
+    checks.violations
+}
+
+#[derive(Default)]
+struct Checks {
+    violations: Vec<GuardrailViolation>,
+}
+
+impl Checks {
+    fn violation(&mut self, rule: &'static str, field: &'static str, message: String) {
+        self.violations.push(GuardrailViolation {
+            rule,
+            field,
+            message,
+        });
+    }
+
+    fn at_least(&mut self, rule: &'static str, field: &'static str, value: u64, min: u64) {
+        if value < min {
+            self.violation(rule, field, format!("{field} {value} is below {min}"));
+        }
+    }
+
+    fn at_most(&mut self, rule: &'static str, field: &'static str, value: u64, max: u64) {
+        if value > max {
+            self.violation(rule, field, format!("{field} {value} exceeds {max}"));
+        }
+    }
+
+    fn range(
+        &mut self,
+        (min_rule, max_rule): (&'static str, &'static str),
+        field: &'static str,
+        value: u64,
+        min: u64,
+        max: u64,
+    ) {
+        self.at_least(min_rule, field, value, min);
+        self.at_most(max_rule, field, value, max);
+    }
+
+    fn ratio_range(
+        &mut self,
+        (min_rule, max_rule): (&'static str, &'static str),
+        field: &'static str,
+        value: &RationalNumber,
+        min: (u64, u64),
+        max: (u64, u64),
+    ) {
+        let v = rational_pair(value);
+        if v.1 == 0 {
+            self.violation(min_rule, field, format!("{field} has a zero denominator"));
+        } else if compare_rationals(v, min).is_lt() {
+            self.violation(
+                min_rule,
+                field,
+                format!("{field} {}/{} is below {}/{}", v.0, v.1, min.0, min.1),
+            );
+        } else if compare_rationals(v, max).is_gt() {
+            self.violation(
+                max_rule,
+                field,
+                format!("{field} {}/{} exceeds {}/{}", v.0, v.1, max.0, max.1),
+            );
+        }
+    }
+
+    // every voting threshold is within 50%-100% (VT-GEN-01), most also within
+    // the narrower range of their own rule
+    fn threshold(&mut self, rule: &'static str, field: &'static str, value: &RationalNumber) {
+        let (min, max) = match rule {
+            "VT-NC-01" | "VT-GEN-02" => ((51, 100), (75, 100)),
+            "VT-CC-01" => ((51, 100), (90, 100)),
+            "VT-CON-01" => ((65, 100), (90, 100)),
+            "VT-HF-01" => ((51, 100), (80, 100)),
+            "VT-GOV-01" => ((75, 100), (90, 100)),
+            _ => ((50, 100), (1, 1)),
+        };
+        let reported = self.violations.len();
+        self.ratio_range(("VT-GEN-01", "VT-GEN-01"), field, value, (50, 100), (1, 1));
+        if self.violations.len() == reported {
+            self.ratio_range((rule, rule), field, value, min, max);
+        }
+    }
+}
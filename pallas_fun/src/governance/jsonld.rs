@@ -0,0 +1,639 @@
+// JSON-LD to RDF and URDNA2015 canonicalization, enough for the CIP-100
+// documents built in `metadata`: inline contexts with prefixes, scoped term
+// contexts, `@type`, `@id` and value objects. Remote contexts, `@vocab`,
+// `@base`, lists and named graphs are rejected. Literal escaping follows
+// jsonld.js (`\\`, `\"`, `\n` and `\r` only), which CIP-100 tools use.
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use cryptoxide::hashing::sha2::Sha256;
+use serde_json::{Map, Value};
+
+use crate::error::PallasFunError;
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum Term {
+    Iri(String),
+    Blank(String),
+    Literal {
+        value: String,
+        datatype: String,
+        language: Option<String>,
+    },
+}
+
+// default graph only
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Quad {
+    subject: Term,
+    predicate: String,
+    object: Term,
+}
+
+// URDNA2015 N-Quads of `document`, one sorted line per quad
+pub(crate) fn canonical_nquads(document: &Value) -> Result<String, PallasFunError> {
+    let mut rdf = ToRdf::default();
+    rdf.document(&Context::default(), document)?;
+    Ok(canonicalize(&rdf.quads))
+}
+
+fn unsupported(what: &str) -> PallasFunError {
+    PallasFunError::Json(format!("unsupported JSON-LD: {}", what))
+}
+
+#[derive(Debug, Default, Clone)]
+struct Context {
+    language: Option<String>,
+    terms: BTreeMap<String, TermDefinition>,
+}
+
+#[derive(Debug, Clone)]
+struct TermDefinition {
+    id: String,
+    scoped: Option<Value>,
+    // `@id` or a datatype IRI
+    coerce: Option<String>,
+}
+
+impl Context {
+    fn with(&self, local: &Value) -> Result<Context, PallasFunError> {
+        match local {
+            Value::Null => Ok(Context::default()),
+            Value::Array(locals) => locals.iter().try_fold(self.clone(), |c, l| c.with(l)),
+            Value::Object(map) => {
+                let mut context = self.clone();
+                for (key, value) in map {
+                    match (key.as_str(), value) {
+                        ("@language", Value::String(language)) => {
+                            context.language = Some(language.to_lowercase())
+                        }
+                        ("@language", Value::Null) => context.language = None,
+                        ("@version", _) => {}
+                        (key, _) if key.starts_with('@') => return Err(unsupported(key)),
+                        (_, Value::Null) => {
+                            context.terms.remove(key);
+                        }
+                        (_, Value::String(id)) => {
+                            let definition = TermDefinition {
+                                id: id.clone(),
+                                scoped: None,
+                                coerce: None,
+                            };
+                            context.terms.insert(key.clone(), definition);
+                        }
+                        (_, Value::Object(definition)) => {
+                            let definition = term_definition(key, definition)?;
+                            context.terms.insert(key.clone(), definition);
+                        }
+                        _ => return Err(unsupported("term definition")),
+                    }
+                }
+                Ok(context)
+            }
+            _ => Err(unsupported("remote context")),
+        }
+    }
+
+    // IRI for a property, type or `@id` value; `None` when it cannot be
+    // expanded and JSON-LD would drop it
+    fn expand(&self, value: &str, vocab: bool) -> Option<String> {
+        self.expand_at(value, vocab, 0)
+    }
+
+    fn expand_at(&self, value: &str, vocab: bool, depth: usize) -> Option<String> {
+        if depth > 8 {
+            return None;
+        }
+        if value.starts_with('@') {
+            return Some(value.to_string());
+        }
+        if vocab && let Some(definition) = self.terms.get(value) {
+            return self.expand_at(&definition.id, true, depth + 1);
+        }
+        let (prefix, suffix) = value.split_once(':')?;
+        if prefix == "_" || suffix.starts_with("//") {
+            return Some(value.to_string());
+        }
+        match self.terms.get(prefix) {
+            Some(definition) => Some(self.expand_at(&definition.id, true, depth + 1)? + suffix),
+            None => Some(value.to_string()),
+        }
+    }
+}
+
+fn term_definition(
+    key: &str,
+    definition: &Map<String, Value>,
+) -> Result<TermDefinition, PallasFunError> {
+    let id = match definition.get("@id") {
+        Some(Value::String(id)) => id.clone(),
+        None if key.contains(':') => key.to_string(),
+        _ => return Err(unsupported("term definition without @id")),
+    };
+    if let Some(container) = definition.get("@container")
+        && container != "@set"
+    {
+        return Err(unsupported("@container other than @set"));
+    }
+    let coerce = match definition.get("@type") {
+        Some(Value::String(coerce)) => Some(coerce.clone()),
+        None => None,
+        _ => return Err(unsupported("@type coercion")),
+    };
+    Ok(TermDefinition {
+        id,
+        scoped: definition.get("@context").cloned(),
+        coerce,
+    })
+}
+
+#[derive(Default)]
+struct ToRdf {
+    quads: Vec<Quad>,
+    blank_nodes: usize,
+}
+
+impl ToRdf {
+    fn document(&mut self, context: &Context, value: &Value) -> Result<(), PallasFunError> {
+        match value {
+            Value::Array(nodes) => nodes.iter().try_for_each(|n| self.document(context, n)),
+            Value::Object(node) => self.node(context, node).map(|_| ()),
+            _ => Err(unsupported("top-level value")),
+        }
+    }
+
+    fn blank_node(&mut self) -> Term {
+        self.blank_nodes += 1;
+        Term::Blank(format!("b{}", self.blank_nodes - 1))
+    }
+
+    fn node(
+        &mut self,
+        context: &Context,
+        node: &Map<String, Value>,
+    ) -> Result<Term, PallasFunError> {
+        let context = match node.get("@context") {
+            Some(local) => context.with(local)?,
+            None => context.clone(),
+        };
+        let subject = match node.get("@id") {
+            Some(Value::String(id)) => identifier(&context, id)?,
+            Some(_) => return Err(unsupported("@id")),
+            None => self.blank_node(),
+        };
+
+        for (key, value) in node {
+            match key.as_str() {
+                "@context" | "@id" => continue,
+                "@type" => {
+                    for kind in one_or_many(value) {
+                        let iri = kind
+                            .as_str()
+                            .and_then(|kind| context.expand(kind, true))
+                            .filter(|iri| iri.contains(':'))
+                            .ok_or_else(|| unsupported("@type"))?;
+                        self.quads.push(Quad {
+                            subject: subject.clone(),
+                            predicate: RDF_TYPE.to_string(),
+                            object: to_term(&iri),
+                        });
+                    }
+                    continue;
+                }
+                key if key.starts_with('@') => return Err(unsupported(key)),
+                _ => {}
+            }
+            // undefined terms and blank node properties are dropped
+            let Some(predicate) = context.expand(key, true) else {
+                continue;
+            };
+            if !predicate.contains(':') || predicate.starts_with("_:") {
+                continue;
+            }
+            let definition = context.terms.get(key);
+            let value_context = match definition.and_then(|d| d.scoped.as_ref()) {
+                Some(scoped) => context.with(scoped)?,
+                None => context.clone(),
+            };
+            let coerce = definition.and_then(|d| d.coerce.as_deref());
+            for object in self.objects(&value_context, coerce, value)? {
+                self.quads.push(Quad {
+                    subject: subject.clone(),
+                    predicate: predicate.clone(),
+                    object,
+                });
+            }
+        }
+        Ok(subject)
+    }
+
+    fn objects(
+        &mut self,
+        context: &Context,
+        coerce: Option<&str>,
+        value: &Value,
+    ) -> Result<Vec<Term>, PallasFunError> {
+        let object = match value {
+            Value::Null => return Ok(vec![]),
+            Value::Array(values) => {
+                let mut objects = Vec::new();
+                for value in values {
+                    objects.extend(self.objects(context, coerce, value)?);
+                }
+                return Ok(objects);
+            }
+            Value::Object(map) if map.contains_key("@value") => value_object(context, map)?,
+            Value::Object(map) => self.node(context, map)?,
+            Value::String(s) => match coerce {
+                Some("@id") => identifier(context, s)?,
+                Some(datatype) => literal(s.clone(), expand_datatype(context, datatype)?),
+                None => match &context.language {
+                    Some(language) => Term::Literal {
+                        value: s.clone(),
+                        datatype: RDF_LANG_STRING.to_string(),
+                        language: Some(language.clone()),
+                    },
+                    None => literal(s.clone(), XSD_STRING.to_string()),
+                },
+            },
+            Value::Bool(b) => literal(b.to_string(), XSD_BOOLEAN.to_string()),
+            Value::Number(n) => number(n),
+        };
+        Ok(vec![object])
+    }
+}
+
+fn one_or_many(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    }
+}
+
+fn to_term(iri: &str) -> Term {
+    match iri.strip_prefix("_:") {
+        Some(label) => Term::Blank(format!("id-{}", label)),
+        None => Term::Iri(iri.to_string()),
+    }
+}
+
+// `@id` values; there is no base IRI, so relative ones are rejected
+fn identifier(context: &Context, id: &str) -> Result<Term, PallasFunError> {
+    context
+        .expand(id, false)
+        .map(|iri| to_term(&iri))
+        .ok_or_else(|| unsupported("relative @id"))
+}
+
+fn expand_datatype(context: &Context, datatype: &str) -> Result<String, PallasFunError> {
+    context
+        .expand(datatype, true)
+        .filter(|iri| !iri.starts_with('@') && iri.contains(':'))
+        .ok_or_else(|| unsupported("@type"))
+}
+
+fn literal(value: String, datatype: String) -> Term {
+    Term::Literal {
+        value,
+        datatype,
+        language: None,
+    }
+}
+
+fn number(n: &serde_json::Number) -> Term {
+    if n.is_i64() || n.is_u64() {
+        return literal(n.to_string(), XSD_INTEGER.to_string());
+    }
+    // canonical xsd:double, e.g. 1.5E0
+    let exponent = format!("{:E}", n.as_f64().unwrap_or_default());
+    let value = match exponent.split_once('E') {
+        Some((mantissa, exponent)) if !mantissa.contains('.') => {
+            format!("{}.0E{}", mantissa, exponent)
+        }
+        _ => exponent,
+    };
+    literal(value, XSD_DOUBLE.to_string())
+}
+
+fn value_object(context: &Context, map: &Map<String, Value>) -> Result<Term, PallasFunError> {
+    let datatype = match map.get("@type") {
+        Some(Value::String(datatype)) => Some(expand_datatype(context, datatype)?),
+        None => None,
+        _ => return Err(unsupported("@type")),
+    };
+    let language = match map.get("@language") {
+        Some(Value::String(language)) => Some(language.to_lowercase()),
+        None => None,
+        _ => return Err(unsupported("@language")),
+    };
+    match (&map["@value"], datatype, language) {
+        (Value::String(s), None, Some(language)) => Ok(Term::Literal {
+            value: s.clone(),
+            datatype: RDF_LANG_STRING.to_string(),
+            language: Some(language),
+        }),
+        (Value::String(s), datatype, None) => Ok(literal(
+            s.clone(),
+            datatype.unwrap_or_else(|| XSD_STRING.to_string()),
+        )),
+        (Value::Bool(b), None, None) => Ok(literal(b.to_string(), XSD_BOOLEAN.to_string())),
+        (Value::Number(n), None, None) => Ok(number(n)),
+        _ => Err(unsupported("@value")),
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// `label` renames the blank nodes of the quad
+fn nquad(quad: &Quad, label: &impl Fn(&str) -> String) -> String {
+    let term = |term: &Term| match term {
+        Term::Iri(iri) => format!("<{}>", iri),
+        Term::Blank(id) => label(id),
+        Term::Literal {
+            value,
+            datatype,
+            language,
+        } => match language {
+            Some(language) => format!("\"{}\"@{}", escape(value), language),
+            None if datatype == XSD_STRING => format!("\"{}\"", escape(value)),
+            None => format!("\"{}\"^^<{}>", escape(value), datatype),
+        },
+    };
+    format!(
+        "{} <{}> {} .\n",
+        term(&quad.subject),
+        quad.predicate,
+        term(&quad.object)
+    )
+}
+
+fn sha256_hex(data: &str) -> String {
+    hex::encode(Sha256::new().update(data.as_bytes()).finalize())
+}
+
+#[derive(Clone)]
+struct IdentifierIssuer {
+    prefix: &'static str,
+    issued: Vec<String>,
+    identifiers: HashMap<String, String>,
+}
+
+impl IdentifierIssuer {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            issued: Vec::new(),
+            identifiers: HashMap::new(),
+        }
+    }
+
+    fn issue(&mut self, id: &str) -> String {
+        if let Some(issued) = self.identifiers.get(id) {
+            return issued.clone();
+        }
+        let issued = format!("{}{}", self.prefix, self.issued.len());
+        self.issued.push(id.to_string());
+        self.identifiers.insert(id.to_string(), issued.clone());
+        issued
+    }
+
+    fn get(&self, id: &str) -> Option<&String> {
+        self.identifiers.get(id)
+    }
+}
+
+struct Canonicalizer<'a> {
+    quads: &'a [Quad],
+    // quads mentioning each blank node
+    blank_node_quads: BTreeMap<String, Vec<usize>>,
+    canonical: IdentifierIssuer,
+}
+
+fn canonicalize(quads: &[Quad]) -> String {
+    // a dataset is a set, repeated quads would change the hashes
+    let mut seen = HashSet::new();
+    let quads: Vec<Quad> = quads.iter().filter(|q| seen.insert(*q)).cloned().collect();
+    let quads = quads.as_slice();
+    let mut blank_node_quads: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, quad) in quads.iter().enumerate() {
+        for term in [&quad.subject, &quad.object] {
+            if let Term::Blank(id) = term {
+                let entry = blank_node_quads.entry(id.clone()).or_default();
+                if entry.last() != Some(&i) {
+                    entry.push(i);
+                }
+            }
+        }
+    }
+    let mut c = Canonicalizer {
+        quads,
+        blank_node_quads,
+        canonical: IdentifierIssuer::new("_:c14n"),
+    };
+
+    let mut hash_to_blank_nodes: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for id in c.blank_node_quads.keys() {
+        hash_to_blank_nodes
+            .entry(c.hash_first_degree(id))
+            .or_default()
+            .push(id.clone());
+    }
+    // unique first degree hashes are labelled first, in hash order
+    hash_to_blank_nodes.retain(|_, ids| {
+        if ids.len() > 1 {
+            return true;
+        }
+        c.canonical.issue(&ids[0]);
+        false
+    });
+    for ids in hash_to_blank_nodes.values() {
+        let mut paths = Vec::new();
+        for id in ids {
+            if c.canonical.get(id).is_some() {
+                continue;
+            }
+            let mut issuer = IdentifierIssuer::new("_:b");
+            issuer.issue(id);
+            paths.push(c.hash_n_degree(id, issuer));
+        }
+        paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (_, issuer) in paths {
+            for id in &issuer.issued {
+                c.canonical.issue(id);
+            }
+        }
+    }
+
+    let label = |id: &str| c.canonical.get(id).cloned().unwrap_or_default();
+    let mut lines: Vec<String> = quads.iter().map(|q| nquad(q, &label)).collect();
+    lines.sort();
+    lines.concat()
+}
+
+impl Canonicalizer<'_> {
+    fn hash_first_degree(&self, id: &str) -> String {
+        let label = |other: &str| if other == id { "_:a" } else { "_:z" }.to_string();
+        let mut lines: Vec<String> = self.blank_node_quads[id]
+            .iter()
+            .map(|i| nquad(&self.quads[*i], &label))
+            .collect();
+        lines.sort();
+        sha256_hex(&lines.concat())
+    }
+
+    fn hash_related(
+        &self,
+        related: &str,
+        quad: &Quad,
+        issuer: &IdentifierIssuer,
+        position: &str,
+    ) -> String {
+        let identifier = match (self.canonical.get(related), issuer.get(related)) {
+            (Some(id), _) | (None, Some(id)) => id.clone(),
+            (None, None) => self.hash_first_degree(related),
+        };
+        sha256_hex(&format!("{}<{}>{}", position, quad.predicate, identifier))
+    }
+
+    fn hash_n_degree(&self, id: &str, mut issuer: IdentifierIssuer) -> (String, IdentifierIssuer) {
+        let mut hash_to_related: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for i in &self.blank_node_quads[id] {
+            let quad = &self.quads[*i];
+            for (term, position) in [(&quad.subject, "s"), (&quad.object, "o")] {
+                if let Term::Blank(related) = term
+                    && related != id
+                {
+                    let hash = self.hash_related(related, quad, &issuer, position);
+                    hash_to_related
+                        .entry(hash)
+                        .or_default()
+                        .push(related.clone());
+                }
+            }
+        }
+
+        let mut data = String::new();
+        for (hash, related) in hash_to_related {
+            data.push_str(&hash);
+            let mut chosen: Option<(String, IdentifierIssuer)> = None;
+            for permutation in permutations(&related) {
+                let mut issuer_copy = issuer.clone();
+                let mut path = String::new();
+                let mut recursion = Vec::new();
+                let longer = |path: &String, chosen: &Option<(String, IdentifierIssuer)>| {
+                    chosen.as_ref().is_some_and(|(chosen, _)| {
+                        path.len() >= chosen.len() && path.as_str() > chosen.as_str()
+                    })
+                };
+                for related in &permutation {
+                    match self.canonical.get(related) {
+                        Some(canonical) => path.push_str(canonical),
+                        None => {
+                            if issuer_copy.get(related).is_none() {
+                                recursion.push(related.clone());
+                            }
+                            path.push_str(&issuer_copy.issue(related));
+                        }
+                    }
+                    if longer(&path, &chosen) {
+                        break;
+                    }
+                }
+                if longer(&path, &chosen) {
+                    continue;
+                }
+                let mut skipped = false;
+                for related in recursion {
+                    let (hash, result_issuer) = self.hash_n_degree(&related, issuer_copy.clone());
+                    path.push_str(&issuer_copy.issue(&related));
+                    path.push_str(&format!("<{}>", hash));
+                    issuer_copy = result_issuer;
+                    if longer(&path, &chosen) {
+                        skipped = true;
+                        break;
+                    }
+                }
+                if skipped {
+                    continue;
+                }
+                if chosen.as_ref().is_none_or(|(chosen, _)| path < *chosen) {
+                    chosen = Some((path, issuer_copy));
+                }
+            }
+            if let Some((path, chosen_issuer)) = chosen {
+                data.push_str(&path);
+                issuer = chosen_issuer;
+            }
+        }
+        (sha256_hex(&data), issuer)
+    }
+}
+
+fn permutations(items: &[String]) -> Vec<Vec<String>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    let mut result = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, first.clone());
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_canonical_nquads_ignores_key_and_node_order() {
+        let context = json!({
+            "@language": "en-us",
+            "ex": "https://example.com/#",
+            "name": "ex:name",
+            "knows": { "@id": "ex:knows", "@container": "@set" },
+        });
+        let a = json!({
+            "@context": context,
+            "name": "root",
+            "knows": [{ "name": "a" }, { "name": "b" }, { "name": "a" }],
+            "ignored": "not in the context",
+        });
+        let b = json!({
+            "knows": [{ "name": "b" }, { "name": "a" }, { "name": "a" }],
+            "name": "root",
+            "@context": context,
+        });
+        let nquads = canonical_nquads(&a).unwrap();
+        assert_eq!(nquads, canonical_nquads(&b).unwrap());
+        assert_eq!(nquads.lines().count(), 7);
+        assert!(nquads.contains("<https://example.com/#name> \"root\"@en-us .\n"));
+        assert!(!nquads.contains("not in the context"));
+
+        assert!(canonical_nquads(&json!({ "@context": "https://example.com/context" })).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+// CIP-100 governance metadata documents: CIP-108 proposals, CIP-119 DRep
+// profiles and CIP-100 vote rationales, serialized for publishing behind an
+// anchor
+use pallas::crypto::hash::{Hash, Hasher};
+use pallas::crypto::key::ed25519::{PublicKey, Signature};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+use super::jsonld::canonical_nquads;
+use crate::error::PallasFunError;
+use crate::wrapper::AnchorWrapper;
+
+const CIP100: &str = "https://github.com/cardano-foundation/CIPs/blob/master/CIP-0100/README.md#";
+const CIP108: &str = "https://github.com/cardano-foundation/CIPs/blob/master/CIP-0108/README.md#";
+const CIP119: &str = "https://github.com/cardano-foundation/CIPs/blob/master/CIP-0119/README.md#";
+
+// `GovernanceMetadata` and `Other` come from CIP-100, `Link` and `Identity`
+// from CIP-119
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReferenceKind {
+    GovernanceMetadata,
+    Other,
+    Link,
+    Identity,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Reference {
+    #[serde(rename = "@type")]
+    pub kind: ReferenceKind,
+    pub label: String,
+    pub uri: String,
+}
+
+// CIP-100 author. The witness is an ed25519 signature of
+// `AnchorDocument::body_hash`, made with the author's own key.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Author {
+    pub name: String,
+    pub witness: AuthorWitness,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorWitness {
+    pub witness_algorithm: String,
+    pub public_key: String,
+    pub signature: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ProposalBody {
+    pub title: String,
+    pub r#abstract: String,
+    pub motivation: String,
+    pub rationale: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DRepBody {
+    pub given_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub objectives: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub motivations: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qualifications: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub do_not_list: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct VoteRationaleBody {
+    pub comment: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AnchorDocumentBody {
+    Proposal(ProposalBody),
+    DRep(DRepBody),
+    VoteRationale(VoteRationaleBody),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AnchorDocument {
+    pub authors: Vec<Author>,
+    pub body: AnchorDocumentBody,
+}
+
+impl AnchorDocument {
+    // JSON-LD document with the `@context` of the body's CIP
+    pub fn to_json(&self) -> Result<Value, PallasFunError> {
+        let (context, body) = match &self.body {
+            AnchorDocumentBody::Proposal(body) => (
+                context(
+                    ("CIP108", CIP108),
+                    &["title", "abstract", "motivation", "rationale"],
+                    &[ReferenceKind::GovernanceMetadata, ReferenceKind::Other],
+                ),
+                serde_json::to_value(body),
+            ),
+            AnchorDocumentBody::DRep(body) => (
+                context(
+                    ("CIP119", CIP119),
+                    &[
+                        "givenName",
+                        "paymentAddress",
+                        "objectives",
+                        "motivations",
+                        "qualifications",
+                        "doNotList",
+                    ],
+                    &[ReferenceKind::Link, ReferenceKind::Identity],
+                ),
+                serde_json::to_value(body),
+            ),
+            AnchorDocumentBody::VoteRationale(body) => (
+                context(
+                    ("CIP100", CIP100),
+                    &["comment"],
+                    &[ReferenceKind::GovernanceMetadata, ReferenceKind::Other],
+                ),
+                serde_json::to_value(body),
+            ),
+        };
+        let body = body.map_err(|e| PallasFunError::Json(e.to_string()))?;
+        let authors =
+            serde_json::to_value(&self.authors).map_err(|e| PallasFunError::Json(e.to_string()))?;
+
+        Ok(json!({
+            "@context": context,
+            "hashAlgorithm": "blake2b-256",
+            "authors": authors,
+            "body": body,
+        }))
+    }
+
+    // Canonical JSON (RFC 8785) of `to_json`, the bytes to publish. serde_json
+    // is built without `preserve_order`, so object keys come out sorted, and
+    // the documents hold no floats.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, PallasFunError> {
+        serde_json::to_vec(&self.to_json()?).map_err(|e| PallasFunError::Json(e.to_string()))
+    }
+
+    // URDNA2015 canonical N-Quads of the JSON-LD document
+    pub fn to_canonical_nquads(&self) -> Result<String, PallasFunError> {
+        canonical_nquads(&self.to_json()?)
+    }
+
+    // blake2b-256 of the canonical N-Quads of `@context` and `body` only,
+    // which CIP-100 author witnesses sign
+    pub fn body_hash(&self) -> Result<Hash<32>, PallasFunError> {
+        let json = self.to_json()?;
+        let body = json!({ "@context": json["@context"], "body": json["body"] });
+        Ok(Hasher::<256>::hash(canonical_nquads(&body)?.as_bytes()))
+    }
+
+    // every author witness is an ed25519 signature of `body_hash`
+    pub fn verify_authors(&self) -> Result<(), PallasFunError> {
+        let body_hash = self.body_hash()?;
+        for (i, author) in self.authors.iter().enumerate() {
+            verify_witness(&author.witness, &body_hash)
+                .map_err(|e| e.at(format!("authors[{}]", i)))?;
+        }
+        Ok(())
+    }
+
+    // anchor for the document once the bytes from `to_bytes` are served at
+    // `url`
+    pub fn anchor(&self, url: String) -> Result<AnchorWrapper, PallasFunError> {
+        AnchorWrapper::from_content(url, &self.to_bytes()?)
+    }
+}
+
+fn verify_witness(witness: &AuthorWitness, body_hash: &Hash<32>) -> Result<(), PallasFunError> {
+    if witness.witness_algorithm != "ed25519" {
+        return Err(PallasFunError::InvalidAuthorWitness(format!(
+            "unsupported witness algorithm {}",
+            witness.witness_algorithm
+        )));
+    }
+    let public_key: PublicKey = witness
+        .public_key
+        .parse()
+        .map_err(|_| PallasFunError::InvalidAuthorWitness("malformed public key".to_string()))?;
+    let signature: Signature = witness
+        .signature
+        .parse()
+        .map_err(|_| PallasFunError::InvalidAuthorWitness("malformed signature".to_string()))?;
+    if !public_key.verify(body_hash, &signature) {
+        return Err(PallasFunError::InvalidAuthorWitness(
+            "signature does not match the body hash".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn context(cip: (&str, &str), fields: &[&str], reference_kinds: &[ReferenceKind]) -> Value {
+    let (prefix, iri) = cip;
+    let mut body = Map::new();
+    for field in fields {
+        body.insert(field.to_string(), json!(format!("{}:{}", prefix, field)));
+    }
+    let mut references = Map::new();
+    for kind in reference_kinds {
+        let (kind_prefix, name) = match kind {
+            ReferenceKind::GovernanceMetadata => ("CIP100", "GovernanceMetadataReference"),
+            ReferenceKind::Other => ("CIP100", "OtherReference"),
+            ReferenceKind::Link => ("CIP119", "LinkReference"),
+            ReferenceKind::Identity => ("CIP119", "IdentityReference"),
+        };
+        references.insert(
+            format!("{:?}", kind),
+            json!(format!("{}:{}", kind_prefix, name)),
+        );
+    }
+    references.insert("label".to_string(), json!("CIP100:reference-label"));
+    references.insert("uri".to_string(), json!("CIP100:reference-uri"));
+    body.insert(
+        "references".to_string(),
+        json!({
+            "@id": format!("{}:references", prefix),
+            "@container": "@set",
+            "@context": references,
+        }),
+    );
+
+    let mut context = json!({
+        "@language": "en-us",
+        "CIP100": CIP100,
+        "hashAlgorithm": "CIP100:hashAlgorithm",
+        "body": {
+            "@id": format!("{}:body", prefix),
+            "@context": body,
+        },
+        "authors": {
+            "@id": "CIP100:authors",
+            "@container": "@set",
+            "@context": {
+                "name": "http://xmlns.com/foaf/0.1/name",
+                "witness": {
+                    "@id": "CIP100:witness",
+                    "@context": {
+                        "witnessAlgorithm": "CIP100:witnessAlgorithm",
+                        "publicKey": "CIP100:publicKey",
+                        "signature": "CIP100:signature",
+                    },
+                },
+            },
+        },
+    });
+    context[prefix] = json!(iri);
+    if reference_kinds.contains(&ReferenceKind::Link) {
+        context["CIP119"] = json!(CIP119);
+    }
+    context
+}
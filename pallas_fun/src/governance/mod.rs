@@ -0,0 +1,20 @@
+mod ballot;
+mod committee;
+mod guardrails;
+mod jsonld;
+mod metadata;
+mod param_diff;
+mod param_groups;
+mod prerequisites;
+mod ratification;
+mod treasury;
+
+pub use ballot::*;
+pub use committee::*;
+pub use guardrails::*;
+pub use metadata::*;
+pub use param_diff::*;
+pub use param_groups::*;
+pub use prerequisites::*;
+pub use ratification::*;
+pub use treasury::*;
@@ -0,0 +1,352 @@
+// Old and new value of every parameter a `ProtocolParamUpdateWrapper` touches,
+// for showing voters what a parameter change proposal does
+use std::fmt;
+
+use pallas::ledger::primitives::RationalNumber;
+use pallas::ledger::primitives::conway::{DRepVotingThresholds, PoolVotingThresholds};
+use serde::Serialize;
+
+use crate::error::PallasFunError;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::{IntoInner, format_decimal_rational};
+use crate::wrapper::{CostModelsWrapper, ProtocolParamUpdateWrapper};
+
+// Values are rendered for display, rationals as decimals. Composite parameters
+// are split into their parts, e.g. `max_tx_ex_units.mem`.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ParamFieldDiff {
+    pub field: String,
+    pub current: String,
+    pub proposed: String,
+}
+
+impl ParamFieldDiff {
+    pub fn is_changed(&self) -> bool {
+        self.current != self.proposed
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ParamUpdateDiff {
+    pub fields: Vec<ParamFieldDiff>,
+}
+
+impl ParamUpdateDiff {
+    // Cost models are compared element by element and only changed elements
+    // are listed; every other touched field is listed even if unchanged.
+    pub fn new(
+        current: &ProtocolParams,
+        update: &ProtocolParamUpdateWrapper,
+    ) -> Result<Self, PallasFunError> {
+        let u = update.into_inner();
+        let mut diff = Self { fields: Vec::new() };
+
+        if let Some(v) = u.minfee_a {
+            diff.push("minfee_a", current.minfee_a, v);
+        }
+        if let Some(v) = u.minfee_b {
+            diff.push("minfee_b", current.minfee_b, v);
+        }
+        if let Some(v) = u.max_block_body_size {
+            diff.push("max_block_body_size", current.max_block_body_size, v);
+        }
+        if let Some(v) = u.max_transaction_size {
+            diff.push("max_transaction_size", current.max_transaction_size, v);
+        }
+        if let Some(v) = u.max_block_header_size {
+            diff.push("max_block_header_size", current.max_block_header_size, v);
+        }
+        if let Some(v) = u.key_deposit {
+            diff.push("key_deposit", current.key_deposit, v);
+        }
+        if let Some(v) = u.pool_deposit {
+            diff.push("pool_deposit", current.pool_deposit, v);
+        }
+        if let Some(v) = u.maximum_epoch {
+            diff.push("maximum_epoch", current.maximum_epoch, v);
+        }
+        if let Some(v) = u.desired_number_of_stake_pools {
+            diff.push(
+                "desired_number_of_stake_pools",
+                current.desired_number_of_stake_pools,
+                v,
+            );
+        }
+        if let Some(v) = &u.pool_pledge_influence {
+            diff.rational("pool_pledge_influence", &current.pool_pledge_influence, v);
+        }
+        if let Some(v) = &u.expansion_rate {
+            diff.rational("expansion_rate", &current.expansion_rate, v);
+        }
+        if let Some(v) = &u.treasury_growth_rate {
+            diff.rational("treasury_growth_rate", &current.treasury_growth_rate, v);
+        }
+        if let Some(v) = u.min_pool_cost {
+            diff.push("min_pool_cost", current.min_pool_cost, v);
+        }
+        if let Some(v) = u.ada_per_utxo_byte {
+            diff.push("ada_per_utxo_byte", current.ada_per_utxo_byte, v);
+        }
+        if let Some(v) = &u.cost_models_for_script_languages {
+            let current = &current.cost_models_for_script_languages;
+            let current = CostModelsWrapper {
+                plutus_v1: current.plutus_v1.clone(),
+                plutus_v2: current.plutus_v2.clone(),
+                plutus_v3: current.plutus_v3.clone(),
+            };
+            let proposed = CostModelsWrapper {
+                plutus_v1: v.plutus_v1.clone(),
+                plutus_v2: v.plutus_v2.clone(),
+                plutus_v3: v.plutus_v3.clone(),
+            };
+            let changes = current
+                .diff(&proposed)
+                .map_err(|e| e.at("cost_models_for_script_languages"))?;
+            for change in changes {
+                diff.push(
+                    format!("cost_models.{}.{}", change.version.name(), change.name),
+                    missing_as_dash(change.current),
+                    missing_as_dash(change.proposed),
+                );
+            }
+        }
+        if let Some(v) = &u.execution_costs {
+            diff.rational(
+                "execution_costs.mem_price",
+                &current.execution_costs.mem_price,
+                &v.mem_price,
+            );
+            diff.rational(
+                "execution_costs.step_price",
+                &current.execution_costs.step_price,
+                &v.step_price,
+            );
+        }
+        if let Some(v) = u.max_tx_ex_units {
+            diff.push("max_tx_ex_units.mem", current.max_tx_ex_units.mem, v.mem);
+            diff.push(
+                "max_tx_ex_units.steps",
+                current.max_tx_ex_units.steps,
+                v.steps,
+            );
+        }
+        if let Some(v) = u.max_block_ex_units {
+            diff.push(
+                "max_block_ex_units.mem",
+                current.max_block_ex_units.mem,
+                v.mem,
+            );
+            diff.push(
+                "max_block_ex_units.steps",
+                current.max_block_ex_units.steps,
+                v.steps,
+            );
+        }
+        if let Some(v) = u.max_value_size {
+            diff.push("max_value_size", current.max_value_size, v);
+        }
+        if let Some(v) = u.collateral_percentage {
+            diff.push("collateral_percentage", current.collateral_percentage, v);
+        }
+        if let Some(v) = u.max_collateral_inputs {
+            diff.push("max_collateral_inputs", current.max_collateral_inputs, v);
+        }
+        if let Some(v) = &u.pool_voting_thresholds {
+            for (name, current, proposed) in pool_thresholds(&current.pool_voting_thresholds, v) {
+                diff.rational(
+                    format!("pool_voting_thresholds.{}", name),
+                    current,
+                    proposed,
+                );
+            }
+        }
+        if let Some(v) = &u.drep_voting_thresholds {
+            for (name, current, proposed) in drep_thresholds(&current.drep_voting_thresholds, v) {
+                diff.rational(
+                    format!("drep_voting_thresholds.{}", name),
+                    current,
+                    proposed,
+                );
+            }
+        }
+        if let Some(v) = u.min_committee_size {
+            diff.push("min_committee_size", current.min_committee_size, v);
+        }
+        if let Some(v) = u.committee_term_limit {
+            diff.push("committee_term_limit", current.committee_term_limit, v);
+        }
+        if let Some(v) = u.governance_action_validity_period {
+            diff.push(
+                "governance_action_validity_period",
+                current.governance_action_validity_period,
+                v,
+            );
+        }
+        if let Some(v) = u.governance_action_deposit {
+            diff.push(
+                "governance_action_deposit",
+                current.governance_action_deposit,
+                v,
+            );
+        }
+        if let Some(v) = u.drep_deposit {
+            diff.push("drep_deposit", current.drep_deposit, v);
+        }
+        if let Some(v) = u.drep_inactivity_period {
+            diff.push("drep_inactivity_period", current.drep_inactivity_period, v);
+        }
+        if let Some(v) = &u.minfee_refscript_cost_per_byte {
+            diff.rational(
+                "minfee_refscript_cost_per_byte",
+                &current.minfee_refscript_cost_per_byte,
+                v,
+            );
+        }
+
+        Ok(diff)
+    }
+
+    pub fn changed(&self) -> impl Iterator<Item = &ParamFieldDiff> {
+        self.fields.iter().filter(|field| field.is_changed())
+    }
+
+    fn push(&mut self, field: impl Into<String>, current: impl ToString, proposed: impl ToString) {
+        self.fields.push(ParamFieldDiff {
+            field: field.into(),
+            current: current.to_string(),
+            proposed: proposed.to_string(),
+        });
+    }
+
+    fn rational(
+        &mut self,
+        field: impl Into<String>,
+        current: &RationalNumber,
+        proposed: &RationalNumber,
+    ) {
+        self.push(
+            field,
+            format_decimal_rational(current.numerator, current.denominator),
+            format_decimal_rational(proposed.numerator, proposed.denominator),
+        );
+    }
+}
+
+// one line per field, `field  current -> proposed`, aligned on the arrow
+impl fmt::Display for ParamUpdateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field_width = self.fields.iter().map(|d| d.field.len()).max();
+        let current_width = self.fields.iter().map(|d| d.current.len()).max();
+        for d in &self.fields {
+            write!(
+                f,
+                "{:fw$}  {:>cw$} -> {}",
+                d.field,
+                d.current,
+                d.proposed,
+                fw = field_width.unwrap_or(0),
+                cw = current_width.unwrap_or(0),
+            )?;
+            if !d.is_changed() {
+                write!(f, " (unchanged)")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn missing_as_dash(value: Option<i64>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+fn pool_thresholds<'a>(
+    current: &'a PoolVotingThresholds,
+    proposed: &'a PoolVotingThresholds,
+) -> [(&'static str, &'a RationalNumber, &'a RationalNumber); 5] {
+    [
+        (
+            "motion_no_confidence",
+            &current.motion_no_confidence,
+            &proposed.motion_no_confidence,
+        ),
+        (
+            "committee_normal",
+            &current.committee_normal,
+            &proposed.committee_normal,
+        ),
+        (
+            "committee_no_confidence",
+            &current.committee_no_confidence,
+            &proposed.committee_no_confidence,
+        ),
+        (
+            "hard_fork_initiation",
+            &current.hard_fork_initiation,
+            &proposed.hard_fork_initiation,
+        ),
+        (
+            "security_voting_threshold",
+            &current.security_voting_threshold,
+            &proposed.security_voting_threshold,
+        ),
+    ]
+}
+
+fn drep_thresholds<'a>(
+    current: &'a DRepVotingThresholds,
+    proposed: &'a DRepVotingThresholds,
+) -> [(&'static str, &'a RationalNumber, &'a RationalNumber); 10] {
+    [
+        (
+            "motion_no_confidence",
+            &current.motion_no_confidence,
+            &proposed.motion_no_confidence,
+        ),
+        (
+            "committee_normal",
+            &current.committee_normal,
+            &proposed.committee_normal,
+        ),
+        (
+            "committee_no_confidence",
+            &current.committee_no_confidence,
+            &proposed.committee_no_confidence,
+        ),
+        (
+            "update_constitution",
+            &current.update_constitution,
+            &proposed.update_constitution,
+        ),
+        (
+            "hard_fork_initiation",
+            &current.hard_fork_initiation,
+            &proposed.hard_fork_initiation,
+        ),
+        (
+            "pp_network_group",
+            &current.pp_network_group,
+            &proposed.pp_network_group,
+        ),
+        (
+            "pp_economic_group",
+            &current.pp_economic_group,
+            &proposed.pp_economic_group,
+        ),
+        (
+            "pp_technical_group",
+            &current.pp_technical_group,
+            &proposed.pp_technical_group,
+        ),
+        (
+            "pp_governance_group",
+            &current.pp_governance_group,
+            &proposed.pp_governance_group,
+        ),
+        (
+            "treasury_withdrawal",
+            &current.treasury_withdrawal,
+            &proposed.treasury_withdrawal,
+        ),
+    ]
+}
@@ -0,0 +1,173 @@
+// Conway parameter groups and the voting thresholds a parameter change needs
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{IntoInner, compare_rationals};
+use crate::wrapper::{
+    DRepVotingThresholdsWrapper, PoolVotingThresholdsWrapper, ProtocolParamUpdateWrapper,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum ParamGroup {
+    Network,
+    Economic,
+    Technical,
+    Governance,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ParamChange {
+    pub field: &'static str,
+    pub group: ParamGroup,
+    // also in the security group, so stake pools vote on it
+    pub security: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ParamUpdateClassification {
+    pub changes: Vec<ParamChange>,
+    // sorted and deduplicated
+    pub groups: Vec<ParamGroup>,
+    pub security_vote_required: bool,
+}
+
+// Thresholds as (numerator, denominator). `spo` is None when stake pools do
+// not vote. The constitutional committee votes on every parameter change.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct RatificationThresholds {
+    pub drep: (u64, u64),
+    pub spo: Option<(u64, u64)>,
+}
+
+// (field, group, security group), in `ProtocolParamUpdate` field order
+const PARAMETERS: [(&str, ParamGroup, bool); 30] = [
+    ("minfee_a", ParamGroup::Economic, true),
+    ("minfee_b", ParamGroup::Economic, true),
+    ("max_block_body_size", ParamGroup::Network, true),
+    ("max_transaction_size", ParamGroup::Network, true),
+    ("max_block_header_size", ParamGroup::Network, true),
+    ("key_deposit", ParamGroup::Economic, false),
+    ("pool_deposit", ParamGroup::Economic, false),
+    ("maximum_epoch", ParamGroup::Technical, false),
+    (
+        "desired_number_of_stake_pools",
+        ParamGroup::Technical,
+        false,
+    ),
+    ("pool_pledge_influence", ParamGroup::Technical, false),
+    ("expansion_rate", ParamGroup::Economic, false),
+    ("treasury_growth_rate", ParamGroup::Economic, false),
+    ("min_pool_cost", ParamGroup::Economic, false),
+    ("ada_per_utxo_byte", ParamGroup::Economic, true),
+    (
+        "cost_models_for_script_languages",
+        ParamGroup::Technical,
+        false,
+    ),
+    ("execution_costs", ParamGroup::Economic, false),
+    ("max_tx_ex_units", ParamGroup::Network, false),
+    ("max_block_ex_units", ParamGroup::Network, true),
+    ("max_value_size", ParamGroup::Network, true),
+    ("collateral_percentage", ParamGroup::Technical, false),
+    ("max_collateral_inputs", ParamGroup::Network, false),
+    ("pool_voting_thresholds", ParamGroup::Governance, false),
+    ("drep_voting_thresholds", ParamGroup::Governance, false),
+    ("min_committee_size", ParamGroup::Governance, false),
+    ("committee_term_limit", ParamGroup::Governance, false),
+    (
+        "governance_action_validity_period",
+        ParamGroup::Governance,
+        false,
+    ),
+    ("governance_action_deposit", ParamGroup::Governance, true),
+    ("drep_deposit", ParamGroup::Governance, false),
+    ("drep_inactivity_period", ParamGroup::Governance, false),
+    ("minfee_refscript_cost_per_byte", ParamGroup::Economic, true),
+];
+
+impl ParamUpdateClassification {
+    pub fn new(update: &ProtocolParamUpdateWrapper) -> Self {
+        let set = set_fields(update);
+        let changes: Vec<ParamChange> = PARAMETERS
+            .iter()
+            .zip(set)
+            .filter(|(_, is_set)| *is_set)
+            .map(|(&(field, group, security), _)| ParamChange {
+                field,
+                group,
+                security,
+            })
+            .collect();
+
+        let mut groups: Vec<ParamGroup> = changes.iter().map(|c| c.group).collect();
+        groups.sort();
+        groups.dedup();
+        let security_vote_required = changes.iter().any(|c| c.security);
+
+        Self {
+            changes,
+            groups,
+            security_vote_required,
+        }
+    }
+
+    // DReps need the highest threshold among the changed groups; stake pools
+    // only vote, at the security threshold, when a security parameter changes.
+    pub fn thresholds(
+        &self,
+        drep_thresholds: &DRepVotingThresholdsWrapper,
+        pool_thresholds: &PoolVotingThresholdsWrapper,
+    ) -> RatificationThresholds {
+        let drep = self
+            .groups
+            .iter()
+            .map(|group| match group {
+                ParamGroup::Network => drep_thresholds.pp_network_group,
+                ParamGroup::Economic => drep_thresholds.pp_economic_group,
+                ParamGroup::Technical => drep_thresholds.pp_technical_group,
+                ParamGroup::Governance => drep_thresholds.pp_governance_group,
+            })
+            .max_by(|a, b| compare_rationals(*a, *b))
+            .unwrap_or((0, 1));
+        let spo = self
+            .security_vote_required
+            .then_some(pool_thresholds.security_voting_threshold);
+
+        RatificationThresholds { drep, spo }
+    }
+}
+
+fn set_fields(update: &ProtocolParamUpdateWrapper) -> [bool; 30] {
+    let u = update.into_inner();
+    [
+        u.minfee_a.is_some(),
+        u.minfee_b.is_some(),
+        u.max_block_body_size.is_some(),
+        u.max_transaction_size.is_some(),
+        u.max_block_header_size.is_some(),
+        u.key_deposit.is_some(),
+        u.pool_deposit.is_some(),
+        u.maximum_epoch.is_some(),
+        u.desired_number_of_stake_pools.is_some(),
+        u.pool_pledge_influence.is_some(),
+        u.expansion_rate.is_some(),
+        u.treasury_growth_rate.is_some(),
+        u.min_pool_cost.is_some(),
+        u.ada_per_utxo_byte.is_some(),
+        u.cost_models_for_script_languages.is_some(),
+        u.execution_costs.is_some(),
+        u.max_tx_ex_units.is_some(),
+        u.max_block_ex_units.is_some(),
+        u.max_value_size.is_some(),
+        u.collateral_percentage.is_some(),
+        u.max_collateral_inputs.is_some(),
+        u.pool_voting_thresholds.is_some(),
+        u.drep_voting_thresholds.is_some(),
+        u.min_committee_size.is_some(),
+        u.committee_term_limit.is_some(),
+        u.governance_action_validity_period.is_some(),
+        u.governance_action_deposit.is_some(),
+        u.drep_deposit.is_some(),
+        u.drep_inactivity_period.is_some(),
+        u.minfee_refscript_cost_per_byte.is_some(),
+    ]
+}
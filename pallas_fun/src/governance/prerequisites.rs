@@ -0,0 +1,115 @@
+// Prerequisite checks for governance actions. Parameter changes, hard forks,
+// committee changes (no confidence and committee updates share one chain) and
+// new constitutions must reference the last enacted action of their purpose.
+use pallas::codec::utils::Nullable;
+use pallas::ledger::primitives::ProtocolVersion;
+use pallas::ledger::primitives::conway::{GovAction, GovActionId};
+use serde::{Deserialize, Serialize};
+
+use crate::error::PallasFunError;
+use crate::utils::IntoInner;
+use crate::wrapper::{GovActionIdWrapper, GovActionWrapper};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum GovActionPurpose {
+    ParameterChange,
+    HardFork,
+    Committee,
+    Constitution,
+}
+
+impl GovActionPurpose {
+    // None for treasury withdrawals and info actions, which have no chain
+    pub fn of(action: &GovActionWrapper) -> Option<Self> {
+        match action.into_inner() {
+            GovAction::ParameterChange(..) => Some(Self::ParameterChange),
+            GovAction::HardForkInitiation(..) => Some(Self::HardFork),
+            GovAction::NoConfidence(..) | GovAction::UpdateCommittee(..) => Some(Self::Committee),
+            GovAction::NewConstitution(..) => Some(Self::Constitution),
+            GovAction::TreasuryWithdrawals(..) | GovAction::Information => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ParameterChange => "parameter change",
+            Self::HardFork => "hard fork",
+            Self::Committee => "committee",
+            Self::Constitution => "constitution",
+        }
+    }
+}
+
+// Last enacted action per purpose, None when nothing was enacted since the
+// Conway hard fork
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct EnactedActions {
+    pub parameter_change: Option<GovActionIdWrapper>,
+    pub hard_fork: Option<GovActionIdWrapper>,
+    pub committee: Option<GovActionIdWrapper>,
+    pub constitution: Option<GovActionIdWrapper>,
+}
+
+impl EnactedActions {
+    pub fn last(&self, purpose: GovActionPurpose) -> Option<&GovActionIdWrapper> {
+        match purpose {
+            GovActionPurpose::ParameterChange => self.parameter_change.as_ref(),
+            GovActionPurpose::HardFork => self.hard_fork.as_ref(),
+            GovActionPurpose::Committee => self.committee.as_ref(),
+            GovActionPurpose::Constitution => self.constitution.as_ref(),
+        }
+    }
+
+    // Checks the previous action `action` references and, for a hard fork,
+    // that the proposed version can follow `current_version`. Proposals
+    // building on another pending proposal are not covered.
+    pub fn check_prerequisite(
+        &self,
+        action: &GovActionWrapper,
+        current_version: ProtocolVersion,
+    ) -> Result<(), PallasFunError> {
+        let Some(purpose) = GovActionPurpose::of(action) else {
+            return Ok(());
+        };
+        let (previous, proposed_version) = match action.into_inner() {
+            GovAction::ParameterChange(previous, ..)
+            | GovAction::NoConfidence(previous)
+            | GovAction::UpdateCommittee(previous, ..)
+            | GovAction::NewConstitution(previous, ..) => (previous, None),
+            GovAction::HardForkInitiation(previous, version) => (previous, Some(version)),
+            GovAction::TreasuryWithdrawals(..) | GovAction::Information => return Ok(()),
+        };
+
+        let previous = match previous {
+            Nullable::Some(id) => Some(id),
+            Nullable::Null | Nullable::Undefined => None,
+        };
+        let expected = self.last(purpose).map(|id| id.into_inner());
+        if previous != expected {
+            return Err(PallasFunError::PrerequisiteMismatch {
+                purpose: purpose.name(),
+                expected: expected.as_ref().map(format_gov_action_id),
+                actual: previous.as_ref().map(format_gov_action_id),
+            });
+        }
+
+        if let Some(proposed) = proposed_version
+            && !can_follow(current_version, proposed)
+        {
+            return Err(PallasFunError::InvalidHardForkVersion {
+                current: current_version,
+                proposed,
+            });
+        }
+        Ok(())
+    }
+}
+
+// the next major version with minor 0, or the next minor version
+pub fn can_follow(current: ProtocolVersion, proposed: ProtocolVersion) -> bool {
+    proposed == (current.0 + 1, 0) || proposed == (current.0, current.1 + 1)
+}
+
+fn format_gov_action_id(id: &GovActionId) -> String {
+    format!("{}#{}", id.transaction_id, id.action_index)
+}
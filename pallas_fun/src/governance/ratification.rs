@@ -0,0 +1,294 @@
+// Offline ratification check of governance actions against a local snapshot
+// of DRep stake, pool stake and committee membership, following the Conway
+// rules after bootstrap (protocol version 10). Every action is checked on its
+// own against the current state; prerequisite chains, enactment order and
+// expiry are not modelled.
+use std::collections::BTreeMap;
+
+use pallas::crypto::hash::Hash;
+use pallas::ledger::primitives::conway::{DRep, GovAction, Vote, Voter};
+use serde::Serialize;
+
+use crate::governance::ParamUpdateClassification;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::IntoInner;
+use crate::wrapper::{
+    DRepVotingThresholdsWrapper, DRepWrapper, GovActionIdWrapper, PoolVotingThresholdsWrapper,
+    ProposalProcedureWrapper, ProtocolParamUpdateWrapper, VoterWrapper, VotingProceduresInput,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DRepStake {
+    // `DRepKey` or `DRepScript` voter
+    pub drep: VoterWrapper,
+    pub stake: u64,
+    // false once the DRep is past its activity period, its stake then abstains
+    pub active: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PoolStake {
+    pub pool: VoterWrapper,
+    pub stake: u64,
+    // DRep the pool's reward account delegates to, decides how the pool
+    // counts when it does not vote
+    pub reward_account_drep: Option<DRepWrapper>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CommitteeMember {
+    // None when no hot key is authorized or the member resigned
+    pub hot: Option<VoterWrapper>,
+    pub expired: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CommitteeSnapshot {
+    pub members: Vec<CommitteeMember>,
+    pub threshold: (u64, u64),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GovernanceSnapshot {
+    pub dreps: Vec<DRepStake>,
+    pub always_abstain_stake: u64,
+    pub always_no_confidence_stake: u64,
+    pub pools: Vec<PoolStake>,
+    // None while in a state of no confidence
+    pub committee: Option<CommitteeSnapshot>,
+}
+
+// Stake (committee: members) per effective vote. Passes when
+// yes / (yes + no) reaches the threshold.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct VoteTally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+    pub threshold: (u64, u64),
+    pub passed: bool,
+}
+
+// A tally is None when that body does not vote on the action.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct RatificationOutcome {
+    pub gov_action_id: GovActionIdWrapper,
+    pub committee: Option<VoteTally>,
+    pub drep: Option<VoteTally>,
+    pub spo: Option<VoteTally>,
+    pub ratified: bool,
+}
+
+type GovActionKey = (Hash<32>, u32);
+
+// Later votes of a voter on the same action replace earlier ones, as a new
+// vote on chain does.
+pub fn simulate_ratification(
+    proposals: &[(GovActionIdWrapper, ProposalProcedureWrapper)],
+    votes: &VotingProceduresInput,
+    snapshot: &GovernanceSnapshot,
+    params: &ProtocolParams,
+) -> Vec<RatificationOutcome> {
+    let mut cast: BTreeMap<(Voter, GovActionKey), Vote> = BTreeMap::new();
+    for (voter, procedures) in votes {
+        for (gov_action_id, procedure) in procedures {
+            let id = gov_action_id.into_inner();
+            cast.insert(
+                (voter.into_inner(), (id.transaction_id, id.action_index)),
+                procedure.into_inner().vote,
+            );
+        }
+    }
+
+    let drep_thresholds = DRepVotingThresholdsWrapper::from(&params.drep_voting_thresholds);
+    let pool_thresholds = PoolVotingThresholdsWrapper::from(&params.pool_voting_thresholds);
+
+    proposals
+        .iter()
+        .map(|(gov_action_id, proposal)| {
+            let id = gov_action_id.into_inner();
+            let key = (id.transaction_id, id.action_index);
+            let vote_of = |voter: &VoterWrapper| cast.get(&(voter.into_inner(), key)).cloned();
+            let action = proposal.into_inner().gov_action;
+
+            let no_confidence = matches!(action, GovAction::NoConfidence(_));
+            let committee_thresholds = if snapshot.committee.is_some() {
+                (
+                    drep_thresholds.committee_normal,
+                    pool_thresholds.committee_normal,
+                )
+            } else {
+                (
+                    drep_thresholds.committee_no_confidence,
+                    pool_thresholds.committee_no_confidence,
+                )
+            };
+            // (committee votes, DRep threshold, SPO threshold)
+            let required = match &action {
+                GovAction::ParameterChange(_, update, _) => {
+                    let update = ProtocolParamUpdateWrapper::from(update.as_ref().clone());
+                    let thresholds = ParamUpdateClassification::new(&update)
+                        .thresholds(&drep_thresholds, &pool_thresholds);
+                    Some((true, Some(thresholds.drep), thresholds.spo))
+                }
+                GovAction::HardForkInitiation(..) => Some((
+                    true,
+                    Some(drep_thresholds.hard_fork_initiation),
+                    Some(pool_thresholds.hard_fork_initiation),
+                )),
+                GovAction::TreasuryWithdrawals(..) => {
+                    Some((true, Some(drep_thresholds.treasury_withdrawal), None))
+                }
+                GovAction::NoConfidence(_) => Some((
+                    false,
+                    Some(drep_thresholds.motion_no_confidence),
+                    Some(pool_thresholds.motion_no_confidence),
+                )),
+                GovAction::UpdateCommittee(..) => Some((
+                    false,
+                    Some(committee_thresholds.0),
+                    Some(committee_thresholds.1),
+                )),
+                GovAction::NewConstitution(..) => {
+                    Some((true, Some(drep_thresholds.update_constitution), None))
+                }
+                // info actions are never ratified
+                GovAction::Information => None,
+            };
+            let Some((committee_votes, drep_threshold, spo_threshold)) = required else {
+                return RatificationOutcome {
+                    gov_action_id: gov_action_id.clone(),
+                    committee: None,
+                    drep: None,
+                    spo: None,
+                    ratified: false,
+                };
+            };
+
+            let committee = committee_votes.then(|| committee_tally(snapshot, params, &vote_of));
+            let drep = drep_threshold.map(|threshold| {
+                let mut tally = Tally::default();
+                for drep in &snapshot.dreps {
+                    if drep.active {
+                        tally.add(vote_of(&drep.drep).unwrap_or(Vote::No), drep.stake);
+                    } else {
+                        tally.add(Vote::Abstain, drep.stake);
+                    }
+                }
+                tally.add(Vote::Abstain, snapshot.always_abstain_stake);
+                let no_confidence_vote = if no_confidence { Vote::Yes } else { Vote::No };
+                tally.add(no_confidence_vote, snapshot.always_no_confidence_stake);
+                tally.finish(threshold)
+            });
+            let spo = spo_threshold.map(|threshold| {
+                let mut tally = Tally::default();
+                for pool in &snapshot.pools {
+                    let vote = vote_of(&pool.pool).unwrap_or_else(|| {
+                        default_pool_vote(&action, pool.reward_account_drep.as_ref())
+                    });
+                    tally.add(vote, pool.stake);
+                }
+                tally.finish(threshold)
+            });
+
+            let ratified = [&committee, &drep, &spo]
+                .iter()
+                .all(|tally| tally.as_ref().is_none_or(|tally| tally.passed));
+            RatificationOutcome {
+                gov_action_id: gov_action_id.clone(),
+                committee,
+                drep,
+                spo,
+                ratified,
+            }
+        })
+        .collect()
+}
+
+// Members that expired or have no hot key abstain, active ones that did not
+// vote count as no. Fails while in no confidence or below the minimum size.
+fn committee_tally(
+    snapshot: &GovernanceSnapshot,
+    params: &ProtocolParams,
+    vote_of: &impl Fn(&VoterWrapper) -> Option<Vote>,
+) -> VoteTally {
+    let Some(committee) = &snapshot.committee else {
+        return Tally::default().finish((1, 1)).failed();
+    };
+
+    let mut tally = Tally::default();
+    let mut active = 0;
+    for member in &committee.members {
+        match &member.hot {
+            Some(hot) if !member.expired => {
+                active += 1;
+                tally.add(vote_of(hot).unwrap_or(Vote::No), 1);
+            }
+            _ => tally.add(Vote::Abstain, 1),
+        }
+    }
+    let tally = tally.finish(committee.threshold);
+    if active < params.min_committee_size {
+        tally.failed()
+    } else {
+        tally
+    }
+}
+
+// Pools that did not vote count as no on a hard fork. Otherwise their reward
+// account delegation decides: always abstain abstains, always no confidence
+// votes yes on no confidence and no on anything else.
+fn default_pool_vote(action: &GovAction, reward_account_drep: Option<&DRepWrapper>) -> Vote {
+    if matches!(action, GovAction::HardForkInitiation(..)) {
+        return Vote::No;
+    }
+    match reward_account_drep.map(|drep| drep.into_inner()) {
+        Some(DRep::Abstain) => Vote::Abstain,
+        Some(DRep::NoConfidence) if matches!(action, GovAction::NoConfidence(_)) => Vote::Yes,
+        _ => Vote::No,
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    yes: u64,
+    no: u64,
+    abstain: u64,
+}
+
+impl Tally {
+    fn add(&mut self, vote: Vote, weight: u64) {
+        let total = match vote {
+            Vote::Yes => &mut self.yes,
+            Vote::No => &mut self.no,
+            Vote::Abstain => &mut self.abstain,
+        };
+        *total = total.saturating_add(weight);
+    }
+
+    // nothing but abstentions gives a ratio of zero
+    fn finish(self, threshold: (u64, u64)) -> VoteTally {
+        let counted = self.yes as u128 + self.no as u128;
+        let passed = if counted == 0 {
+            threshold.0 == 0
+        } else {
+            self.yes as u128 * threshold.1 as u128 >= threshold.0 as u128 * counted
+        };
+        VoteTally {
+            yes: self.yes,
+            no: self.no,
+            abstain: self.abstain,
+            threshold,
+            passed,
+        }
+    }
+}
+
+impl VoteTally {
+    fn failed(self) -> Self {
+        Self {
+            passed: false,
+            ..self
+        }
+    }
+}
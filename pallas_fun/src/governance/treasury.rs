@@ -0,0 +1,63 @@
+// Checks on treasury withdrawal actions beyond what `GovActionWrapper::new`
+// enforces
+use std::collections::BTreeSet;
+
+use pallas::ledger::primitives::NetworkId;
+use pallas::ledger::primitives::conway::GovAction;
+use serde::Serialize;
+
+use crate::error::PallasFunError;
+use crate::utils::IntoInner;
+use crate::wrapper::{GovActionWrapper, RewardAccountWrapper};
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TreasuryWithdrawalSummary {
+    pub accounts: usize,
+    pub total: u64,
+}
+
+impl TreasuryWithdrawalSummary {
+    // Every account must be a reward account on `network_id` (any network if
+    // None) and appear once, every amount must be non-zero and the total must
+    // fit in a u64.
+    pub fn new(
+        action: &GovActionWrapper,
+        network_id: Option<NetworkId>,
+    ) -> Result<Self, PallasFunError> {
+        let GovAction::TreasuryWithdrawals(withdrawals, _) = action.into_inner() else {
+            return Err(PallasFunError::UnexpectedGovAction("treasury withdrawals"));
+        };
+
+        let mut seen = BTreeSet::new();
+        let mut total: u64 = 0;
+        for (i, (account, amount)) in withdrawals.iter().enumerate() {
+            let at = |e: PallasFunError| e.at(format!("withdrawals[{}]", i));
+            RewardAccountWrapper::from(account.clone())
+                .check(network_id)
+                .map_err(at)?;
+            if !seen.insert(account.clone()) {
+                return Err(at(PallasFunError::DuplicateEntry {
+                    kind: "withdrawal account",
+                    entry: hex::encode(account.as_slice()),
+                }));
+            }
+            if *amount == 0 {
+                return Err(at(PallasFunError::ZeroAmount("treasury withdrawal")));
+            }
+            total = total
+                .checked_add(*amount)
+                .ok_or(PallasFunError::Overflow("treasury withdrawal total"))?;
+        }
+
+        Ok(Self {
+            accounts: withdrawals.len(),
+            total,
+        })
+    }
+
+    // `treasury` is the current balance, e.g. the `treasury_value` declared in
+    // the transaction body
+    pub fn is_feasible(&self, treasury: u64) -> bool {
+        self.total <= treasury
+    }
+}
@@ -1,3 +1,14 @@
+pub mod blueprint;
+pub mod cip129;
+pub mod collateral;
+pub mod cost_models;
+pub mod delegation;
+pub mod error;
+pub mod governance;
+pub mod plutus;
+pub mod pool;
+pub mod protocol_params;
 pub mod utils;
+pub mod witness;
 pub mod wrapper;
 // pub use utils::*;
@@ -0,0 +1,24 @@
+// Plutus language version, shared by blueprints and cost models
+use serde::{Deserialize, Serialize};
+
+// serialized as in CIP-57 blueprints
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PlutusVersion {
+    #[serde(rename = "v1")]
+    V1,
+    #[serde(rename = "v2")]
+    V2,
+    #[serde(rename = "v3")]
+    V3,
+}
+
+impl PlutusVersion {
+    // ledger name, as used for cost models
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::V1 => "PlutusV1",
+            Self::V2 => "PlutusV2",
+            Self::V3 => "PlutusV3",
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Stake pool registration checks and the pool metadata file referenced by
+// `PoolMetadataWrapper`
+use std::fs;
+use std::path::Path;
+
+use pallas::codec::utils::Nullable;
+use pallas::ledger::primitives::conway::Certificate;
+use serde::{Deserialize, Serialize};
+
+use crate::error::PallasFunError;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::IntoInner;
+use crate::wrapper::{CertificateWrapper, PoolMetadataWrapper, RelayWrapper};
+
+// limits cardano-cli applies to pool metadata files
+pub const MAX_POOL_METADATA_SIZE: usize = 512;
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_DESCRIPTION_LENGTH: usize = 255;
+const MAX_HOMEPAGE_LENGTH: usize = 64;
+
+// The margin must be at most 1 and the cost at least `min_pool_cost`. A key
+// hash reward account must belong to one of the owners. Relay DNS names and
+// the metadata URL are limited to 64 bytes. The constructor only checks the
+// margin denominator, so this is the only validation a decoded certificate
+// gets.
+pub fn check_pool_registration(
+    certificate: &CertificateWrapper,
+    params: &ProtocolParams,
+) -> Result<(), PallasFunError> {
+    let Certificate::PoolRegistration {
+        cost,
+        margin,
+        reward_account,
+        pool_owners,
+        relays,
+        pool_metadata,
+        ..
+    } = certificate.into_inner()
+    else {
+        return Err(PallasFunError::UnexpectedCertificate("pool registration"));
+    };
+
+    if margin.denominator == 0 || margin.numerator > margin.denominator {
+        return Err(PallasFunError::InvalidRational {
+            numerator: margin.numerator,
+            denominator: margin.denominator,
+        }
+        .at("margin"));
+    }
+    if cost < params.min_pool_cost {
+        return Err(PallasFunError::BelowMinimum {
+            kind: "pool cost",
+            min: params.min_pool_cost,
+            actual: cost,
+        }
+        .at("cost"));
+    }
+
+    // script reward accounts cannot be owners
+    if reward_account.len() == 29 && reward_account[0] >> 4 == 0b1110 {
+        let key_hash = &reward_account[1..];
+        if !pool_owners.iter().any(|owner| owner.as_ref() == key_hash) {
+            return Err(PallasFunError::MissingOwner(hex::encode(key_hash)).at("pool_owners"));
+        }
+    }
+
+    for (i, relay) in relays.into_iter().enumerate() {
+        RelayWrapper::from(relay)
+            .check_dns_name()
+            .map_err(|e| e.at(format!("relays[{}]", i)))?;
+    }
+    if let Nullable::Some(pool_metadata) = pool_metadata {
+        PoolMetadataWrapper::from(pool_metadata)
+            .check_url()
+            .map_err(|e| e.at("pool_metadata"))?;
+    }
+    Ok(())
+}
+
+// CIP-6 pool metadata, the JSON file `PoolMetadataWrapper` points to
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct PoolMetadataDocument {
+    pub name: String,
+    pub description: String,
+    pub ticker: String,
+    pub homepage: String,
+}
+
+impl PoolMetadataDocument {
+    pub fn from_json(content: &[u8]) -> Result<Self, PallasFunError> {
+        if content.len() > MAX_POOL_METADATA_SIZE {
+            return Err(PallasFunError::TooLong {
+                kind: "pool metadata",
+                max: MAX_POOL_METADATA_SIZE,
+                actual: content.len(),
+            });
+        }
+        let document: Self =
+            serde_json::from_slice(content).map_err(|e| PallasFunError::Json(e.to_string()))?;
+        document.check()?;
+        Ok(document)
+    }
+
+    pub fn check(&self) -> Result<(), PallasFunError> {
+        let invalid = |field, reason: String| PallasFunError::InvalidPoolMetadata { field, reason };
+
+        if self.name.chars().count() > MAX_NAME_LENGTH {
+            return Err(invalid(
+                "name",
+                format!("longer than {} characters", MAX_NAME_LENGTH),
+            ));
+        }
+        if self.description.chars().count() > MAX_DESCRIPTION_LENGTH {
+            return Err(invalid(
+                "description",
+                format!("longer than {} characters", MAX_DESCRIPTION_LENGTH),
+            ));
+        }
+        if !(3..=5).contains(&self.ticker.chars().count()) {
+            return Err(invalid(
+                "ticker",
+                format!("{} must be 3 to 5 characters", self.ticker),
+            ));
+        }
+        if self.homepage.len() > MAX_HOMEPAGE_LENGTH {
+            return Err(invalid(
+                "homepage",
+                format!("longer than {} bytes", MAX_HOMEPAGE_LENGTH),
+            ));
+        }
+        if !(self.homepage.starts_with("https://") || self.homepage.starts_with("http://")) {
+            return Err(invalid(
+                "homepage",
+                format!("{} is not an http(s) URL", self.homepage),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// Reads and checks a local copy of the metadata to be published at `url`,
+// hashing the file bytes as they are.
+pub fn load_pool_metadata(
+    url: String,
+    path: impl AsRef<Path>,
+) -> Result<(PoolMetadataDocument, PoolMetadataWrapper), PallasFunError> {
+    let content = fs::read(path).map_err(|e| PallasFunError::Io(e.to_string()))?;
+    let document = PoolMetadataDocument::from_json(&content)?;
+    let pool_metadata = PoolMetadataWrapper::from_content(url, &content)?;
+    Ok((document, pool_metadata))
+}
@@ -0,0 +1,397 @@
+// Full protocol parameter set, as needed for fee, min-UTxO and deposit
+// calculations, loaded from local cardano-cli, Blockfrost or Koios JSON.
+use std::path::Path;
+
+use pallas::ledger::primitives::conway::{
+    CostModels, DRepVotingThresholds, ExUnitPrices, PoolVotingThresholds,
+};
+use pallas::ledger::primitives::{ExUnits, ProtocolVersion, RationalNumber, UnitInterval};
+use serde_json::Value;
+
+use crate::cost_models::{PlutusVersion, cost_model_from_named};
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_decimal_rational, parse_rational_number, read_file};
+use crate::wrapper::{ProtocolParamUpdateBuilder, ProtocolParamUpdateWrapper};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProtocolParams {
+    pub minfee_a: u64,
+    pub minfee_b: u64,
+    pub max_block_body_size: u64,
+    pub max_transaction_size: u64,
+    pub max_block_header_size: u64,
+    pub key_deposit: u64,
+    pub pool_deposit: u64,
+    pub maximum_epoch: u64,
+    pub desired_number_of_stake_pools: u64,
+    pub pool_pledge_influence: RationalNumber,
+    pub expansion_rate: UnitInterval,
+    pub treasury_growth_rate: UnitInterval,
+    pub min_pool_cost: u64,
+    pub ada_per_utxo_byte: u64,
+    pub cost_models_for_script_languages: CostModels,
+    pub execution_costs: ExUnitPrices,
+    pub max_tx_ex_units: ExUnits,
+    pub max_block_ex_units: ExUnits,
+    pub max_value_size: u64,
+    pub collateral_percentage: u64,
+    pub max_collateral_inputs: u64,
+    pub pool_voting_thresholds: PoolVotingThresholds,
+    pub drep_voting_thresholds: DRepVotingThresholds,
+    pub min_committee_size: u64,
+    pub committee_term_limit: u64,
+    pub governance_action_validity_period: u64,
+    pub governance_action_deposit: u64,
+    pub drep_deposit: u64,
+    pub drep_inactivity_period: u64,
+    pub minfee_refscript_cost_per_byte: UnitInterval,
+    pub protocol_version: ProtocolVersion,
+}
+
+impl ProtocolParams {
+    // `cardano-cli conway query protocol-parameters`, also served by Koios
+    // `/cli_protocol_params`
+    pub fn from_cardano_cli_json(json: &str) -> Result<Self, PallasFunError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| PallasFunError::Json(e.to_string()))?;
+        let p = JsonFields(&value);
+
+        Ok(Self {
+            minfee_a: p.u64(&["txFeePerByte"])?,
+            minfee_b: p.u64(&["txFeeFixed"])?,
+            max_block_body_size: p.u64(&["maxBlockBodySize"])?,
+            max_transaction_size: p.u64(&["maxTxSize"])?,
+            max_block_header_size: p.u64(&["maxBlockHeaderSize"])?,
+            key_deposit: p.u64(&["stakeAddressDeposit"])?,
+            pool_deposit: p.u64(&["stakePoolDeposit"])?,
+            maximum_epoch: p.u64(&["poolRetireMaxEpoch"])?,
+            desired_number_of_stake_pools: p.u64(&["stakePoolTargetNum"])?,
+            pool_pledge_influence: p.rational(&["poolPledgeInfluence"])?,
+            expansion_rate: p.rational(&["monetaryExpansion"])?,
+            treasury_growth_rate: p.rational(&["treasuryCut"])?,
+            min_pool_cost: p.u64(&["minPoolCost"])?,
+            ada_per_utxo_byte: p.u64(&["utxoCostPerByte"])?,
+            cost_models_for_script_languages: CostModels {
+                plutus_v1: p.cost_model(PlutusVersion::V1, &["costModels.PlutusV1"])?,
+                plutus_v2: p.cost_model(PlutusVersion::V2, &["costModels.PlutusV2"])?,
+                plutus_v3: p.cost_model(PlutusVersion::V3, &["costModels.PlutusV3"])?,
+            },
+            execution_costs: ExUnitPrices {
+                mem_price: p.rational(&["executionUnitPrices.priceMemory"])?,
+                step_price: p.rational(&["executionUnitPrices.priceSteps"])?,
+            },
+            max_tx_ex_units: ExUnits {
+                mem: p.u64(&["maxTxExecutionUnits.memory"])?,
+                steps: p.u64(&["maxTxExecutionUnits.steps"])?,
+            },
+            max_block_ex_units: ExUnits {
+                mem: p.u64(&["maxBlockExecutionUnits.memory"])?,
+                steps: p.u64(&["maxBlockExecutionUnits.steps"])?,
+            },
+            max_value_size: p.u64(&["maxValueSize"])?,
+            collateral_percentage: p.u64(&["collateralPercentage"])?,
+            max_collateral_inputs: p.u64(&["maxCollateralInputs"])?,
+            pool_voting_thresholds: PoolVotingThresholds {
+                motion_no_confidence: p.rational(&["poolVotingThresholds.motionNoConfidence"])?,
+                committee_normal: p.rational(&["poolVotingThresholds.committeeNormal"])?,
+                committee_no_confidence: p
+                    .rational(&["poolVotingThresholds.committeeNoConfidence"])?,
+                hard_fork_initiation: p.rational(&["poolVotingThresholds.hardForkInitiation"])?,
+                security_voting_threshold: p.rational(&["poolVotingThresholds.ppSecurityGroup"])?,
+            },
+            drep_voting_thresholds: DRepVotingThresholds {
+                motion_no_confidence: p.rational(&["dRepVotingThresholds.motionNoConfidence"])?,
+                committee_normal: p.rational(&["dRepVotingThresholds.committeeNormal"])?,
+                committee_no_confidence: p
+                    .rational(&["dRepVotingThresholds.committeeNoConfidence"])?,
+                update_constitution: p.rational(&["dRepVotingThresholds.updateToConstitution"])?,
+                hard_fork_initiation: p.rational(&["dRepVotingThresholds.hardForkInitiation"])?,
+                pp_network_group: p.rational(&["dRepVotingThresholds.ppNetworkGroup"])?,
+                pp_economic_group: p.rational(&["dRepVotingThresholds.ppEconomicGroup"])?,
+                pp_technical_group: p.rational(&["dRepVotingThresholds.ppTechnicalGroup"])?,
+                pp_governance_group: p.rational(&["dRepVotingThresholds.ppGovGroup"])?,
+                treasury_withdrawal: p.rational(&["dRepVotingThresholds.treasuryWithdrawal"])?,
+            },
+            min_committee_size: p.u64(&["committeeMinSize"])?,
+            committee_term_limit: p.u64(&["committeeMaxTermLength"])?,
+            governance_action_validity_period: p.u64(&["govActionLifetime"])?,
+            governance_action_deposit: p.u64(&["govActionDeposit"])?,
+            drep_deposit: p.u64(&["dRepDeposit"])?,
+            drep_inactivity_period: p.u64(&["dRepActivity"])?,
+            minfee_refscript_cost_per_byte: p.rational(&["minFeeRefScriptCostPerByte"])?,
+            protocol_version: (
+                p.u64(&["protocolVersion.major"])?,
+                p.u64(&["protocolVersion.minor"])?,
+            ),
+        })
+    }
+
+    // Blockfrost `/epochs/{number}/parameters`
+    pub fn from_blockfrost_json(json: &str) -> Result<Self, PallasFunError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| PallasFunError::Json(e.to_string()))?;
+        Self::from_db_sync_fields(JsonFields(&value))
+    }
+
+    // Koios `/epoch_params`, a list of which the first entry is used
+    pub fn from_koios_json(json: &str) -> Result<Self, PallasFunError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| PallasFunError::Json(e.to_string()))?;
+        let params = match &value {
+            Value::Array(entries) => entries
+                .first()
+                .ok_or(PallasFunError::EmptySet("epoch parameters"))?,
+            params => params,
+        };
+        Self::from_db_sync_fields(JsonFields(params))
+    }
+
+    pub fn from_cardano_cli_file(path: impl AsRef<Path>) -> Result<Self, PallasFunError> {
+        Self::from_cardano_cli_json(&read_file(path)?)
+    }
+
+    pub fn from_blockfrost_file(path: impl AsRef<Path>) -> Result<Self, PallasFunError> {
+        Self::from_blockfrost_json(&read_file(path)?)
+    }
+
+    pub fn from_koios_file(path: impl AsRef<Path>) -> Result<Self, PallasFunError> {
+        Self::from_koios_json(&read_file(path)?)
+    }
+
+    // Blockfrost and Koios both serve the db-sync `epoch_param` table, with a
+    // few columns renamed; the first key is Blockfrost's, the second Koios'.
+    fn from_db_sync_fields(p: JsonFields) -> Result<Self, PallasFunError> {
+        Ok(Self {
+            minfee_a: p.u64(&["min_fee_a"])?,
+            minfee_b: p.u64(&["min_fee_b"])?,
+            max_block_body_size: p.u64(&["max_block_size"])?,
+            max_transaction_size: p.u64(&["max_tx_size"])?,
+            max_block_header_size: p.u64(&["max_block_header_size", "max_bh_size"])?,
+            key_deposit: p.u64(&["key_deposit"])?,
+            pool_deposit: p.u64(&["pool_deposit"])?,
+            maximum_epoch: p.u64(&["e_max", "max_epoch"])?,
+            desired_number_of_stake_pools: p.u64(&["n_opt", "optimal_pool_count"])?,
+            pool_pledge_influence: p.rational(&["a0", "influence"])?,
+            expansion_rate: p.rational(&["rho", "monetary_expand_rate"])?,
+            treasury_growth_rate: p.rational(&["tau", "treasury_growth_rate"])?,
+            min_pool_cost: p.u64(&["min_pool_cost"])?,
+            ada_per_utxo_byte: p.u64(&["coins_per_utxo_size"])?,
+            cost_models_for_script_languages: CostModels {
+                plutus_v1: p.cost_model(
+                    PlutusVersion::V1,
+                    &["cost_models_raw.PlutusV1", "cost_models.PlutusV1"],
+                )?,
+                plutus_v2: p.cost_model(
+                    PlutusVersion::V2,
+                    &["cost_models_raw.PlutusV2", "cost_models.PlutusV2"],
+                )?,
+                plutus_v3: p.cost_model(
+                    PlutusVersion::V3,
+                    &["cost_models_raw.PlutusV3", "cost_models.PlutusV3"],
+                )?,
+            },
+            execution_costs: ExUnitPrices {
+                mem_price: p.rational(&["price_mem"])?,
+                step_price: p.rational(&["price_step"])?,
+            },
+            max_tx_ex_units: ExUnits {
+                mem: p.u64(&["max_tx_ex_mem"])?,
+                steps: p.u64(&["max_tx_ex_steps"])?,
+            },
+            max_block_ex_units: ExUnits {
+                mem: p.u64(&["max_block_ex_mem"])?,
+                steps: p.u64(&["max_block_ex_steps"])?,
+            },
+            max_value_size: p.u64(&["max_val_size"])?,
+            collateral_percentage: p.u64(&["collateral_percent"])?,
+            max_collateral_inputs: p.u64(&["max_collateral_inputs"])?,
+            pool_voting_thresholds: PoolVotingThresholds {
+                motion_no_confidence: p.rational(&["pvt_motion_no_confidence"])?,
+                committee_normal: p.rational(&["pvt_committee_normal"])?,
+                committee_no_confidence: p.rational(&["pvt_committee_no_confidence"])?,
+                hard_fork_initiation: p.rational(&["pvt_hard_fork_initiation"])?,
+                security_voting_threshold: p
+                    .rational(&["pvt_p_p_security_group", "pvtpp_security_group"])?,
+            },
+            drep_voting_thresholds: DRepVotingThresholds {
+                motion_no_confidence: p.rational(&["dvt_motion_no_confidence"])?,
+                committee_normal: p.rational(&["dvt_committee_normal"])?,
+                committee_no_confidence: p.rational(&["dvt_committee_no_confidence"])?,
+                update_constitution: p.rational(&["dvt_update_to_constitution"])?,
+                hard_fork_initiation: p.rational(&["dvt_hard_fork_initiation"])?,
+                pp_network_group: p.rational(&["dvt_p_p_network_group"])?,
+                pp_economic_group: p.rational(&["dvt_p_p_economic_group"])?,
+                pp_technical_group: p.rational(&["dvt_p_p_technical_group"])?,
+                pp_governance_group: p.rational(&["dvt_p_p_gov_group"])?,
+                treasury_withdrawal: p.rational(&["dvt_treasury_withdrawal"])?,
+            },
+            min_committee_size: p.u64(&["committee_min_size"])?,
+            committee_term_limit: p.u64(&["committee_max_term_length"])?,
+            governance_action_validity_period: p.u64(&["gov_action_lifetime"])?,
+            governance_action_deposit: p.u64(&["gov_action_deposit"])?,
+            drep_deposit: p.u64(&["drep_deposit"])?,
+            drep_inactivity_period: p.u64(&["drep_activity"])?,
+            minfee_refscript_cost_per_byte: p.rational(&["min_fee_ref_script_cost_per_byte"])?,
+            protocol_version: (
+                p.u64(&["protocol_major_ver", "protocol_major"])?,
+                p.u64(&["protocol_minor_ver", "protocol_minor"])?,
+            ),
+        })
+    }
+
+    // Next parameter set once `update` is enacted. Cost models are merged per
+    // language, the protocol version only changes through a hard fork.
+    pub fn apply(&self, update: &ProtocolParamUpdateWrapper) -> Self {
+        let update = update.into_inner();
+
+        let mut cost_models = self.cost_models_for_script_languages.clone();
+        if let Some(updated) = update.cost_models_for_script_languages {
+            if updated.plutus_v1.is_some() {
+                cost_models.plutus_v1 = updated.plutus_v1;
+            }
+            if updated.plutus_v2.is_some() {
+                cost_models.plutus_v2 = updated.plutus_v2;
+            }
+            if updated.plutus_v3.is_some() {
+                cost_models.plutus_v3 = updated.plutus_v3;
+            }
+        }
+
+        Self {
+            minfee_a: update.minfee_a.unwrap_or(self.minfee_a),
+            minfee_b: update.minfee_b.unwrap_or(self.minfee_b),
+            max_block_body_size: update
+                .max_block_body_size
+                .unwrap_or(self.max_block_body_size),
+            max_transaction_size: update
+                .max_transaction_size
+                .unwrap_or(self.max_transaction_size),
+            max_block_header_size: update
+                .max_block_header_size
+                .unwrap_or(self.max_block_header_size),
+            key_deposit: update.key_deposit.unwrap_or(self.key_deposit),
+            pool_deposit: update.pool_deposit.unwrap_or(self.pool_deposit),
+            maximum_epoch: update.maximum_epoch.unwrap_or(self.maximum_epoch),
+            desired_number_of_stake_pools: update
+                .desired_number_of_stake_pools
+                .unwrap_or(self.desired_number_of_stake_pools),
+            pool_pledge_influence: update
+                .pool_pledge_influence
+                .unwrap_or_else(|| self.pool_pledge_influence.clone()),
+            expansion_rate: update
+                .expansion_rate
+                .unwrap_or_else(|| self.expansion_rate.clone()),
+            treasury_growth_rate: update
+                .treasury_growth_rate
+                .unwrap_or_else(|| self.treasury_growth_rate.clone()),
+            min_pool_cost: update.min_pool_cost.unwrap_or(self.min_pool_cost),
+            ada_per_utxo_byte: update.ada_per_utxo_byte.unwrap_or(self.ada_per_utxo_byte),
+            cost_models_for_script_languages: cost_models,
+            execution_costs: update
+                .execution_costs
+                .unwrap_or_else(|| self.execution_costs.clone()),
+            max_tx_ex_units: update.max_tx_ex_units.unwrap_or(self.max_tx_ex_units),
+            max_block_ex_units: update.max_block_ex_units.unwrap_or(self.max_block_ex_units),
+            max_value_size: update.max_value_size.unwrap_or(self.max_value_size),
+            collateral_percentage: update
+                .collateral_percentage
+                .unwrap_or(self.collateral_percentage),
+            max_collateral_inputs: update
+                .max_collateral_inputs
+                .unwrap_or(self.max_collateral_inputs),
+            pool_voting_thresholds: update
+                .pool_voting_thresholds
+                .unwrap_or_else(|| self.pool_voting_thresholds.clone()),
+            drep_voting_thresholds: update
+                .drep_voting_thresholds
+                .unwrap_or_else(|| self.drep_voting_thresholds.clone()),
+            min_committee_size: update.min_committee_size.unwrap_or(self.min_committee_size),
+            committee_term_limit: update
+                .committee_term_limit
+                .unwrap_or(self.committee_term_limit),
+            governance_action_validity_period: update
+                .governance_action_validity_period
+                .unwrap_or(self.governance_action_validity_period),
+            governance_action_deposit: update
+                .governance_action_deposit
+                .unwrap_or(self.governance_action_deposit),
+            drep_deposit: update.drep_deposit.unwrap_or(self.drep_deposit),
+            drep_inactivity_period: update
+                .drep_inactivity_period
+                .unwrap_or(self.drep_inactivity_period),
+            minfee_refscript_cost_per_byte: update
+                .minfee_refscript_cost_per_byte
+                .unwrap_or_else(|| self.minfee_refscript_cost_per_byte.clone()),
+            protocol_version: self.protocol_version,
+        }
+    }
+
+    // every parameter as an update, e.g. as a base for `ProtocolParamUpdateWrapper::diff`
+    pub fn to_update(&self) -> Result<ProtocolParamUpdateWrapper, PallasFunError> {
+        ProtocolParamUpdateBuilder::from_params(self).build()
+    }
+
+    // update that turns `self` into `proposed`
+    pub fn diff(&self, proposed: &Self) -> Result<ProtocolParamUpdateWrapper, PallasFunError> {
+        ProtocolParamUpdateWrapper::diff(&self.to_update()?, &proposed.to_update()?)
+    }
+}
+
+// Field lookup over loosely typed JSON: `a.b` paths, alternative keys, and
+// numbers that some APIs serialize as strings.
+struct JsonFields<'a>(&'a Value);
+
+impl JsonFields<'_> {
+    fn get(&self, keys: &[&str]) -> Option<&Value> {
+        keys.iter().find_map(|key| {
+            key.split('.')
+                .try_fold(self.0, |value, segment| value.get(segment))
+                .filter(|value| !value.is_null())
+        })
+    }
+
+    fn text(&self, keys: &[&str]) -> Result<String, PallasFunError> {
+        match self.get(keys) {
+            Some(Value::Number(number)) => Ok(number.to_string()),
+            Some(Value::String(string)) => Ok(string.clone()),
+            Some(other) => {
+                Err(PallasFunError::Json(format!("unexpected value {}", other)).at(keys[0]))
+            }
+            None => Err(PallasFunError::MissingField(keys[0].to_string())),
+        }
+    }
+
+    fn u64(&self, keys: &[&str]) -> Result<u64, PallasFunError> {
+        let text = self.text(keys)?;
+        text.parse()
+            .map_err(|_| PallasFunError::InvalidDecimal(text).at(keys[0]))
+    }
+
+    fn rational(&self, keys: &[&str]) -> Result<RationalNumber, PallasFunError> {
+        let (numerator, denominator) =
+            parse_decimal_rational(&self.text(keys)?).map_err(|e| e.at(keys[0]))?;
+        parse_rational_number(numerator, denominator)
+    }
+
+    // A missing language is fine, e.g. before it was introduced. Models given
+    // as a name to value object are put in cost model order.
+    fn cost_model(
+        &self,
+        version: PlutusVersion,
+        keys: &[&str],
+    ) -> Result<Option<Vec<i64>>, PallasFunError> {
+        self.get(keys)
+            .map(|value| {
+                let values = if value.is_object() {
+                    serde_json::from_value(value.clone())
+                        .map_err(|e| PallasFunError::Json(e.to_string()))
+                        .and_then(|named| cost_model_from_named(version, &named))
+                } else {
+                    serde_json::from_value(value.clone())
+                        .map_err(|e| PallasFunError::Json(e.to_string()))
+                };
+                values.map_err(|e| e.at(keys[0]))
+            })
+            .transpose()
+    }
+}
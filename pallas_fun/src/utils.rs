@@ -1,54 +1,72 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
 use std::str::FromStr;
 
 use pallas::{
-    codec::utils::{Bytes, Set},
+    codec::utils::{Bytes, NonEmptySet, Set},
+    crypto::hash::Hash,
     ledger::primitives::{
         AddrKeyhash, DatumHash, PoolKeyhash, PoolMetadataHash, RationalNumber, ScriptHash,
-        UnitInterval, VrfKeyhash,
+        StakeCredential, UnitInterval, VrfKeyhash,
     },
 };
 
+use crate::error::PallasFunError;
+
+// hex string to hash, reporting the expected and actual byte length
+pub fn parse_hash<const BYTES: usize>(
+    hash_str: &str,
+    kind: &'static str,
+) -> Result<Hash<BYTES>, PallasFunError> {
+    let bytes = hex::decode(hash_str).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+    if bytes.len() != BYTES {
+        return Err(PallasFunError::InvalidLength {
+            kind,
+            expected: BYTES,
+            actual: bytes.len(),
+        });
+    }
+    Ok(Hash::from(bytes.as_slice()))
+}
+
 // key hash parser from &str to key hashes
-pub fn parse_address_key_hash(address_key_hash_str: &str) -> Result<AddrKeyhash, String> {
-    address_key_hash_str
-        .parse()
-        .map_err(|_| "Invalid address key hash length".to_string())
+pub fn parse_address_key_hash(address_key_hash_str: &str) -> Result<AddrKeyhash, PallasFunError> {
+    parse_hash(address_key_hash_str, "address key hash")
 }
 
-pub fn parse_script_hash(script_hash_str: &str) -> Result<ScriptHash, String> {
-    script_hash_str
-        .parse()
-        .map_err(|_| "Invalid script hash length".to_string())
+pub fn parse_script_hash(script_hash_str: &str) -> Result<ScriptHash, PallasFunError> {
+    parse_hash(script_hash_str, "script hash")
 }
 
-pub fn parse_pool_key_hash(pool_keyhash_str: &str) -> Result<PoolKeyhash, String> {
-    pool_keyhash_str
-        .parse()
-        .map_err(|_| "Invalid pool key hash length".to_string())
+pub fn parse_pool_key_hash(pool_keyhash_str: &str) -> Result<PoolKeyhash, PallasFunError> {
+    parse_hash(pool_keyhash_str, "pool key hash")
 }
 
-pub fn parse_vrf_key_hash(vrf_keyhash_str: &str) -> Result<VrfKeyhash, String> {
-    vrf_keyhash_str
-        .parse()
-        .map_err(|_| "Invalid VRF key hash length".to_string())
+pub fn parse_vrf_key_hash(vrf_keyhash_str: &str) -> Result<VrfKeyhash, PallasFunError> {
+    parse_hash(vrf_keyhash_str, "VRF key hash")
 }
 
-pub fn parse_pool_metadata_hash(pool_metadata_key_hash: &str) -> Result<PoolMetadataHash, String> {
-    pool_metadata_key_hash
-        .parse()
-        .map_err(|_| "Invalid pool metadata key hash length".to_string())
+pub fn parse_pool_metadata_hash(
+    pool_metadata_key_hash: &str,
+) -> Result<PoolMetadataHash, PallasFunError> {
+    parse_hash(pool_metadata_key_hash, "pool metadata hash")
 }
 
-pub fn parse_datum_hash(datum_hash: &str) -> Result<DatumHash, String> {
-    datum_hash
-        .parse()
-        .map_err(|_| "Invalid datum hash length".to_string())
+pub fn parse_datum_hash(datum_hash: &str) -> Result<DatumHash, PallasFunError> {
+    parse_hash(datum_hash, "datum hash")
 }
 
 // UnitInterval parser from u64 to UnitInterval
-pub fn parse_rational_number(numerator: u64, denominator: u64) -> Result<UnitInterval, String> {
+pub fn parse_rational_number(
+    numerator: u64,
+    denominator: u64,
+) -> Result<UnitInterval, PallasFunError> {
     if denominator == 0 {
-        return Err("Denominator cannot be zero".to_string());
+        return Err(PallasFunError::InvalidRational {
+            numerator,
+            denominator,
+        });
     }
     Ok(RationalNumber {
         numerator,
@@ -56,6 +74,105 @@ pub fn parse_rational_number(numerator: u64, denominator: u64) -> Result<UnitInt
     })
 }
 
+// Exact rational from a decimal as found in cardano-cli / Blockfrost JSON,
+// e.g. "0.0577" -> 577/10000, "7.21e-5" -> 721/10000000
+pub fn parse_decimal_rational(decimal: &str) -> Result<(u64, u64), PallasFunError> {
+    let invalid = || PallasFunError::InvalidDecimal(decimal.to_string());
+
+    let (mantissa, exponent) = match decimal.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().map_err(|_| invalid())?),
+        None => (decimal, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    // every character is a digit, so parsing only fails on overflow
+    let mut numerator: u128 = digits
+        .parse()
+        .map_err(|_| PallasFunError::Overflow("decimal"))?;
+    let mut denominator: u128 = 1;
+    // i64 cannot overflow here: a usize length minus an i32 exponent
+    let scale = fraction.len() as i64 - exponent as i64;
+    let pow10 = |n: i64| {
+        u32::try_from(n)
+            .ok()
+            .and_then(|n| 10u128.checked_pow(n))
+            .ok_or(PallasFunError::Overflow("decimal"))
+    };
+    if scale >= 0 {
+        denominator = pow10(scale)?;
+    } else {
+        numerator = numerator
+            .checked_mul(pow10(-scale)?)
+            .ok_or(PallasFunError::Overflow("decimal"))?;
+    }
+
+    let divisor = gcd(numerator, denominator);
+    let numerator =
+        u64::try_from(numerator / divisor).map_err(|_| PallasFunError::Overflow("decimal"))?;
+    let denominator =
+        u64::try_from(denominator / divisor).map_err(|_| PallasFunError::Overflow("decimal"))?;
+    Ok((numerator, denominator))
+}
+
+// Decimal form of a rational for display, the inverse of `parse_decimal_rational`.
+// Exact when the expansion terminates within 20 digits, rounded to 10 otherwise.
+pub fn format_decimal_rational(numerator: u64, denominator: u64) -> String {
+    if denominator == 0 {
+        return format!("{}/{}", numerator, denominator);
+    }
+    let (numerator, denominator) = (numerator as u128, denominator as u128);
+    let integer = numerator / denominator;
+    let mut remainder = numerator % denominator;
+    let mut fraction = String::new();
+    while remainder != 0 && fraction.len() < 20 {
+        remainder *= 10;
+        fraction.push(char::from(b'0' + (remainder / denominator) as u8));
+        remainder %= denominator;
+    }
+    if remainder == 0 {
+        return if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{}.{}", integer, fraction)
+        };
+    }
+
+    let scale = 10u128.pow(10);
+    let rounded = (numerator * scale + denominator / 2) / denominator;
+    let fraction = format!("{:010}", rounded % scale);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (rounded / scale).to_string()
+    } else {
+        format!("{}.{}", rounded / scale, fraction)
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a.max(1) } else { gcd(b, a % b) }
+}
+
+// (numerator, denominator) of a ledger rational, the form `compare_rationals` takes
+pub fn rational_pair(rational: &RationalNumber) -> (u64, u64) {
+    (rational.numerator, rational.denominator)
+}
+
+// compares a/b with c/d without losing precision
+pub fn compare_rationals(a: (u64, u64), b: (u64, u64)) -> Ordering {
+    (a.0 as u128 * b.1 as u128).cmp(&(b.0 as u128 * a.1 as u128))
+}
+
+// hex hash of a key or script credential, as shown in error messages
+pub fn credential_hash(credential: &StakeCredential) -> String {
+    match credential {
+        StakeCredential::AddrKeyhash(hash) | StakeCredential::ScriptHash(hash) => hash.to_string(),
+    }
+}
+
 // pub fn parse_unit_interval(numerator: u64, denominator: u64) -> Result<UnitInterval, String> {
 //     if denominator == 0 {
 //         return Err("Denominator cannot be zero".to_string());
@@ -68,15 +185,33 @@ pub fn parse_rational_number(numerator: u64, denominator: u64) -> Result<UnitInt
 
 pub fn parse_vec_string_to_set_addr_keyhash(
     inputs: Vec<String>,
-) -> Result<Set<AddrKeyhash>, String> {
+) -> Result<Set<AddrKeyhash>, PallasFunError> {
     let mut result = Vec::with_capacity(inputs.len());
-    for s in inputs {
-        let keyhash = parse_address_key_hash(&s)?;
+    for (i, s) in inputs.iter().enumerate() {
+        let keyhash = parse_address_key_hash(s).map_err(|e| e.at(format!("[{}]", i)))?;
         result.push(keyhash);
     }
     Ok(Set::from(result))
 }
 
+// reads a UTF-8 file, reporting failures as `PallasFunError::Io`
+pub fn read_file(path: impl AsRef<Path>) -> Result<String, PallasFunError> {
+    fs::read_to_string(path).map_err(|e| PallasFunError::Io(e.to_string()))
+}
+
+// Collects fallible items, tagging a failure with `field[i]`, e.g. building
+// `outputs` from user data reports `outputs[2].address: ...`.
+pub fn collect_indexed<T>(
+    field: &str,
+    items: impl IntoIterator<Item = Result<T, PallasFunError>>,
+) -> Result<Vec<T>, PallasFunError> {
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| item.map_err(|e| e.at(format!("{}[{}]", field, i))))
+        .collect()
+}
+
 // pub fn parse_bytes(byte_str: &str) -> Result<Bytes, String> {
 //     Bytes::from_str(byte_str).map_err(|_| "Invalid byte length".to_string())
 // }
@@ -96,6 +231,19 @@ where
             .collect::<Vec<U>>(),
     )
 }
+
+pub fn parse_vec_wrapper_to_non_empty_set<T, U>(
+    inputs: Vec<T>,
+    kind: &'static str,
+) -> Result<NonEmptySet<U>, PallasFunError>
+where
+    T: IntoInner<U>,
+{
+    NonEmptySet::from_vec(inputs.into_iter().map(|w| w.into_inner()).collect())
+        .ok_or(PallasFunError::EmptySet(kind))
+}
+
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoInner<T> {
     fn into_inner(&self) -> T;
 }
@@ -0,0 +1,371 @@
+// Required-signer and witness inference for a transaction body
+use std::collections::BTreeSet;
+
+use pallas::codec::utils::{Bytes, Nullable};
+use pallas::ledger::addresses::{Address, ShelleyPaymentPart};
+use pallas::ledger::primitives::conway::{
+    Certificate, GovAction, PseudoTransactionOutput, TransactionBody, TransactionOutput, Voter,
+};
+use pallas::ledger::primitives::{
+    AddrKeyhash, PolicyId, RewardAccount, ScriptHash, StakeCredential, TransactionInput,
+};
+
+use crate::error::PallasFunError;
+use crate::utils::IntoInner;
+use crate::wrapper::{TransactionBodyWrapper, TransactionInputWrapper, TransactionOutputWrapper};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum RequiredWitness {
+    VKey(AddrKeyhash),
+    Script(ScriptHash),
+    Bootstrap(Bytes), // byron address, needs a bootstrap witness
+}
+
+// why a witness is needed, for fee estimation breakdowns and signing UIs
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WitnessSource {
+    Input(TransactionInput),
+    Collateral(TransactionInput),
+    Certificate(usize), // position in the body
+    Withdrawal(RewardAccount),
+    Voter(Voter),
+    Proposal(usize), // guardrail script, position in the body
+    RequiredSigner,
+    Mint(PolicyId),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RequiredWitnesses {
+    pub entries: Vec<(RequiredWitness, WitnessSource)>,
+}
+
+impl RequiredWitnesses {
+    // Every vkey hash and script hash that must witness `transaction_body`.
+    // `resolved_inputs` must contain the outputs spent by inputs and collateral.
+    pub fn new(
+        transaction_body: &TransactionBodyWrapper,
+        resolved_inputs: &[(TransactionInputWrapper, TransactionOutputWrapper)],
+    ) -> Result<Self, PallasFunError> {
+        let body: TransactionBody = transaction_body.into_inner();
+        let resolved: Vec<(TransactionInput, TransactionOutput)> = resolved_inputs
+            .iter()
+            .map(|(input, output)| (input.into_inner(), output.into_inner()))
+            .collect();
+        let mut witnesses = Self::default();
+
+        for input in body.inputs.iter() {
+            let witness = Self::payment_witness(input, &resolved)?;
+            witnesses.push(witness, WitnessSource::Input(input.clone()));
+        }
+
+        if let Some(collateral) = &body.collateral {
+            for input in collateral.iter() {
+                let witness = Self::payment_witness(input, &resolved)?;
+                witnesses.push(witness, WitnessSource::Collateral(input.clone()));
+            }
+        }
+
+        if let Some(certificates) = &body.certificates {
+            for (index, certificate) in certificates.iter().enumerate() {
+                for witness in Self::certificate_witnesses(certificate) {
+                    witnesses.push(witness, WitnessSource::Certificate(index));
+                }
+            }
+        }
+
+        if let Some(withdrawals) = &body.withdrawals {
+            for (reward_account, _) in withdrawals.iter() {
+                let witness = Self::reward_account_witness(reward_account)?;
+                witnesses.push(witness, WitnessSource::Withdrawal(reward_account.clone()));
+            }
+        }
+
+        if let Some(voting_procedures) = &body.voting_procedures {
+            for (voter, _) in voting_procedures.iter() {
+                let witness = match voter {
+                    Voter::ConstitutionalCommitteeKey(hash)
+                    | Voter::DRepKey(hash)
+                    | Voter::StakePoolKey(hash) => RequiredWitness::VKey(*hash),
+                    Voter::ConstitutionalCommitteeScript(hash) | Voter::DRepScript(hash) => {
+                        RequiredWitness::Script(*hash)
+                    }
+                };
+                witnesses.push(witness, WitnessSource::Voter(voter.clone()));
+            }
+        }
+
+        if let Some(proposal_procedures) = &body.proposal_procedures {
+            for (index, proposal) in proposal_procedures.iter().enumerate() {
+                let guardrail = match &proposal.gov_action {
+                    GovAction::ParameterChange(_, _, Nullable::Some(hash))
+                    | GovAction::TreasuryWithdrawals(_, Nullable::Some(hash)) => Some(*hash),
+                    _ => None,
+                };
+                if let Some(hash) = guardrail {
+                    witnesses.push(
+                        RequiredWitness::Script(hash),
+                        WitnessSource::Proposal(index),
+                    );
+                }
+            }
+        }
+
+        if let Some(required_signers) = &body.required_signers {
+            for hash in required_signers.iter() {
+                witnesses.push(RequiredWitness::VKey(*hash), WitnessSource::RequiredSigner);
+            }
+        }
+
+        if let Some(mint) = &body.mint {
+            for (policy_id, _) in mint.iter() {
+                witnesses.push(
+                    RequiredWitness::Script(*policy_id),
+                    WitnessSource::Mint(*policy_id),
+                );
+            }
+        }
+
+        Ok(witnesses)
+    }
+
+    pub fn vkey_hashes(&self) -> BTreeSet<AddrKeyhash> {
+        self.entries
+            .iter()
+            .filter_map(|(witness, _)| match witness {
+                RequiredWitness::VKey(hash) => Some(*hash),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn script_hashes(&self) -> BTreeSet<ScriptHash> {
+        self.entries
+            .iter()
+            .filter_map(|(witness, _)| match witness {
+                RequiredWitness::Script(hash) => Some(*hash),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn bootstrap_addresses(&self) -> BTreeSet<Bytes> {
+        self.entries
+            .iter()
+            .filter_map(|(witness, _)| match witness {
+                RequiredWitness::Bootstrap(address) => Some(address.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn sources(&self, witness: &RequiredWitness) -> Vec<&WitnessSource> {
+        self.entries
+            .iter()
+            .filter(|(w, _)| w == witness)
+            .map(|(_, source)| source)
+            .collect()
+    }
+
+    fn push(&mut self, witness: RequiredWitness, source: WitnessSource) {
+        self.entries.push((witness, source));
+    }
+
+    fn payment_witness(
+        input: &TransactionInput,
+        resolved: &[(TransactionInput, TransactionOutput)],
+    ) -> Result<RequiredWitness, PallasFunError> {
+        let output = resolved
+            .iter()
+            .find(|(i, _)| i == input)
+            .map(|(_, output)| output)
+            .ok_or_else(|| {
+                PallasFunError::UnresolvedInput(format!("{}#{}", input.transaction_id, input.index))
+            })?;
+        let address = match output {
+            PseudoTransactionOutput::PostAlonzo(o) => &o.address,
+            PseudoTransactionOutput::Legacy(o) => &o.address,
+        };
+        match Address::from_bytes(address) {
+            Ok(Address::Shelley(shelley)) => Ok(match shelley.payment() {
+                ShelleyPaymentPart::Key(hash) => RequiredWitness::VKey(*hash),
+                ShelleyPaymentPart::Script(hash) => RequiredWitness::Script(*hash),
+            }),
+            Ok(Address::Byron(_)) => Ok(RequiredWitness::Bootstrap(address.clone())),
+            _ => Err(PallasFunError::InvalidAddress(format!(
+                "input {}#{} is not locked by a payment address",
+                input.transaction_id, input.index
+            ))),
+        }
+    }
+
+    fn credential_witness(credential: &StakeCredential) -> RequiredWitness {
+        match credential {
+            StakeCredential::AddrKeyhash(hash) => RequiredWitness::VKey(*hash),
+            StakeCredential::ScriptHash(hash) => RequiredWitness::Script(*hash),
+        }
+    }
+
+    fn reward_account_witness(
+        reward_account: &RewardAccount,
+    ) -> Result<RequiredWitness, PallasFunError> {
+        if reward_account.len() != 29 {
+            return Err(PallasFunError::InvalidLength {
+                kind: "reward account",
+                expected: 29,
+                actual: reward_account.len(),
+            });
+        }
+        let hash: AddrKeyhash = AddrKeyhash::from(&reward_account[1..]);
+        Ok(if reward_account[0] & 0x10 == 0 {
+            RequiredWitness::VKey(hash)
+        } else {
+            RequiredWitness::Script(hash)
+        })
+    }
+
+    fn certificate_witnesses(certificate: &Certificate) -> Vec<RequiredWitness> {
+        match certificate {
+            // the pre-Conway registration certificate carries no deposit and needs no witness
+            Certificate::StakeRegistration(_) => vec![],
+            Certificate::StakeDeregistration(credential)
+            | Certificate::StakeDelegation(credential, _)
+            | Certificate::Reg(credential, _)
+            | Certificate::UnReg(credential, _)
+            | Certificate::VoteDeleg(credential, _)
+            | Certificate::StakeVoteDeleg(credential, _, _)
+            | Certificate::StakeRegDeleg(credential, _, _)
+            | Certificate::VoteRegDeleg(credential, _, _)
+            | Certificate::StakeVoteRegDeleg(credential, _, _, _)
+            | Certificate::AuthCommitteeHot(credential, _)
+            | Certificate::ResignCommitteeCold(credential, _)
+            | Certificate::RegDRepCert(credential, _, _)
+            | Certificate::UnRegDRepCert(credential, _)
+            | Certificate::UpdateDRepCert(credential, _) => {
+                vec![Self::credential_witness(credential)]
+            }
+            Certificate::PoolRegistration {
+                operator,
+                pool_owners,
+                ..
+            } => std::iter::once(*operator)
+                .chain(pool_owners.iter().copied())
+                .map(RequiredWitness::VKey)
+                .collect(),
+            Certificate::PoolRetirement(pool_key_hash, _) => {
+                vec![RequiredWitness::VKey(*pool_key_hash)]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wrapper::{
+        CertificateKind, CertificateWrapper, RequiredSignersWrapper, RewardAccountWrapper,
+        StakeCredentialKind, StakeCredentialWrapper, ValueWrapper,
+    };
+
+    const KEY_HASH: &str = "276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+    const SCRIPT_HASH: &str = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+    const SIGNER_HASH: &str = "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeaddead";
+
+    #[test]
+    fn test_required_witnesses_for_body() {
+        let input = TransactionInputWrapper::new(
+            "aabbccddeeff00112233445566778899aabbccddeeff00112233445566778899",
+            0,
+        )
+        .unwrap();
+        let output = TransactionOutputWrapper::new(
+            format!("61{}", KEY_HASH),
+            ValueWrapper::new(10_000_000, None),
+            None,
+            None,
+        )
+        .unwrap();
+        let certificate = CertificateWrapper::new(CertificateKind::UnReg {
+            stake_credential_wrapper: StakeCredentialWrapper::new(StakeCredentialKind::ScriptHash(
+                SCRIPT_HASH.to_string(),
+            ))
+            .unwrap(),
+            amount: 2_000_000,
+        })
+        .unwrap();
+        let withdrawal = RewardAccountWrapper::new(&format!("f1{}", SCRIPT_HASH)).unwrap();
+
+        let body = TransactionBodyWrapper::new(
+            vec![input.clone()],
+            vec![output.clone()],
+            200_000,
+            None,
+            Some(vec![certificate]),
+            Some(vec![(withdrawal, 1)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(RequiredSignersWrapper::new(vec![SIGNER_HASH]).unwrap()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let witnesses = RequiredWitnesses::new(&body, &[(input, output)]).unwrap();
+
+        assert_eq!(
+            witnesses.vkey_hashes(),
+            BTreeSet::from([KEY_HASH.parse().unwrap(), SIGNER_HASH.parse().unwrap()])
+        );
+        assert_eq!(
+            witnesses.script_hashes(),
+            BTreeSet::from([SCRIPT_HASH.parse().unwrap()])
+        );
+        assert_eq!(
+            witnesses
+                .sources(&RequiredWitness::Script(SCRIPT_HASH.parse().unwrap()))
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_required_witnesses_unresolved_input() {
+        let input = TransactionInputWrapper::new(
+            "aabbccddeeff00112233445566778899aabbccddeeff00112233445566778899",
+            0,
+        )
+        .unwrap();
+        let body = TransactionBodyWrapper::new(
+            vec![input],
+            vec![],
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(RequiredWitnesses::new(&body, &[]).is_err());
+    }
+}
@@ -1,5 +1,9 @@
+use std::fs;
+use std::path::Path;
+
 use hex;
 pub use pallas::crypto::hash::Hash;
+use pallas::crypto::hash::Hasher;
 use pallas::ledger::primitives::Fragment;
 use pallas::{
     codec::minicbor::{self, Decode, Encode},
@@ -7,7 +11,11 @@ use pallas::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::IntoInner;
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_hash};
+
+// ledger limit on anchor URLs, in bytes
+pub const MAX_ANCHOR_URL_LENGTH: usize = 128;
 
 #[derive(Serialize, Deserialize, Encode, Decode, Debug, PartialEq, Eq, Clone)]
 pub struct AnchorWrapper {
@@ -16,24 +24,63 @@ pub struct AnchorWrapper {
 }
 
 impl AnchorWrapper {
-    pub fn new(url: String, content_hash: String) -> Result<Self, String> {
-        let content_hash: Hash<32> = content_hash
-            .parse()
-            .map_err(|_| "Invalid transaction id length".to_string())?;
-
-        Ok(Self {
+    pub fn new(url: String, content_hash: String) -> Result<Self, PallasFunError> {
+        let content_hash: Hash<32> = parse_hash(&content_hash, "anchor content hash")?;
+        let anchor = Self {
             inner: Anchor { url, content_hash },
-        })
+        };
+        anchor.check_url()?;
+
+        Ok(anchor)
+    }
+
+    // anchor for a document published at `url`, hashed as served
+    pub fn from_content(url: String, content: &[u8]) -> Result<Self, PallasFunError> {
+        Self::new(url, anchor_content_hash(content).to_string())
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn verify(&self, content: &[u8]) -> Result<(), PallasFunError> {
+        let computed = anchor_content_hash(content);
+        if computed != self.inner.content_hash {
+            return Err(PallasFunError::AnchorHashMismatch {
+                declared: self.inner.content_hash.to_string(),
+                computed: computed.to_string(),
+            });
+        }
+        Ok(())
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
-        let pallas_anchor =
-            Anchor::decode_fragment(&bytes).map_err(|e| format!("Fragment decode error: {}", e))?;
+    // checks a downloaded copy of the anchored document
+    pub fn verify_file(&self, path: impl AsRef<Path>) -> Result<(), PallasFunError> {
+        let content = fs::read(path).map_err(|e| PallasFunError::Io(e.to_string()))?;
+        self.verify(&content)
+    }
+
+    // decoded anchors are not checked, so builders call this again
+    pub fn check_url(&self) -> Result<(), PallasFunError> {
+        if self.inner.url.len() > MAX_ANCHOR_URL_LENGTH {
+            return Err(PallasFunError::TooLong {
+                kind: "anchor url",
+                max: MAX_ANCHOR_URL_LENGTH,
+                actual: self.inner.url.len(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+        let pallas_anchor = Anchor::decode_fragment(&bytes)
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_anchor,
@@ -41,6 +88,11 @@ impl AnchorWrapper {
     }
 }
 
+// blake2b-256 of the raw document bytes, no normalisation
+pub fn anchor_content_hash(content: &[u8]) -> Hash<32> {
+    Hasher::<256>::hash(content)
+}
+
 impl IntoInner<Anchor> for AnchorWrapper {
     fn into_inner(&self) -> Anchor {
         self.inner.clone()
@@ -5,6 +5,7 @@ use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::Certificate;
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::{
     IntoInner, parse_pool_key_hash, parse_rational_number, parse_vec_string_to_set_addr_keyhash,
     parse_vrf_key_hash,
@@ -110,7 +111,7 @@ pub struct CertificateWrapper {
 }
 
 impl CertificateWrapper {
-    pub fn new(certificate: CertificateKind) -> Result<Self, String> {
+    pub fn new(certificate: CertificateKind) -> Result<Self, PallasFunError> {
         // Convert the CertificateKind into a Pallas Certificate
         let pallas_certificate = match certificate {
             CertificateKind::StakeRegistration {
@@ -126,7 +127,8 @@ impl CertificateWrapper {
                 pool_key_hash,
             } => Certificate::StakeDelegation(
                 stake_credential_wrapper.into_inner(),
-                parse_pool_key_hash(&pool_key_hash)?,
+                parse_pool_key_hash(&pool_key_hash)
+                    .map_err(|e| e.at("StakeDelegation.pool_key_hash"))?,
             ),
 
             CertificateKind::PoolRegistration {
@@ -141,13 +143,17 @@ impl CertificateWrapper {
                 relay_wrappers,
                 pool_metadata_wrapper,
             } => Certificate::PoolRegistration {
-                operator: parse_pool_key_hash(&operator)?,
-                vrf_keyhash: parse_vrf_key_hash(&vrf_keyhash)?,
+                operator: parse_pool_key_hash(&operator)
+                    .map_err(|e| e.at("PoolRegistration.operator"))?,
+                vrf_keyhash: parse_vrf_key_hash(&vrf_keyhash)
+                    .map_err(|e| e.at("PoolRegistration.vrf_keyhash"))?,
                 pledge,
                 cost,
-                margin: parse_rational_number(margin_nominator, margin_denominator)?,
+                margin: parse_rational_number(margin_nominator, margin_denominator)
+                    .map_err(|e| e.at("PoolRegistration.margin"))?,
                 reward_account: reward_account_wrapper.into_inner(),
-                pool_owners: parse_vec_string_to_set_addr_keyhash(pool_owners)?,
+                pool_owners: parse_vec_string_to_set_addr_keyhash(pool_owners)
+                    .map_err(|e| e.at("PoolRegistration.pool_owners"))?,
                 relays: relay_wrappers.into_iter().map(|r| r.into_inner()).collect(),
                 pool_metadata: Nullable::from(pool_metadata_wrapper.map(|pm| pm.into_inner())),
             },
@@ -155,7 +161,11 @@ impl CertificateWrapper {
             CertificateKind::PoolRetirement {
                 pool_key_hash,
                 epoch,
-            } => Certificate::PoolRetirement(parse_pool_key_hash(&pool_key_hash)?, epoch),
+            } => Certificate::PoolRetirement(
+                parse_pool_key_hash(&pool_key_hash)
+                    .map_err(|e| e.at("PoolRetirement.pool_key_hash"))?,
+                epoch,
+            ),
 
             CertificateKind::Reg {
                 stake_credential_wrapper,
@@ -180,7 +190,8 @@ impl CertificateWrapper {
                 drep_wrapper,
             } => Certificate::StakeVoteDeleg(
                 stake_credential_wrapper.into_inner(),
-                parse_pool_key_hash(&pool_key_hash)?,
+                parse_pool_key_hash(&pool_key_hash)
+                    .map_err(|e| e.at("StakeVoteDeleg.pool_key_hash"))?,
                 drep_wrapper.into_inner(),
             ),
 
@@ -190,7 +201,8 @@ impl CertificateWrapper {
                 amount,
             } => Certificate::StakeRegDeleg(
                 stake_credential_wrapper.into_inner(),
-                parse_pool_key_hash(&pool_key_hash)?,
+                parse_pool_key_hash(&pool_key_hash)
+                    .map_err(|e| e.at("StakeRegDeleg.pool_key_hash"))?,
                 amount,
             ),
 
@@ -211,7 +223,8 @@ impl CertificateWrapper {
                 amount,
             } => Certificate::StakeVoteRegDeleg(
                 stake_credential_wrapper.into_inner(),
-                parse_pool_key_hash(&pool_key_hash)?,
+                parse_pool_key_hash(&pool_key_hash)
+                    .map_err(|e| e.at("StakeVoteRegDeleg.pool_key_hash"))?,
                 drep_wrapper.into_inner(),
                 amount,
             ),
@@ -259,14 +272,19 @@ impl CertificateWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let certificate = Certificate::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self { inner: certificate })
     }
 }
@@ -299,7 +317,7 @@ mod tests {
         let wrapper =
             CertificateWrapper::new(cert_kind).expect("should create certificate wrapper");
 
-        let encoded = wrapper.encode();
+        let encoded = wrapper.encode().expect("should encode");
         let decoded = CertificateWrapper::decode(encoded).expect("should decode");
 
         assert_eq!(wrapper, decoded);
@@ -327,4 +345,41 @@ mod tests {
             "should fail to create certificate wrapper with invalid keyhash"
         );
     }
+
+    #[test]
+    fn test_certificate_wrapper_error_path() {
+        let owner = "276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3".to_string();
+        let cert_kind = CertificateKind::PoolRegistration {
+            operator: "276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3".to_string(),
+            vrf_keyhash: "aabbccddeeff00112233445566778899aabbccddeeff00112233445566778899"
+                .to_string(),
+            pledge: 0,
+            cost: 340_000_000,
+            margin_nominator: 1,
+            margin_denominator: 100,
+            reward_account_wrapper: RewardAccountWrapper::new(
+                "e1276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3",
+            )
+            .unwrap(),
+            pool_owners: vec![owner.clone(), owner.clone(), owner, "abcd".to_string()],
+            relay_wrappers: vec![],
+            pool_metadata_wrapper: None,
+        };
+
+        let err = CertificateWrapper::new(cert_kind)
+            .map_err(|e| e.at("certificates[0]"))
+            .unwrap_err();
+        assert_eq!(
+            err.path(),
+            Some("certificates[0].PoolRegistration.pool_owners[3]")
+        );
+        assert_eq!(
+            err.root_cause(),
+            &PallasFunError::InvalidLength {
+                kind: "address key hash",
+                expected: 28,
+                actual: 2,
+            }
+        );
+    }
 }
@@ -7,6 +7,7 @@ use pallas::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_script_hash};
 use crate::wrapper::anchor::AnchorWrapper;
 
@@ -20,7 +21,7 @@ impl ConstitutionWrapper {
     pub fn new(
         anchor_wrapper: AnchorWrapper,
         guardrail_script_hash: Option<String>,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, PallasFunError> {
         let guardrail_script = match guardrail_script_hash {
             Some(hash) => Nullable::Some(parse_script_hash(&hash)?),
             None => Nullable::Null,
@@ -34,14 +35,19 @@ impl ConstitutionWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_constitution = Constitution::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_constitution,
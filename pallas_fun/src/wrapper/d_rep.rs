@@ -1,8 +1,12 @@
+use crate::cip129::{
+    GovCredentialRole, decode_credential, encode_credential, encode_legacy_credential,
+};
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_address_key_hash, parse_script_hash};
 use hex;
 use pallas::codec::minicbor::{self, Decode, Encode};
-use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::DRep;
+use pallas::ledger::primitives::{Fragment, StakeCredential};
 use serde::{Deserialize, Serialize};
 
 pub enum DRepKind {
@@ -18,7 +22,7 @@ pub struct DRepWrapper {
 }
 
 impl DRepWrapper {
-    pub fn new(drep: DRepKind) -> Result<Self, String> {
+    pub fn new(drep: DRepKind) -> Result<Self, PallasFunError> {
         let pallas_drep = match drep {
             DRepKind::Key { addr_key_hash } => DRep::Key(parse_address_key_hash(&addr_key_hash)?),
             DRepKind::Script { script_hash } => DRep::Script(parse_script_hash(&script_hash)?),
@@ -29,14 +33,53 @@ impl DRepWrapper {
         Ok(Self { inner: pallas_drep })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    // CIP-129 `drep1...` with header byte
+    pub fn to_bech32(&self) -> Result<String, PallasFunError> {
+        Ok(encode_credential(
+            GovCredentialRole::DRep,
+            &self.credential()?,
+        ))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    // CIP-105 `drep1...` or `drep_script1...`
+    pub fn to_legacy_bech32(&self) -> Result<String, PallasFunError> {
+        Ok(encode_legacy_credential(
+            GovCredentialRole::DRep,
+            &self.credential()?,
+        ))
+    }
+
+    pub fn from_bech32(bech32: &str) -> Result<Self, PallasFunError> {
+        let inner = match decode_credential(GovCredentialRole::DRep, bech32)? {
+            StakeCredential::AddrKeyhash(hash) => DRep::Key(hash),
+            StakeCredential::ScriptHash(hash) => DRep::Script(hash),
+        };
+        Ok(Self { inner })
+    }
+
+    fn credential(&self) -> Result<StakeCredential, PallasFunError> {
+        match &self.inner {
+            DRep::Key(hash) => Ok(StakeCredential::AddrKeyhash(*hash)),
+            DRep::Script(hash) => Ok(StakeCredential::ScriptHash(*hash)),
+            DRep::Abstain | DRep::NoConfidence => Err(PallasFunError::Bech32(
+                "predefined DReps have no bech32 id".to_string(),
+            )),
+        }
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_drep =
-            DRep::decode_fragment(&bytes).map_err(|e| format!("Fragment decode error: {}", e))?;
+            DRep::decode_fragment(&bytes).map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self { inner: pallas_drep })
     }
 }
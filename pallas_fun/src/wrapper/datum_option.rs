@@ -4,6 +4,7 @@ use pallas::codec::utils::CborWrap;
 use pallas::ledger::primitives::conway::DatumOption;
 use pallas::ledger::primitives::{Fragment, PlutusData};
 
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_datum_hash};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -19,7 +20,7 @@ pub struct DatumOptionWrapper {
 }
 
 impl DatumOptionWrapper {
-    pub fn new(datum_option_kind: DatumOptionKind) -> Result<Self, String> {
+    pub fn new(datum_option_kind: DatumOptionKind) -> Result<Self, PallasFunError> {
         let pallas_datum_option = match datum_option_kind {
             DatumOptionKind::Hash { datum_hash } => {
                 let datum_hash = parse_datum_hash(&datum_hash)?;
@@ -27,11 +28,11 @@ impl DatumOptionWrapper {
             }
 
             DatumOptionKind::Data { plutus_data_hex } => {
-                let bytes =
-                    hex::decode(plutus_data_hex).map_err(|e| format!("Hex decode error: {}", e))?;
+                let bytes = hex::decode(plutus_data_hex)
+                    .map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
                 PlutusData::decode_fragment(&bytes)
                     .map(|e| DatumOption::Data(CborWrap(e)))
-                    .map_err(|e| format!("Fragment decode error: {}", e))?
+                    .map_err(|e| PallasFunError::CborDecode(e.to_string()))?
             }
         };
 
@@ -40,14 +41,19 @@ impl DatumOptionWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_datum_option = DatumOption::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_datum_option,
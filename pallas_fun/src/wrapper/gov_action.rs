@@ -5,6 +5,7 @@ use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::GovAction;
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_rational_number, parse_script_hash, parse_vec_wrapper_to_set};
 use crate::wrapper::constitution::ConstitutionWrapper;
 use crate::wrapper::gov_action_id::GovActionIdWrapper;
@@ -50,7 +51,7 @@ pub struct GovActionWrapper {
 }
 
 impl GovActionWrapper {
-    pub fn new(gov_action_kind: GovActionKind) -> Result<Self, String> {
+    pub fn new(gov_action_kind: GovActionKind) -> Result<Self, PallasFunError> {
         let pallas_gov_action = match gov_action_kind {
             GovActionKind::ParameterChange {
                 gov_action_id_wrapper,
@@ -60,7 +61,10 @@ impl GovActionWrapper {
                 Nullable::from(gov_action_id_wrapper.map(|w| w.into_inner())),
                 Box::new(params.into_inner()),
                 Nullable::from(match script_hash {
-                    Some(hash) => Some(parse_script_hash(&hash)?),
+                    Some(hash) => Some(
+                        parse_script_hash(&hash)
+                            .map_err(|e| e.at("ParameterChange.script_hash"))?,
+                    ),
                     None => None,
                 }),
             ),
@@ -81,7 +85,10 @@ impl GovActionWrapper {
                     },
                 )),
                 Nullable::from(match script_hash {
-                    Some(hash) => Some(parse_script_hash(&hash)?),
+                    Some(hash) => Some(
+                        parse_script_hash(&hash)
+                            .map_err(|e| e.at("TreasuryWithdrawals.script_hash"))?,
+                    ),
                     None => None,
                 }),
             ),
@@ -105,7 +112,8 @@ impl GovActionWrapper {
                         .into_iter()
                         .map(|(w, amount)| (w.into_inner(), amount)),
                 ),
-                parse_rational_number(unit_interval.0, unit_interval.1)?,
+                parse_rational_number(unit_interval.0, unit_interval.1)
+                    .map_err(|e| e.at("UpdateCommittee.unit_interval"))?,
             ),
 
             GovActionKind::NewConstitution {
@@ -124,14 +132,19 @@ impl GovActionWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let gov_action = GovAction::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self { inner: gov_action })
     }
 }
@@ -5,7 +5,9 @@ use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::GovActionId;
 use serde::{Deserialize, Serialize};
 
-use crate::utils::IntoInner;
+use crate::cip129::{decode_gov_action_id, encode_gov_action_id};
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_hash};
 
 #[derive(Encode, Decode, Serialize, Deserialize, Debug, PartialEq, Eq, Clone)] // #[derive(PartialOrd, Ord] somehow missing
 pub struct GovActionIdWrapper {
@@ -14,10 +16,8 @@ pub struct GovActionIdWrapper {
 }
 
 impl GovActionIdWrapper {
-    pub fn new(transaction_id: &str, index: u32) -> Result<Self, String> {
-        let digest: Hash<32> = transaction_id
-            .parse()
-            .map_err(|_| "Invalid transaction id length".to_string())?;
+    pub fn new(transaction_id: &str, index: u32) -> Result<Self, PallasFunError> {
+        let digest: Hash<32> = parse_hash(transaction_id, "transaction id")?;
 
         Ok(Self {
             inner: GovActionId {
@@ -27,14 +27,30 @@ impl GovActionIdWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    // CIP-129 `gov_action1...`
+    pub fn to_bech32(&self) -> Result<String, PallasFunError> {
+        encode_gov_action_id(&self.inner)
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn from_bech32(bech32: &str) -> Result<Self, PallasFunError> {
+        Ok(Self {
+            inner: decode_gov_action_id(bech32)?,
+        })
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let gov_action_id = GovActionId::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self {
             inner: gov_action_id,
         })
@@ -1,4 +1,3 @@
-mod alonzo_value;
 mod anchor;
 mod certificate;
 mod constitution;
@@ -10,13 +9,18 @@ mod multiasset_non_zero_int;
 mod multiasset_positive_coin;
 mod pool_metadata;
 mod proposal_procedure;
+mod proposal_procedure_builder;
 mod protocol_param_update;
+mod protocol_param_update_builder;
+mod redeemer;
+mod redeemers;
 mod relay;
 mod required_signers;
 mod reward_account;
 mod script_ref;
 mod stake_credential;
 mod transaction_body;
+mod transaction_body_builder;
 mod transaction_input;
 mod transaction_output;
 mod value;
@@ -34,12 +38,18 @@ pub use multiasset_non_zero_int::*;
 pub use multiasset_positive_coin::*;
 pub use pool_metadata::*;
 pub use proposal_procedure::*;
+pub use proposal_procedure_builder::*;
 pub use protocol_param_update::*;
+pub use protocol_param_update_builder::*;
+pub use redeemer::*;
+pub use redeemers::*;
 pub use relay::*;
 pub use required_signers::*;
 pub use reward_account::*;
 pub use script_ref::*;
+pub use stake_credential::*;
 pub use transaction_body::*;
+pub use transaction_body_builder::*;
 pub use transaction_input::*;
 pub use transaction_output::*;
 pub use value::*;
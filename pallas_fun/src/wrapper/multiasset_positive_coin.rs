@@ -6,7 +6,8 @@ use pallas::ledger::primitives::{AssetName, Fragment, PolicyId};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-use crate::utils::IntoInner;
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_hash};
 
 #[derive(
     Serialize,
@@ -23,66 +24,72 @@ use crate::utils::IntoInner;
 )]
 pub struct MultiassetPositiveCoinWrapper {
     #[n(0)]
-     inner: Multiasset<PositiveCoin>,
+    inner: Multiasset<PositiveCoin>,
 }
 
 impl MultiassetPositiveCoinWrapper {
-    fn parse_policy_id(policy_str: &str) -> Result<PolicyId, String> {
-        policy_str
-            .parse()
-            .map_err(|_| "Invalid policy ID length".to_string())
+    fn parse_policy_id(policy_str: &str) -> Result<PolicyId, PallasFunError> {
+        parse_hash(policy_str, "policy id")
     }
 
-    fn parse_asset_name(asset_str: &str) -> Result<AssetName, String> {
-        Bytes::from_str(asset_str).map_err(|_| "Invalid asset name length".to_string())
+    fn parse_asset_name(asset_str: &str) -> Result<AssetName, PallasFunError> {
+        Bytes::from_str(asset_str).map_err(|e| PallasFunError::HexDecode(e.to_string()))
     }
 
     fn parse_asset_vec(
         asset_vec: Vec<(String, u64)>,
-    ) -> Result<NonEmptyKeyValuePairs<AssetName, PositiveCoin>, String> {
+    ) -> Result<NonEmptyKeyValuePairs<AssetName, PositiveCoin>, PallasFunError> {
         let mut asset_pallas = Vec::new();
-        for (asset_str, amount_u64) in asset_vec {
-            let asset_name = Self::parse_asset_name(&asset_str)?;
-            let amount =
-                PositiveCoin::try_from(amount_u64).map_err(|_| "Invalid amount".to_string())?;
+        for (i, (asset_str, amount_u64)) in asset_vec.into_iter().enumerate() {
+            let asset_name =
+                Self::parse_asset_name(&asset_str).map_err(|e| e.at(format!("asset[{}]", i)))?;
+            let amount = PositiveCoin::try_from(amount_u64).map_err(|_| {
+                PallasFunError::ZeroAmount("asset amount").at(format!("asset[{}]", i))
+            })?;
             asset_pallas.push((asset_name, amount));
         }
-        NonEmptyKeyValuePairs::from_vec(asset_pallas).ok_or_else(|| "Invalid asset".to_string())
+        NonEmptyKeyValuePairs::from_vec(asset_pallas).ok_or(PallasFunError::EmptySet("assets"))
     }
 
     fn convert_to_pallas_multiasset(
         input: Vec<(String, Vec<(String, u64)>)>,
-    ) -> Result<Multiasset<PositiveCoin>, String> {
+    ) -> Result<Multiasset<PositiveCoin>, PallasFunError> {
         let mut pallas_multiasset_vec: Vec<(
             PolicyId,
             NonEmptyKeyValuePairs<AssetName, PositiveCoin>,
         )> = Vec::new();
 
         for (policy_str, asset_vec) in input {
-            let policy_id = Self::parse_policy_id(&policy_str)?;
-            let asset_pallas = Self::parse_asset_vec(asset_vec)?;
+            let at = |e: PallasFunError| e.at(format!("multiasset[policy={}]", policy_str));
+            let policy_id = Self::parse_policy_id(&policy_str).map_err(at)?;
+            let asset_pallas = Self::parse_asset_vec(asset_vec).map_err(at)?;
             pallas_multiasset_vec.push((policy_id, asset_pallas));
         }
 
         NonEmptyKeyValuePairs::from_vec(pallas_multiasset_vec)
-            .ok_or_else(|| "Invalid multiasset".to_string())
+            .ok_or(PallasFunError::EmptySet("multiasset"))
     }
 
-    pub fn new(multiasset: Vec<(String, Vec<(String, u64)>)>) -> Result<Self, String> {
+    pub fn new(multiasset: Vec<(String, Vec<(String, u64)>)>) -> Result<Self, PallasFunError> {
         let pallas_multiasset = Self::convert_to_pallas_multiasset(multiasset)?;
         Ok(Self {
             inner: pallas_multiasset,
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let multiasset = NonEmptyKeyValuePairs::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self { inner: multiasset })
     }
 }
@@ -1,11 +1,16 @@
 use hex;
 use pallas::codec::minicbor::{self, Decode, Encode};
+use pallas::crypto::hash::Hasher;
 use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::PoolMetadata;
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_pool_metadata_hash};
 
+// ledger limit on pool metadata URLs, in bytes
+pub const MAX_POOL_METADATA_URL_LENGTH: usize = 64;
+
 #[derive(Serialize, Deserialize, Encode, Decode, Debug, PartialEq, Eq, Clone)]
 pub struct PoolMetadataWrapper {
     #[n(0)]
@@ -13,22 +18,47 @@ pub struct PoolMetadataWrapper {
 }
 
 impl PoolMetadataWrapper {
-    pub fn new(url: String, hash: String) -> Result<Self, String> {
+    pub fn new(url: String, hash: String) -> Result<Self, PallasFunError> {
         let hash = parse_pool_metadata_hash(&hash)?;
 
-        Ok(Self {
+        let pool_metadata = Self {
             inner: PoolMetadata { url, hash },
-        })
+        };
+        pool_metadata.check_url()?;
+
+        Ok(pool_metadata)
+    }
+
+    // metadata for a file published at `url`, hashed as served
+    pub fn from_content(url: String, content: &[u8]) -> Result<Self, PallasFunError> {
+        Self::new(url, Hasher::<256>::hash(content).to_string())
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    // decoded metadata is not checked, so pool registration checks call this again
+    pub fn check_url(&self) -> Result<(), PallasFunError> {
+        if self.inner.url.len() > MAX_POOL_METADATA_URL_LENGTH {
+            return Err(PallasFunError::TooLong {
+                kind: "pool metadata url",
+                max: MAX_POOL_METADATA_URL_LENGTH,
+                actual: self.inner.url.len(),
+            });
+        }
+        Ok(())
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_pool_metadata = PoolMetadata::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_pool_metadata,
@@ -41,3 +71,9 @@ impl IntoInner<PoolMetadata> for PoolMetadataWrapper {
         self.inner.clone()
     }
 }
+
+impl From<PoolMetadata> for PoolMetadataWrapper {
+    fn from(inner: PoolMetadata) -> Self {
+        Self { inner }
+    }
+}
@@ -6,6 +6,7 @@ use pallas::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::IntoInner;
 use crate::wrapper::anchor::AnchorWrapper;
 use crate::wrapper::gov_action::GovActionWrapper;
@@ -23,7 +24,7 @@ impl ProposalProcedureWrapper {
         reward_account_wrapper: RewardAccountWrapper,
         gov_action_wrapper: GovActionWrapper,
         anchor_wrapper: AnchorWrapper,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, PallasFunError> {
         Ok(Self {
             inner: ProposalProcedure {
                 deposit,
@@ -34,14 +35,19 @@ impl ProposalProcedureWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let tx_input = ProposalProcedure::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self { inner: tx_input })
     }
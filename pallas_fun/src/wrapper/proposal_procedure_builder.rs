@@ -0,0 +1,107 @@
+use pallas::codec::utils::Nullable;
+use pallas::ledger::primitives::conway::GovAction;
+use pallas::ledger::primitives::{NetworkId, ScriptHash};
+
+use crate::error::PallasFunError;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::IntoInner;
+use crate::wrapper::anchor::AnchorWrapper;
+use crate::wrapper::constitution::ConstitutionWrapper;
+use crate::wrapper::gov_action::GovActionWrapper;
+use crate::wrapper::proposal_procedure::ProposalProcedureWrapper;
+use crate::wrapper::reward_account::RewardAccountWrapper;
+
+// Checked alternative to `ProposalProcedureWrapper::new`. The deposit is the
+// current `governance_action_deposit`; the return account, guardrail script
+// hash and anchor are checked in `build`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProposalProcedureBuilder {
+    deposit: u64,
+    network_id: Option<NetworkId>,
+    reward_account: Option<RewardAccountWrapper>,
+    gov_action: Option<GovActionWrapper>,
+    anchor: Option<AnchorWrapper>,
+    guardrail_script_hash: Option<ScriptHash>,
+}
+
+impl ProposalProcedureBuilder {
+    pub fn new(params: &ProtocolParams) -> Self {
+        Self {
+            deposit: params.governance_action_deposit,
+            network_id: None,
+            reward_account: None,
+            gov_action: None,
+            anchor: None,
+            guardrail_script_hash: None,
+        }
+    }
+
+    // network the return account must be on
+    pub fn set_network_id(mut self, network_id: NetworkId) -> Self {
+        self.network_id = Some(network_id);
+        self
+    }
+
+    // where the deposit is returned
+    pub fn set_reward_account(mut self, reward_account: RewardAccountWrapper) -> Self {
+        self.reward_account = Some(reward_account);
+        self
+    }
+
+    pub fn set_gov_action(mut self, gov_action: GovActionWrapper) -> Self {
+        self.gov_action = Some(gov_action);
+        self
+    }
+
+    pub fn set_anchor(mut self, anchor: AnchorWrapper) -> Self {
+        self.anchor = Some(anchor);
+        self
+    }
+
+    // current constitution, parameter changes and treasury withdrawals must
+    // name its guardrail script
+    pub fn set_constitution(mut self, constitution: &ConstitutionWrapper) -> Self {
+        self.guardrail_script_hash = match constitution.into_inner().guardrail_script {
+            Nullable::Some(hash) => Some(hash),
+            Nullable::Null | Nullable::Undefined => None,
+        };
+        self
+    }
+
+    pub fn build(self) -> Result<ProposalProcedureWrapper, PallasFunError> {
+        let reward_account = self
+            .reward_account
+            .ok_or_else(|| PallasFunError::MissingField("reward_account".to_string()))?;
+        reward_account
+            .check(self.network_id)
+            .map_err(|e| e.at("reward_account"))?;
+        let gov_action = self
+            .gov_action
+            .ok_or_else(|| PallasFunError::MissingField("gov_action".to_string()))?;
+        let anchor = self
+            .anchor
+            .ok_or_else(|| PallasFunError::MissingField("anchor".to_string()))?;
+        anchor.check_url().map_err(|e| e.at("anchor"))?;
+
+        let policy = match gov_action.into_inner() {
+            GovAction::ParameterChange(_, _, policy)
+            | GovAction::TreasuryWithdrawals(_, policy) => Some(policy),
+            _ => None,
+        };
+        if let Some(policy) = policy {
+            let policy = match policy {
+                Nullable::Some(hash) => Some(hash),
+                Nullable::Null | Nullable::Undefined => None,
+            };
+            if policy != self.guardrail_script_hash {
+                return Err(PallasFunError::GuardrailScriptMismatch {
+                    expected: self.guardrail_script_hash.map(|hash| hash.to_string()),
+                    actual: policy.map(|hash| hash.to_string()),
+                }
+                .at("gov_action"));
+            }
+        }
+
+        ProposalProcedureWrapper::new(self.deposit, reward_account, gov_action, anchor)
+    }
+}
@@ -10,7 +10,9 @@ use pallas::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::{IntoInner, parse_rational_number};
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_rational_number, rational_pair};
+use crate::wrapper::protocol_param_update_builder::ProtocolParamUpdateBuilder;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct CostModelsWrapper {
@@ -42,6 +44,35 @@ pub struct DRepVotingThresholdsWrapper {
     pub treasury_withdrawal: (u64, u64),
 }
 
+impl From<&PoolVotingThresholds> for PoolVotingThresholdsWrapper {
+    fn from(t: &PoolVotingThresholds) -> Self {
+        Self {
+            motion_no_confidence: rational_pair(&t.motion_no_confidence),
+            committee_normal: rational_pair(&t.committee_normal),
+            committee_no_confidence: rational_pair(&t.committee_no_confidence),
+            hard_fork_initiation: rational_pair(&t.hard_fork_initiation),
+            security_voting_threshold: rational_pair(&t.security_voting_threshold),
+        }
+    }
+}
+
+impl From<&DRepVotingThresholds> for DRepVotingThresholdsWrapper {
+    fn from(t: &DRepVotingThresholds) -> Self {
+        Self {
+            motion_no_confidence: rational_pair(&t.motion_no_confidence),
+            committee_normal: rational_pair(&t.committee_normal),
+            committee_no_confidence: rational_pair(&t.committee_no_confidence),
+            update_constitution: rational_pair(&t.update_constitution),
+            hard_fork_initiation: rational_pair(&t.hard_fork_initiation),
+            pp_network_group: rational_pair(&t.pp_network_group),
+            pp_economic_group: rational_pair(&t.pp_economic_group),
+            pp_technical_group: rational_pair(&t.pp_technical_group),
+            pp_governance_group: rational_pair(&t.pp_governance_group),
+            treasury_withdrawal: rational_pair(&t.treasury_withdrawal),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Encode, Decode, Debug, PartialEq, Eq, Clone)]
 pub struct ProtocolParamUpdateWrapper {
     #[n(0)]
@@ -49,6 +80,7 @@ pub struct ProtocolParamUpdateWrapper {
 }
 
 impl ProtocolParamUpdateWrapper {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         minfee_a: Option<u64>,
         minfee_b: Option<u64>,
@@ -83,17 +115,23 @@ impl ProtocolParamUpdateWrapper {
         drep_deposit: Option<u64>,
         drep_inactivity_period: Option<u64>,
         minfee_refscript_cost_per_byte: Option<(u64, u64)>, // unit interval
-    ) -> Result<Self, String> {
+    ) -> Result<Self, PallasFunError> {
         let pool_pledge_influence = match pool_pledge_influence {
-            Some((num, denom)) => Some(parse_rational_number(num, denom)?),
+            Some((num, denom)) => {
+                Some(parse_rational_number(num, denom).map_err(|e| e.at("pool_pledge_influence"))?)
+            }
             None => None,
         };
         let expansion_rate = match expansion_rate {
-            Some((num, denom)) => Some(parse_rational_number(num, denom)?),
+            Some((num, denom)) => {
+                Some(parse_rational_number(num, denom).map_err(|e| e.at("expansion_rate"))?)
+            }
             None => None,
         };
         let treasury_growth_rate = match treasury_growth_rate {
-            Some((num, denom)) => Some(parse_rational_number(num, denom)?),
+            Some((num, denom)) => {
+                Some(parse_rational_number(num, denom).map_err(|e| e.at("treasury_growth_rate"))?)
+            }
             None => None,
         };
 
@@ -106,8 +144,10 @@ impl ProtocolParamUpdateWrapper {
 
         let execution_costs = match (execution_costs_mem_price, execution_costs_step_price) {
             (Some((mem_num, mem_denom)), Some((step_num, step_denom))) => Some(ExUnitPrices {
-                mem_price: parse_rational_number(mem_num, mem_denom)?,
-                step_price: parse_rational_number(step_num, step_denom)?,
+                mem_price: parse_rational_number(mem_num, mem_denom)
+                    .map_err(|e| e.at("execution_costs.mem_price"))?,
+                step_price: parse_rational_number(step_num, step_denom)
+                    .map_err(|e| e.at("execution_costs.step_price"))?,
             }),
             _ => None,
         };
@@ -133,23 +173,28 @@ impl ProtocolParamUpdateWrapper {
                 motion_no_confidence: parse_rational_number(
                     pool_voting_thresholds.motion_no_confidence.0,
                     pool_voting_thresholds.motion_no_confidence.1,
-                )?,
+                )
+                .map_err(|e| e.at("pool_voting_thresholds.motion_no_confidence"))?,
                 committee_normal: parse_rational_number(
                     pool_voting_thresholds.committee_normal.0,
                     pool_voting_thresholds.committee_normal.1,
-                )?,
+                )
+                .map_err(|e| e.at("pool_voting_thresholds.committee_normal"))?,
                 committee_no_confidence: parse_rational_number(
                     pool_voting_thresholds.committee_no_confidence.0,
                     pool_voting_thresholds.committee_no_confidence.1,
-                )?,
+                )
+                .map_err(|e| e.at("pool_voting_thresholds.committee_no_confidence"))?,
                 hard_fork_initiation: parse_rational_number(
                     pool_voting_thresholds.hard_fork_initiation.0,
                     pool_voting_thresholds.hard_fork_initiation.1,
-                )?,
+                )
+                .map_err(|e| e.at("pool_voting_thresholds.hard_fork_initiation"))?,
                 security_voting_threshold: parse_rational_number(
                     pool_voting_thresholds.security_voting_threshold.0,
                     pool_voting_thresholds.security_voting_threshold.1,
-                )?,
+                )
+                .map_err(|e| e.at("pool_voting_thresholds.security_voting_threshold"))?,
             }),
             None => None,
         };
@@ -159,49 +204,62 @@ impl ProtocolParamUpdateWrapper {
                 motion_no_confidence: parse_rational_number(
                     drep_voting_thresholds.motion_no_confidence.0,
                     drep_voting_thresholds.motion_no_confidence.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.motion_no_confidence"))?,
                 committee_normal: parse_rational_number(
                     drep_voting_thresholds.committee_normal.0,
                     drep_voting_thresholds.committee_normal.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.committee_normal"))?,
                 committee_no_confidence: parse_rational_number(
                     drep_voting_thresholds.committee_no_confidence.0,
                     drep_voting_thresholds.committee_no_confidence.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.committee_no_confidence"))?,
                 update_constitution: parse_rational_number(
                     drep_voting_thresholds.update_constitution.0,
                     drep_voting_thresholds.update_constitution.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.update_constitution"))?,
                 hard_fork_initiation: parse_rational_number(
                     drep_voting_thresholds.hard_fork_initiation.0,
                     drep_voting_thresholds.hard_fork_initiation.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.hard_fork_initiation"))?,
                 pp_network_group: parse_rational_number(
                     drep_voting_thresholds.pp_network_group.0,
                     drep_voting_thresholds.pp_network_group.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.pp_network_group"))?,
                 pp_economic_group: parse_rational_number(
                     drep_voting_thresholds.pp_economic_group.0,
                     drep_voting_thresholds.pp_economic_group.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.pp_economic_group"))?,
                 pp_technical_group: parse_rational_number(
                     drep_voting_thresholds.pp_technical_group.0,
                     drep_voting_thresholds.pp_technical_group.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.pp_technical_group"))?,
                 pp_governance_group: parse_rational_number(
                     drep_voting_thresholds.pp_governance_group.0,
                     drep_voting_thresholds.pp_governance_group.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.pp_governance_group"))?,
                 treasury_withdrawal: parse_rational_number(
                     drep_voting_thresholds.treasury_withdrawal.0,
                     drep_voting_thresholds.treasury_withdrawal.1,
-                )?,
+                )
+                .map_err(|e| e.at("drep_voting_thresholds.treasury_withdrawal"))?,
             }),
             None => None,
         };
 
         let minfee_refscript_cost_per_byte = match minfee_refscript_cost_per_byte {
-            Some((num, denom)) => Some(parse_rational_number(num, denom)?),
+            Some((num, denom)) => Some(
+                parse_rational_number(num, denom)
+                    .map_err(|e| e.at("minfee_refscript_cost_per_byte"))?,
+            ),
             None => None,
         };
 
@@ -241,14 +299,138 @@ impl ProtocolParamUpdateWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    // Update holding only the parameters that differ between two full parameter
+    // sets, e.g. both loaded with `ProtocolParamUpdateBuilder::from_cardano_cli_json`.
+    // Cost models are compared per language since an update merges them.
+    pub fn diff(current: &Self, proposed: &Self) -> Result<Self, PallasFunError> {
+        let current = &current.inner;
+        let proposed = &proposed.inner;
+
+        let cost_models_for_script_languages = match (
+            &current.cost_models_for_script_languages,
+            &proposed.cost_models_for_script_languages,
+        ) {
+            (Some(current), Some(proposed)) => {
+                let cost_models = CostModels {
+                    plutus_v1: changed(&current.plutus_v1, &proposed.plutus_v1),
+                    plutus_v2: changed(&current.plutus_v2, &proposed.plutus_v2),
+                    plutus_v3: changed(&current.plutus_v3, &proposed.plutus_v3),
+                };
+                if cost_models.plutus_v1.is_none()
+                    && cost_models.plutus_v2.is_none()
+                    && cost_models.plutus_v3.is_none()
+                {
+                    None
+                } else {
+                    Some(cost_models)
+                }
+            }
+            (current, proposed) => changed(current, proposed),
+        };
+
+        let inner = ProtocolParamUpdate {
+            minfee_a: changed(&current.minfee_a, &proposed.minfee_a),
+            minfee_b: changed(&current.minfee_b, &proposed.minfee_b),
+            max_block_body_size: changed(
+                &current.max_block_body_size,
+                &proposed.max_block_body_size,
+            ),
+            max_transaction_size: changed(
+                &current.max_transaction_size,
+                &proposed.max_transaction_size,
+            ),
+            max_block_header_size: changed(
+                &current.max_block_header_size,
+                &proposed.max_block_header_size,
+            ),
+            key_deposit: changed(&current.key_deposit, &proposed.key_deposit),
+            pool_deposit: changed(&current.pool_deposit, &proposed.pool_deposit),
+            maximum_epoch: changed(&current.maximum_epoch, &proposed.maximum_epoch),
+            desired_number_of_stake_pools: changed(
+                &current.desired_number_of_stake_pools,
+                &proposed.desired_number_of_stake_pools,
+            ),
+            pool_pledge_influence: changed(
+                &current.pool_pledge_influence,
+                &proposed.pool_pledge_influence,
+            ),
+            expansion_rate: changed(&current.expansion_rate, &proposed.expansion_rate),
+            treasury_growth_rate: changed(
+                &current.treasury_growth_rate,
+                &proposed.treasury_growth_rate,
+            ),
+            min_pool_cost: changed(&current.min_pool_cost, &proposed.min_pool_cost),
+            ada_per_utxo_byte: changed(&current.ada_per_utxo_byte, &proposed.ada_per_utxo_byte),
+            cost_models_for_script_languages,
+            execution_costs: changed(&current.execution_costs, &proposed.execution_costs),
+            max_tx_ex_units: changed(&current.max_tx_ex_units, &proposed.max_tx_ex_units),
+            max_block_ex_units: changed(&current.max_block_ex_units, &proposed.max_block_ex_units),
+            max_value_size: changed(&current.max_value_size, &proposed.max_value_size),
+            collateral_percentage: changed(
+                &current.collateral_percentage,
+                &proposed.collateral_percentage,
+            ),
+            max_collateral_inputs: changed(
+                &current.max_collateral_inputs,
+                &proposed.max_collateral_inputs,
+            ),
+            pool_voting_thresholds: changed(
+                &current.pool_voting_thresholds,
+                &proposed.pool_voting_thresholds,
+            ),
+            drep_voting_thresholds: changed(
+                &current.drep_voting_thresholds,
+                &proposed.drep_voting_thresholds,
+            ),
+            min_committee_size: changed(&current.min_committee_size, &proposed.min_committee_size),
+            committee_term_limit: changed(
+                &current.committee_term_limit,
+                &proposed.committee_term_limit,
+            ),
+            governance_action_validity_period: changed(
+                &current.governance_action_validity_period,
+                &proposed.governance_action_validity_period,
+            ),
+            governance_action_deposit: changed(
+                &current.governance_action_deposit,
+                &proposed.governance_action_deposit,
+            ),
+            drep_deposit: changed(&current.drep_deposit, &proposed.drep_deposit),
+            drep_inactivity_period: changed(
+                &current.drep_inactivity_period,
+                &proposed.drep_inactivity_period,
+            ),
+            minfee_refscript_cost_per_byte: changed(
+                &current.minfee_refscript_cost_per_byte,
+                &proposed.minfee_refscript_cost_per_byte,
+            ),
+        };
+
+        let update = Self { inner };
+        if update.is_empty() {
+            return Err(PallasFunError::EmptySet("protocol parameter update"));
+        }
+        Ok(update)
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    // true when no parameter is set
+    pub fn is_empty(&self) -> bool {
+        ProtocolParamUpdateBuilder::from_update(self) == ProtocolParamUpdateBuilder::default()
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_protocol_param_update = ProtocolParamUpdate::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_protocol_param_update,
@@ -256,6 +438,20 @@ impl ProtocolParamUpdateWrapper {
     }
 }
 
+// the proposed value when it is set and differs from the current one
+fn changed<T: PartialEq + Clone>(current: &Option<T>, proposed: &Option<T>) -> Option<T> {
+    match proposed {
+        Some(_) if proposed != current => proposed.clone(),
+        _ => None,
+    }
+}
+
+impl From<ProtocolParamUpdate> for ProtocolParamUpdateWrapper {
+    fn from(inner: ProtocolParamUpdate) -> Self {
+        Self { inner }
+    }
+}
+
 impl IntoInner<ProtocolParamUpdate> for ProtocolParamUpdateWrapper {
     fn into_inner(&self) -> ProtocolParamUpdate {
         self.inner.clone()
@@ -0,0 +1,359 @@
+use pallas::ledger::primitives::conway::ProtocolParamUpdate;
+
+use crate::error::PallasFunError;
+use crate::protocol_params::ProtocolParams;
+use crate::utils::{IntoInner, rational_pair};
+use crate::wrapper::protocol_param_update::{
+    CostModelsWrapper, DRepVotingThresholdsWrapper, PoolVotingThresholdsWrapper,
+    ProtocolParamUpdateWrapper,
+};
+
+// Named setters for `ProtocolParamUpdateWrapper::new`. Execution units and
+// prices are set as pairs so memory and steps of different limits cannot be
+// mixed up.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ProtocolParamUpdateBuilder {
+    minfee_a: Option<u64>,
+    minfee_b: Option<u64>,
+    max_block_body_size: Option<u64>,
+    max_transaction_size: Option<u64>,
+    max_block_header_size: Option<u64>,
+    key_deposit: Option<u64>,
+    pool_deposit: Option<u64>,
+    maximum_epoch: Option<u64>,
+    desired_number_of_stake_pools: Option<u64>,
+    pool_pledge_influence: Option<(u64, u64)>,
+    expansion_rate: Option<(u64, u64)>,
+    treasury_growth_rate: Option<(u64, u64)>,
+    min_pool_cost: Option<u64>,
+    ada_per_utxo_byte: Option<u64>,
+    cost_models_for_script_languages: Option<CostModelsWrapper>,
+    execution_costs: Option<((u64, u64), (u64, u64))>, // (mem price, step price)
+    max_tx_ex_units: Option<(u64, u64)>,               // (mem, steps)
+    max_block_ex_units: Option<(u64, u64)>,            // (mem, steps)
+    max_value_size: Option<u64>,
+    collateral_percentage: Option<u64>,
+    max_collateral_inputs: Option<u64>,
+    pool_voting_thresholds: Option<PoolVotingThresholdsWrapper>,
+    drep_voting_thresholds: Option<DRepVotingThresholdsWrapper>,
+    min_committee_size: Option<u64>,
+    committee_term_limit: Option<u64>,
+    governance_action_validity_period: Option<u64>,
+    governance_action_deposit: Option<u64>,
+    drep_deposit: Option<u64>,
+    drep_inactivity_period: Option<u64>,
+    minfee_refscript_cost_per_byte: Option<(u64, u64)>,
+}
+
+impl ProtocolParamUpdateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // starts from an existing update, e.g. to amend a drafted proposal
+    pub fn from_update(update: &ProtocolParamUpdateWrapper) -> Self {
+        let update: ProtocolParamUpdate = update.into_inner();
+        Self {
+            minfee_a: update.minfee_a,
+            minfee_b: update.minfee_b,
+            max_block_body_size: update.max_block_body_size,
+            max_transaction_size: update.max_transaction_size,
+            max_block_header_size: update.max_block_header_size,
+            key_deposit: update.key_deposit,
+            pool_deposit: update.pool_deposit,
+            maximum_epoch: update.maximum_epoch,
+            desired_number_of_stake_pools: update.desired_number_of_stake_pools,
+            pool_pledge_influence: update.pool_pledge_influence.map(|r| rational_pair(&r)),
+            expansion_rate: update.expansion_rate.map(|r| rational_pair(&r)),
+            treasury_growth_rate: update.treasury_growth_rate.map(|r| rational_pair(&r)),
+            min_pool_cost: update.min_pool_cost,
+            ada_per_utxo_byte: update.ada_per_utxo_byte,
+            cost_models_for_script_languages: update.cost_models_for_script_languages.map(|cm| {
+                CostModelsWrapper {
+                    plutus_v1: cm.plutus_v1,
+                    plutus_v2: cm.plutus_v2,
+                    plutus_v3: cm.plutus_v3,
+                }
+            }),
+            execution_costs: update.execution_costs.map(|prices| {
+                (
+                    rational_pair(&prices.mem_price),
+                    rational_pair(&prices.step_price),
+                )
+            }),
+            max_tx_ex_units: update.max_tx_ex_units.map(|units| (units.mem, units.steps)),
+            max_block_ex_units: update
+                .max_block_ex_units
+                .map(|units| (units.mem, units.steps)),
+            max_value_size: update.max_value_size,
+            collateral_percentage: update.collateral_percentage,
+            max_collateral_inputs: update.max_collateral_inputs,
+            pool_voting_thresholds: update
+                .pool_voting_thresholds
+                .map(|t| PoolVotingThresholdsWrapper::from(&t)),
+            drep_voting_thresholds: update
+                .drep_voting_thresholds
+                .map(|t| DRepVotingThresholdsWrapper::from(&t)),
+            min_committee_size: update.min_committee_size,
+            committee_term_limit: update.committee_term_limit,
+            governance_action_validity_period: update.governance_action_validity_period,
+            governance_action_deposit: update.governance_action_deposit,
+            drep_deposit: update.drep_deposit,
+            drep_inactivity_period: update.drep_inactivity_period,
+            minfee_refscript_cost_per_byte: update
+                .minfee_refscript_cost_per_byte
+                .map(|r| rational_pair(&r)),
+        }
+    }
+
+    // every parameter of a full set, e.g. to start from the current one
+    pub fn from_params(params: &ProtocolParams) -> Self {
+        Self {
+            minfee_a: Some(params.minfee_a),
+            minfee_b: Some(params.minfee_b),
+            max_block_body_size: Some(params.max_block_body_size),
+            max_transaction_size: Some(params.max_transaction_size),
+            max_block_header_size: Some(params.max_block_header_size),
+            key_deposit: Some(params.key_deposit),
+            pool_deposit: Some(params.pool_deposit),
+            maximum_epoch: Some(params.maximum_epoch),
+            desired_number_of_stake_pools: Some(params.desired_number_of_stake_pools),
+            pool_pledge_influence: Some(rational_pair(&params.pool_pledge_influence)),
+            expansion_rate: Some(rational_pair(&params.expansion_rate)),
+            treasury_growth_rate: Some(rational_pair(&params.treasury_growth_rate)),
+            min_pool_cost: Some(params.min_pool_cost),
+            ada_per_utxo_byte: Some(params.ada_per_utxo_byte),
+            cost_models_for_script_languages: Some(CostModelsWrapper {
+                plutus_v1: params.cost_models_for_script_languages.plutus_v1.clone(),
+                plutus_v2: params.cost_models_for_script_languages.plutus_v2.clone(),
+                plutus_v3: params.cost_models_for_script_languages.plutus_v3.clone(),
+            }),
+            execution_costs: Some((
+                rational_pair(&params.execution_costs.mem_price),
+                rational_pair(&params.execution_costs.step_price),
+            )),
+            max_tx_ex_units: Some((params.max_tx_ex_units.mem, params.max_tx_ex_units.steps)),
+            max_block_ex_units: Some((
+                params.max_block_ex_units.mem,
+                params.max_block_ex_units.steps,
+            )),
+            max_value_size: Some(params.max_value_size),
+            collateral_percentage: Some(params.collateral_percentage),
+            max_collateral_inputs: Some(params.max_collateral_inputs),
+            pool_voting_thresholds: Some(PoolVotingThresholdsWrapper::from(
+                &params.pool_voting_thresholds,
+            )),
+            drep_voting_thresholds: Some(DRepVotingThresholdsWrapper::from(
+                &params.drep_voting_thresholds,
+            )),
+            min_committee_size: Some(params.min_committee_size),
+            committee_term_limit: Some(params.committee_term_limit),
+            governance_action_validity_period: Some(params.governance_action_validity_period),
+            governance_action_deposit: Some(params.governance_action_deposit),
+            drep_deposit: Some(params.drep_deposit),
+            drep_inactivity_period: Some(params.drep_inactivity_period),
+            minfee_refscript_cost_per_byte: Some(rational_pair(
+                &params.minfee_refscript_cost_per_byte,
+            )),
+        }
+    }
+
+    // every parameter of a `cardano-cli query protocol-parameters` output
+    pub fn from_cardano_cli_json(json: &str) -> Result<Self, PallasFunError> {
+        ProtocolParams::from_cardano_cli_json(json).map(|params| Self::from_params(&params))
+    }
+
+    pub fn set_minfee_a(mut self, minfee_a: u64) -> Self {
+        self.minfee_a = Some(minfee_a);
+        self
+    }
+
+    pub fn set_minfee_b(mut self, minfee_b: u64) -> Self {
+        self.minfee_b = Some(minfee_b);
+        self
+    }
+
+    pub fn set_max_block_body_size(mut self, max_block_body_size: u64) -> Self {
+        self.max_block_body_size = Some(max_block_body_size);
+        self
+    }
+
+    pub fn set_max_transaction_size(mut self, max_transaction_size: u64) -> Self {
+        self.max_transaction_size = Some(max_transaction_size);
+        self
+    }
+
+    pub fn set_max_block_header_size(mut self, max_block_header_size: u64) -> Self {
+        self.max_block_header_size = Some(max_block_header_size);
+        self
+    }
+
+    pub fn set_key_deposit(mut self, key_deposit: u64) -> Self {
+        self.key_deposit = Some(key_deposit);
+        self
+    }
+
+    pub fn set_pool_deposit(mut self, pool_deposit: u64) -> Self {
+        self.pool_deposit = Some(pool_deposit);
+        self
+    }
+
+    pub fn set_maximum_epoch(mut self, maximum_epoch: u64) -> Self {
+        self.maximum_epoch = Some(maximum_epoch);
+        self
+    }
+
+    pub fn set_desired_number_of_stake_pools(mut self, desired_number_of_stake_pools: u64) -> Self {
+        self.desired_number_of_stake_pools = Some(desired_number_of_stake_pools);
+        self
+    }
+
+    pub fn set_pool_pledge_influence(mut self, numerator: u64, denominator: u64) -> Self {
+        self.pool_pledge_influence = Some((numerator, denominator));
+        self
+    }
+
+    pub fn set_expansion_rate(mut self, numerator: u64, denominator: u64) -> Self {
+        self.expansion_rate = Some((numerator, denominator));
+        self
+    }
+
+    pub fn set_treasury_growth_rate(mut self, numerator: u64, denominator: u64) -> Self {
+        self.treasury_growth_rate = Some((numerator, denominator));
+        self
+    }
+
+    pub fn set_min_pool_cost(mut self, min_pool_cost: u64) -> Self {
+        self.min_pool_cost = Some(min_pool_cost);
+        self
+    }
+
+    pub fn set_ada_per_utxo_byte(mut self, ada_per_utxo_byte: u64) -> Self {
+        self.ada_per_utxo_byte = Some(ada_per_utxo_byte);
+        self
+    }
+
+    pub fn set_cost_models(mut self, cost_models: CostModelsWrapper) -> Self {
+        self.cost_models_for_script_languages = Some(cost_models);
+        self
+    }
+
+    // prices as (numerator, denominator)
+    pub fn set_execution_costs(mut self, mem_price: (u64, u64), step_price: (u64, u64)) -> Self {
+        self.execution_costs = Some((mem_price, step_price));
+        self
+    }
+
+    pub fn set_max_tx_ex_units(mut self, mem: u64, steps: u64) -> Self {
+        self.max_tx_ex_units = Some((mem, steps));
+        self
+    }
+
+    pub fn set_max_block_ex_units(mut self, mem: u64, steps: u64) -> Self {
+        self.max_block_ex_units = Some((mem, steps));
+        self
+    }
+
+    pub fn set_max_value_size(mut self, max_value_size: u64) -> Self {
+        self.max_value_size = Some(max_value_size);
+        self
+    }
+
+    pub fn set_collateral_percentage(mut self, collateral_percentage: u64) -> Self {
+        self.collateral_percentage = Some(collateral_percentage);
+        self
+    }
+
+    pub fn set_max_collateral_inputs(mut self, max_collateral_inputs: u64) -> Self {
+        self.max_collateral_inputs = Some(max_collateral_inputs);
+        self
+    }
+
+    pub fn set_pool_voting_thresholds(mut self, thresholds: PoolVotingThresholdsWrapper) -> Self {
+        self.pool_voting_thresholds = Some(thresholds);
+        self
+    }
+
+    pub fn set_drep_voting_thresholds(mut self, thresholds: DRepVotingThresholdsWrapper) -> Self {
+        self.drep_voting_thresholds = Some(thresholds);
+        self
+    }
+
+    pub fn set_min_committee_size(mut self, min_committee_size: u64) -> Self {
+        self.min_committee_size = Some(min_committee_size);
+        self
+    }
+
+    pub fn set_committee_term_limit(mut self, committee_term_limit: u64) -> Self {
+        self.committee_term_limit = Some(committee_term_limit);
+        self
+    }
+
+    pub fn set_governance_action_validity_period(mut self, period: u64) -> Self {
+        self.governance_action_validity_period = Some(period);
+        self
+    }
+
+    pub fn set_governance_action_deposit(mut self, governance_action_deposit: u64) -> Self {
+        self.governance_action_deposit = Some(governance_action_deposit);
+        self
+    }
+
+    pub fn set_drep_deposit(mut self, drep_deposit: u64) -> Self {
+        self.drep_deposit = Some(drep_deposit);
+        self
+    }
+
+    pub fn set_drep_inactivity_period(mut self, drep_inactivity_period: u64) -> Self {
+        self.drep_inactivity_period = Some(drep_inactivity_period);
+        self
+    }
+
+    pub fn set_minfee_refscript_cost_per_byte(mut self, numerator: u64, denominator: u64) -> Self {
+        self.minfee_refscript_cost_per_byte = Some((numerator, denominator));
+        self
+    }
+
+    // the ledger rejects a parameter change that changes nothing
+    pub fn build(self) -> Result<ProtocolParamUpdateWrapper, PallasFunError> {
+        if self == Self::default() {
+            return Err(PallasFunError::EmptySet("protocol parameter update"));
+        }
+
+        ProtocolParamUpdateWrapper::new(
+            self.minfee_a,
+            self.minfee_b,
+            self.max_block_body_size,
+            self.max_transaction_size,
+            self.max_block_header_size,
+            self.key_deposit,
+            self.pool_deposit,
+            self.maximum_epoch,
+            self.desired_number_of_stake_pools,
+            self.pool_pledge_influence,
+            self.expansion_rate,
+            self.treasury_growth_rate,
+            self.min_pool_cost,
+            self.ada_per_utxo_byte,
+            self.cost_models_for_script_languages,
+            self.execution_costs.map(|(mem_price, _)| mem_price),
+            self.execution_costs.map(|(_, step_price)| step_price),
+            self.max_tx_ex_units.map(|(mem, _)| mem),
+            self.max_tx_ex_units.map(|(_, steps)| steps),
+            self.max_block_ex_units.map(|(mem, _)| mem),
+            self.max_block_ex_units.map(|(_, steps)| steps),
+            self.max_value_size,
+            self.collateral_percentage,
+            self.max_collateral_inputs,
+            self.pool_voting_thresholds,
+            self.drep_voting_thresholds,
+            self.min_committee_size,
+            self.committee_term_limit,
+            self.governance_action_validity_period,
+            self.governance_action_deposit,
+            self.drep_deposit,
+            self.drep_inactivity_period,
+            self.minfee_refscript_cost_per_byte,
+        )
+    }
+}
@@ -0,0 +1,271 @@
+use hex;
+use pallas::ledger::primitives::conway::{
+    Certificate, ProposalProcedure, Redeemer, RedeemerTag, TransactionBody, Voter,
+};
+use pallas::ledger::primitives::{
+    ExUnits, Fragment, PlutusData, PolicyId, RewardAccount, TransactionInput,
+};
+
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_hash};
+use crate::wrapper::certificate::CertificateWrapper;
+use crate::wrapper::proposal_procedure::ProposalProcedureWrapper;
+use crate::wrapper::reward_account::RewardAccountWrapper;
+use crate::wrapper::transaction_body::TransactionBodyWrapper;
+use crate::wrapper::transaction_input::TransactionInputWrapper;
+use crate::wrapper::voter::VoterWrapper;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RedeemerPurposeKind {
+    Spend {
+        transaction_input_wrapper: TransactionInputWrapper,
+    },
+    Mint {
+        policy_id: String,
+    },
+    Cert {
+        certificate_wrapper: CertificateWrapper,
+    },
+    Reward {
+        reward_account_wrapper: RewardAccountWrapper,
+    },
+    Vote {
+        voter_wrapper: VoterWrapper,
+    },
+    Propose {
+        proposal_procedure_wrapper: ProposalProcedureWrapper,
+    },
+}
+
+// The purpose is kept as the thing being redeemed, the numeric index is only
+// resolved against the final transaction body.
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum RedeemerPurpose {
+    Spend(TransactionInput),
+    Mint(PolicyId),
+    Cert(Certificate),
+    Reward(RewardAccount),
+    Vote(Voter),
+    Propose(ProposalProcedure),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RedeemerWrapper {
+    purpose: RedeemerPurpose,
+    data: PlutusData,
+    ex_units: ExUnits,
+}
+
+impl RedeemerWrapper {
+    pub fn new(
+        purpose: RedeemerPurposeKind,
+        plutus_data_hex: String,
+        mem: u64,
+        steps: u64,
+    ) -> Result<Self, PallasFunError> {
+        let purpose = match purpose {
+            RedeemerPurposeKind::Spend {
+                transaction_input_wrapper,
+            } => RedeemerPurpose::Spend(transaction_input_wrapper.into_inner()),
+            RedeemerPurposeKind::Mint { policy_id } => {
+                RedeemerPurpose::Mint(parse_hash(&policy_id, "policy id")?)
+            }
+            RedeemerPurposeKind::Cert {
+                certificate_wrapper,
+            } => RedeemerPurpose::Cert(certificate_wrapper.into_inner()),
+            RedeemerPurposeKind::Reward {
+                reward_account_wrapper,
+            } => RedeemerPurpose::Reward(reward_account_wrapper.into_inner()),
+            RedeemerPurposeKind::Vote { voter_wrapper } => {
+                RedeemerPurpose::Vote(voter_wrapper.into_inner())
+            }
+            RedeemerPurposeKind::Propose {
+                proposal_procedure_wrapper,
+            } => RedeemerPurpose::Propose(proposal_procedure_wrapper.into_inner()),
+        };
+
+        let bytes =
+            hex::decode(plutus_data_hex).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+        let data = PlutusData::decode_fragment(&bytes)
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
+
+        Ok(Self {
+            purpose,
+            data,
+            ex_units: ExUnits { mem, steps },
+        })
+    }
+
+    // execution units are usually only known after evaluating the finished transaction
+    pub fn set_ex_units(&mut self, mem: u64, steps: u64) {
+        self.ex_units = ExUnits { mem, steps };
+    }
+
+    pub fn tag(&self) -> RedeemerTag {
+        match self.purpose {
+            RedeemerPurpose::Spend(_) => RedeemerTag::Spend,
+            RedeemerPurpose::Mint(_) => RedeemerTag::Mint,
+            RedeemerPurpose::Cert(_) => RedeemerTag::Cert,
+            RedeemerPurpose::Reward(_) => RedeemerTag::Reward,
+            RedeemerPurpose::Vote(_) => RedeemerTag::Vote,
+            RedeemerPurpose::Propose(_) => RedeemerTag::Propose,
+        }
+    }
+
+    // Resolves the index of the purpose following the ledger ordering:
+    // inputs, policy ids, withdrawals and voters are sorted, certificates and
+    // proposals keep their position in the body.
+    pub fn resolve_index(
+        &self,
+        transaction_body: &TransactionBodyWrapper,
+    ) -> Result<u32, PallasFunError> {
+        let body: TransactionBody = transaction_body.into_inner();
+
+        let position = match &self.purpose {
+            RedeemerPurpose::Spend(input) => {
+                let mut inputs: Vec<TransactionInput> = body.inputs.to_vec();
+                inputs.sort();
+                inputs.iter().position(|i| i == input)
+            }
+            RedeemerPurpose::Mint(policy_id) => {
+                let mut policies: Vec<PolicyId> = body
+                    .mint
+                    .map(|mint| mint.iter().map(|(policy, _)| *policy).collect())
+                    .unwrap_or_default();
+                policies.sort();
+                policies.iter().position(|p| p == policy_id)
+            }
+            RedeemerPurpose::Cert(certificate) => body
+                .certificates
+                .and_then(|certs| certs.iter().position(|c| c == certificate)),
+            RedeemerPurpose::Reward(reward_account) => {
+                let mut accounts: Vec<RewardAccount> = body
+                    .withdrawals
+                    .map(|w| w.iter().map(|(account, _)| account.clone()).collect())
+                    .unwrap_or_default();
+                accounts.sort_by_key(reward_account_order);
+                accounts.iter().position(|a| a == reward_account)
+            }
+            RedeemerPurpose::Vote(voter) => {
+                // pallas' derived order (script before key, committee < drep < pool)
+                // matches the ledger's
+                let mut voters: Vec<Voter> = body
+                    .voting_procedures
+                    .map(|vp| vp.iter().map(|(voter, _)| voter.clone()).collect())
+                    .unwrap_or_default();
+                voters.sort();
+                voters.iter().position(|v| v == voter)
+            }
+            RedeemerPurpose::Propose(proposal) => body
+                .proposal_procedures
+                .and_then(|pp| pp.iter().position(|p| p == proposal)),
+        };
+
+        let position = position.ok_or(PallasFunError::RedeemerTargetNotFound(self.tag()))?;
+        u32::try_from(position).map_err(|_| PallasFunError::Overflow("redeemer index"))
+    }
+
+    pub fn resolve(
+        &self,
+        transaction_body: &TransactionBodyWrapper,
+    ) -> Result<Redeemer, PallasFunError> {
+        Ok(Redeemer {
+            tag: self.tag(),
+            index: self.resolve_index(transaction_body)?,
+            data: self.data.clone(),
+            ex_units: self.ex_units,
+        })
+    }
+}
+
+// Ledger order of reward accounts: network, then script credentials before key
+// credentials, then the credential hash. Raw byte order puts keys first.
+fn reward_account_order(reward_account: &RewardAccount) -> (u8, bool, Vec<u8>) {
+    match reward_account.split_first() {
+        Some((header, hash)) => (header & 0x0f, header & 0x10 == 0, hash.to_vec()),
+        None => (0, false, Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wrapper::{TransactionOutputWrapper, ValueWrapper};
+
+    fn body_with_inputs(inputs: Vec<TransactionInputWrapper>) -> TransactionBodyWrapper {
+        let output = TransactionOutputWrapper::new(
+            "6100".to_string(),
+            ValueWrapper::new(1, None),
+            None,
+            None,
+        )
+        .expect("valid output");
+        TransactionBodyWrapper::new(
+            inputs,
+            vec![output],
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("valid body")
+    }
+
+    #[test]
+    fn test_spend_redeemer_index_follows_sorted_inputs() {
+        let script_input = TransactionInputWrapper::new(
+            "bb00000000000000000000000000000000000000000000000000000000000000",
+            0,
+        )
+        .unwrap();
+        let redeemer = RedeemerWrapper::new(
+            RedeemerPurposeKind::Spend {
+                transaction_input_wrapper: script_input.clone(),
+            },
+            "d87980".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+
+        let body = body_with_inputs(vec![script_input.clone()]);
+        assert_eq!(redeemer.resolve_index(&body), Ok(0));
+
+        // adding an input that sorts first shifts the script input
+        let other_input = TransactionInputWrapper::new(
+            "aa00000000000000000000000000000000000000000000000000000000000000",
+            5,
+        )
+        .unwrap();
+        let body = body_with_inputs(vec![script_input, other_input]);
+        assert_eq!(redeemer.resolve_index(&body), Ok(1));
+    }
+
+    #[test]
+    fn test_redeemer_target_missing() {
+        let redeemer = RedeemerWrapper::new(
+            RedeemerPurposeKind::Mint {
+                policy_id: "00000000000000000000000000000000000000000000000000000000".to_string(),
+            },
+            "d87980".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+        assert!(redeemer.resolve(&body_with_inputs(vec![])).is_err());
+    }
+}
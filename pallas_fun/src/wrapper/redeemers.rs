@@ -0,0 +1,138 @@
+use hex;
+use pallas::codec::minicbor::{self, Decode, Encode};
+use pallas::codec::utils::{MaybeIndefArray, NonEmptyKeyValuePairs};
+use pallas::ledger::primitives::Fragment;
+use pallas::ledger::primitives::conway::{Redeemer, Redeemers, RedeemersKey, RedeemersValue};
+
+use crate::error::PallasFunError;
+use crate::utils::IntoInner;
+use crate::wrapper::redeemer::RedeemerWrapper;
+use crate::wrapper::transaction_body::TransactionBodyWrapper;
+
+// The witness set accepts both forms; the script data hash is computed over
+// whichever bytes end up in the transaction, so the choice must be preserved.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RedeemersEncoding {
+    List, // legacy [ redeemer ]
+    Map,  // Conway { [tag, index] => [data, ex_units] }
+}
+
+#[derive(Encode, Decode, Debug, PartialEq, Clone)] // removed `Serialize` and `Deserialize`
+pub struct RedeemersWrapper {
+    #[n(0)]
+    inner: Redeemers,
+}
+
+impl RedeemersWrapper {
+    pub fn new(
+        redeemer_wrappers: Vec<RedeemerWrapper>,
+        transaction_body: &TransactionBodyWrapper,
+        encoding: RedeemersEncoding,
+    ) -> Result<Self, PallasFunError> {
+        let mut redeemers = redeemer_wrappers
+            .iter()
+            .map(|r| r.resolve(transaction_body))
+            .collect::<Result<Vec<Redeemer>, PallasFunError>>()?;
+        redeemers.sort_by_key(|r| (r.tag, r.index));
+
+        if let Some(pair) = redeemers
+            .windows(2)
+            .find(|pair| (pair[0].tag, pair[0].index) == (pair[1].tag, pair[1].index))
+        {
+            return Err(PallasFunError::DuplicateRedeemer {
+                tag: pair[0].tag,
+                index: pair[0].index,
+            });
+        }
+
+        Self::from_redeemers(redeemers, encoding)
+    }
+
+    fn from_redeemers(
+        redeemers: Vec<Redeemer>,
+        encoding: RedeemersEncoding,
+    ) -> Result<Self, PallasFunError> {
+        if redeemers.is_empty() {
+            return Err(PallasFunError::EmptySet("redeemers"));
+        }
+
+        let inner = match encoding {
+            RedeemersEncoding::List => Redeemers::List(MaybeIndefArray::Def(redeemers)),
+            RedeemersEncoding::Map => Redeemers::Map(
+                NonEmptyKeyValuePairs::from_vec(
+                    redeemers
+                        .into_iter()
+                        .map(|r| {
+                            (
+                                RedeemersKey {
+                                    tag: r.tag,
+                                    index: r.index,
+                                },
+                                RedeemersValue {
+                                    data: r.data,
+                                    ex_units: r.ex_units,
+                                },
+                            )
+                        })
+                        .collect(),
+                )
+                .ok_or(PallasFunError::EmptySet("redeemers"))?,
+            ),
+        };
+
+        Ok(Self { inner })
+    }
+
+    pub fn encoding(&self) -> RedeemersEncoding {
+        match self.inner {
+            Redeemers::List(_) => RedeemersEncoding::List,
+            Redeemers::Map(_) => RedeemersEncoding::Map,
+        }
+    }
+
+    // flattened view regardless of the encoding
+    pub fn redeemers(&self) -> Vec<Redeemer> {
+        match &self.inner {
+            Redeemers::List(list) => list.iter().cloned().collect(),
+            Redeemers::Map(map) => map
+                .iter()
+                .map(|(key, value)| Redeemer {
+                    tag: key.tag,
+                    index: key.index,
+                    data: value.data.clone(),
+                    ex_units: value.ex_units,
+                })
+                .collect(),
+        }
+    }
+
+    // re-encodes the same redeemers in the other form
+    pub fn with_encoding(&self, encoding: RedeemersEncoding) -> Result<Self, PallasFunError> {
+        if encoding == self.encoding() {
+            return Ok(self.clone());
+        }
+        Self::from_redeemers(self.redeemers(), encoding)
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+        let redeemers = Redeemers::decode_fragment(&bytes)
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
+        Ok(Self { inner: redeemers })
+    }
+}
+
+impl IntoInner<Redeemers> for RedeemersWrapper {
+    fn into_inner(&self) -> Redeemers {
+        self.inner.clone()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, option_hex_string_to_option_bytes};
 use hex;
 use pallas::codec::minicbor::{self, Decode, Encode};
@@ -5,6 +6,9 @@ use pallas::codec::utils::Nullable;
 use pallas::ledger::primitives::{Fragment, Relay};
 use serde::{Deserialize, Serialize};
 
+// ledger limit on relay DNS names, in bytes
+pub const MAX_DNS_NAME_LENGTH: usize = 64;
+
 pub enum RelayKind {
     SingleHostAddr(Option<u32>, Option<String>, Option<String>),
     SingleHostName(Option<u32>, String),
@@ -18,7 +22,7 @@ pub struct RelayWrapper {
 }
 
 impl RelayWrapper {
-    pub fn new(relay: RelayKind) -> Result<Self, String> {
+    pub fn new(relay: RelayKind) -> Result<Self, PallasFunError> {
         let pallas_relay = match relay {
             RelayKind::SingleHostAddr(port, ipv4, ipv6) => Relay::SingleHostAddr(
                 Nullable::from(port),
@@ -31,19 +35,41 @@ impl RelayWrapper {
             RelayKind::MultiHostName(dns_name) => Relay::MultiHostName(dns_name),
         };
 
-        Ok(Self {
+        let relay = Self {
             inner: pallas_relay,
-        })
+        };
+        relay.check_dns_name()?;
+
+        Ok(relay)
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    // decoded relays are not checked, so pool registration checks call this again
+    pub fn check_dns_name(&self) -> Result<(), PallasFunError> {
+        if let Relay::SingleHostName(_, dns_name) | Relay::MultiHostName(dns_name) = &self.inner
+            && dns_name.len() > MAX_DNS_NAME_LENGTH
+        {
+            return Err(PallasFunError::TooLong {
+                kind: "dns name",
+                max: MAX_DNS_NAME_LENGTH,
+                actual: dns_name.len(),
+            });
+        }
+        Ok(())
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
-        let pallas_relay =
-            Relay::decode_fragment(&bytes).map_err(|e| format!("Fragment decode error: {}", e))?;
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+        let pallas_relay = Relay::decode_fragment(&bytes)
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self {
             inner: pallas_relay,
         })
@@ -59,3 +85,9 @@ impl IntoInner<Relay> for RelayWrapper {
         self.inner.clone()
     }
 }
+
+impl From<Relay> for RelayWrapper {
+    fn from(inner: Relay) -> Self {
+        Self { inner }
+    }
+}
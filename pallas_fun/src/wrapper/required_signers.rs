@@ -5,6 +5,7 @@ use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::RequiredSigners;
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_address_key_hash};
 
 #[derive(Serialize, Deserialize, Encode, Decode, Debug, PartialEq, Eq, PartialOrd, Clone)]
@@ -14,28 +15,33 @@ pub struct RequiredSignersWrapper {
 }
 
 impl RequiredSignersWrapper {
-    pub fn new(required_signers: Vec<&str>) -> Result<Self, String> {
+    pub fn new(required_signers: Vec<&str>) -> Result<Self, PallasFunError> {
         let mut pallas_require_signers_vec = Vec::new();
         for address_key_hash_str in required_signers {
             let keyhash = parse_address_key_hash(address_key_hash_str)?;
             pallas_require_signers_vec.push(keyhash);
         }
         let non_empty_set = NonEmptySet::from_vec(pallas_require_signers_vec)
-            .ok_or_else(|| "invalid require signers".to_string())?;
+            .ok_or(PallasFunError::EmptySet("required signers"))?;
 
         Ok(Self {
             inner: non_empty_set,
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let required_signers = RequiredSigners::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self {
             inner: required_signers,
         })
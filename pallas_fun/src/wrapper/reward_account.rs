@@ -1,10 +1,11 @@
 use hex;
 use pallas::codec::minicbor::{self, Decode, Encode};
 use pallas::codec::utils::Bytes;
-use pallas::ledger::primitives::{Fragment, RewardAccount};
+use pallas::ledger::primitives::{Fragment, NetworkId, RewardAccount};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use crate::error::PallasFunError;
 use crate::utils::IntoInner;
 
 #[derive(
@@ -22,24 +23,62 @@ use crate::utils::IntoInner;
 )]
 pub struct RewardAccountWrapper {
     #[n(0)]
-     inner: RewardAccount,
+    inner: RewardAccount,
 }
 
 impl RewardAccountWrapper {
-    pub fn new(reward_account: &str) -> Result<Self, String> {
+    pub fn new(reward_account: &str) -> Result<Self, PallasFunError> {
         let bytes = Bytes::from_str(reward_account)
-            .map_err(|_| "Invalid reward account length".to_string())?;
+            .map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         Ok(Self { inner: bytes })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    // 29 bytes with a stake key (0xe_) or stake script (0xf_) header, on
+    // `network_id` when given
+    pub fn check(&self, network_id: Option<NetworkId>) -> Result<(), PallasFunError> {
+        if self.inner.len() != 29 {
+            return Err(PallasFunError::InvalidLength {
+                kind: "reward account",
+                expected: 29,
+                actual: self.inner.len(),
+            });
+        }
+        let header = self.inner[0];
+        if header >> 4 != 0b1110 && header >> 4 != 0b1111 {
+            return Err(PallasFunError::InvalidAddress(format!(
+                "header {:02x} is not a reward account",
+                header
+            )));
+        }
+        if let Some(network_id) = network_id {
+            let expected = match network_id {
+                NetworkId::Testnet => 0,
+                NetworkId::Mainnet => 1,
+            };
+            if header & 0x0f != expected {
+                return Err(PallasFunError::InvalidAddress(format!(
+                    "reward account on network {}, expected {}",
+                    header & 0x0f,
+                    expected
+                )));
+            }
+        }
+        Ok(())
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let reward_account = RewardAccount::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self {
             inner: reward_account,
         })
@@ -51,3 +90,9 @@ impl IntoInner<RewardAccount> for RewardAccountWrapper {
         self.inner.clone()
     }
 }
+
+impl From<RewardAccount> for RewardAccountWrapper {
+    fn from(inner: RewardAccount) -> Self {
+        Self { inner }
+    }
+}
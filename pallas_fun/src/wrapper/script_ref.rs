@@ -6,6 +6,7 @@ use pallas::codec::utils::Bytes;
 use pallas::ledger::primitives::conway::{NativeScript, ScriptRef};
 use pallas::ledger::primitives::{Fragment, PlutusScript};
 
+use crate::error::PallasFunError;
 use crate::utils::IntoInner;
 
 #[derive(Debug, Clone)]
@@ -23,31 +24,31 @@ pub struct ScriptRefWrapper {
 }
 
 impl ScriptRefWrapper {
-    pub fn new(script_ref_kind: ScriptRefKind) -> Result<Self, String> {
+    pub fn new(script_ref_kind: ScriptRefKind) -> Result<Self, PallasFunError> {
         let pallas_script_ref = match script_ref_kind {
             ScriptRefKind::NativeScript { native_script_hex } => {
                 let bytes = hex::decode(native_script_hex)
-                    .map_err(|e| format!("Hex decode error: {}", e))?;
+                    .map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
                 let pallas_native_script = NativeScript::decode_fragment(&bytes)
-                    .map_err(|e| format!("Fragment decode error: {}", e))?;
+                    .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
                 ScriptRef::NativeScript(pallas_native_script)
             }
 
             ScriptRefKind::PlutusV1Script { plutus_v1_script } => {
                 let bytes = Bytes::from_str(&plutus_v1_script)
-                    .map_err(|e| format!("Invalid Plutus V1 script bytes: {}", e))?;
+                    .map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
                 ScriptRef::PlutusV1Script(PlutusScript::<1>(bytes))
             }
 
             ScriptRefKind::PlutusV2Script { plutus_v2_script } => {
                 let bytes = Bytes::from_str(&plutus_v2_script)
-                    .map_err(|e| format!("Invalid Plutus V2 script bytes: {}", e))?;
+                    .map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
                 ScriptRef::PlutusV2Script(PlutusScript::<2>(bytes))
             }
 
             ScriptRefKind::PlutusV3Script { plutus_v3_script } => {
                 let bytes = Bytes::from_str(&plutus_v3_script)
-                    .map_err(|e| format!("Invalid Plutus V3 script bytes: {}", e))?;
+                    .map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
                 ScriptRef::PlutusV3Script(PlutusScript::<3>(bytes))
             }
         };
@@ -57,14 +58,19 @@ impl ScriptRefWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_script_ref = ScriptRef::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_script_ref,
@@ -3,6 +3,10 @@ use pallas::codec::minicbor::{self, Decode, Encode};
 use pallas::ledger::primitives::{Fragment, StakeCredential};
 use serde::{Deserialize, Serialize};
 
+use crate::cip129::{
+    GovCredentialRole, decode_credential, encode_credential, encode_legacy_credential,
+};
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_address_key_hash, parse_script_hash};
 
 pub enum StakeCredentialKind {
@@ -17,7 +21,7 @@ pub struct StakeCredentialWrapper {
 }
 
 impl StakeCredentialWrapper {
-    pub fn new(stake_credential: StakeCredentialKind) -> Result<Self, String> {
+    pub fn new(stake_credential: StakeCredentialKind) -> Result<Self, PallasFunError> {
         let pallas_stake_credential = match stake_credential {
             StakeCredentialKind::AddrKeyhash(keyhash) => {
                 StakeCredential::AddrKeyhash(parse_address_key_hash(&keyhash)?)
@@ -32,14 +36,34 @@ impl StakeCredentialWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    // CIP-129 id of a committee or DRep credential, e.g. `cc_cold1...`
+    pub fn to_bech32(&self, role: GovCredentialRole) -> String {
+        encode_credential(role, &self.pallas_stake_credential)
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn to_legacy_bech32(&self, role: GovCredentialRole) -> String {
+        encode_legacy_credential(role, &self.pallas_stake_credential)
+    }
+
+    pub fn from_bech32(role: GovCredentialRole, bech32: &str) -> Result<Self, PallasFunError> {
+        Ok(Self {
+            pallas_stake_credential: decode_credential(role, bech32)?,
+        })
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
+    }
+
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let stake_credential = StakeCredential::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self {
             pallas_stake_credential: stake_credential,
         })
@@ -51,3 +75,11 @@ impl IntoInner<StakeCredential> for StakeCredentialWrapper {
         self.pallas_stake_credential.clone()
     }
 }
+
+impl From<StakeCredential> for StakeCredentialWrapper {
+    fn from(pallas_stake_credential: StakeCredential) -> Self {
+        Self {
+            pallas_stake_credential,
+        }
+    }
+}
@@ -6,12 +6,15 @@ use pallas::codec::utils::{
 };
 use pallas::crypto::hash::Hash;
 use pallas::ledger::primitives::conway::{
-    Certificate, GovActionId, Multiasset, ProposalProcedure, RequiredSigners, TransactionBody,
-    TransactionOutput, Voter, VotingProcedure,
+    Certificate, Multiasset, ProposalProcedure, RequiredSigners, TransactionBody,
+    TransactionOutput, VotingProcedures,
 };
 use pallas::ledger::primitives::{Coin, Fragment, NetworkId, RewardAccount, TransactionInput};
 
-use crate::utils::{IntoInner, parse_vec_wrapper_to_set};
+use crate::error::PallasFunError;
+use crate::utils::{
+    IntoInner, parse_hash, parse_vec_wrapper_to_non_empty_set, parse_vec_wrapper_to_set,
+};
 use crate::wrapper::certificate::CertificateWrapper;
 use crate::wrapper::gov_action_id::GovActionIdWrapper;
 use crate::wrapper::multiasset_non_zero_int::MultiassetNonZeroIntWrapper;
@@ -22,6 +25,12 @@ use crate::wrapper::voter::VoterWrapper;
 use crate::wrapper::voting_procedure::VotingProcedureWrapper;
 use crate::wrapper::{TransactionInputWrapper, TransactionOutputWrapper};
 
+// voter -> [(gov action id, voting procedure)], as taken by `TransactionBodyWrapper::new`
+pub type VotingProceduresInput = Vec<(
+    VoterWrapper,
+    Vec<(GovActionIdWrapper, VotingProcedureWrapper)>,
+)>;
+
 #[derive(Encode, Decode, Debug, PartialEq, Clone)] // removed serialize and deserialize traits
 #[cbor(map)]
 pub struct TransactionBodyWrapper {
@@ -30,6 +39,7 @@ pub struct TransactionBodyWrapper {
 }
 
 impl TransactionBodyWrapper {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         inputs: Vec<TransactionInputWrapper>,
         outputs: Vec<TransactionOutputWrapper>,
@@ -47,31 +57,27 @@ impl TransactionBodyWrapper {
         collateral_return: Option<TransactionOutputWrapper>,
         total_collateral: Option<u64>,
         reference_inputs: Option<Vec<TransactionInputWrapper>>, // Option<NonEmptySet<TransactionInput>>,
-        voting_procedures: Option<
-            Vec<(
-                VoterWrapper,
-                Vec<(GovActionIdWrapper, VotingProcedureWrapper)>,
-            )>,
-        >, // NonEmptyKeyValuePairs<Voter, NonEmptyKeyValuePairs<GovActionId, VotingProcedure>>;
+        voting_procedures: Option<VotingProceduresInput>, // NonEmptyKeyValuePairs<Voter, NonEmptyKeyValuePairs<GovActionId, VotingProcedure>>;
         proposal_procedures: Option<Vec<ProposalProcedureWrapper>>, // Option<NonEmptySet<ProposalProcedure>>,
         treasury_value: Option<u64>,                                // coin
         donation: Option<u64>,
-    ) -> Result<Self, String> {
-        // TODO: error handling for parsing functions
+    ) -> Result<Self, PallasFunError> {
         let inputs = Self::parse_inputs(inputs);
         let outputs = Self::parse_transaction_outputs(outputs);
-        let certificates = Self::parse_certificates(certificates);
-        let withdrawals = Self::parse_withdrawals(withdrawals);
-        let auxiliary_data_hash = Self::parse_auxiliary_data_hash(auxiliary_data_hash)?;
+        let certificates = Self::parse_certificates(certificates)?;
+        let withdrawals = Self::parse_withdrawals(withdrawals).map_err(|e| e.at("withdrawals"))?;
+        let auxiliary_data_hash = Self::parse_auxiliary_data_hash(auxiliary_data_hash)
+            .map_err(|e| e.at("auxiliary_data_hash"))?;
         let mint = Self::parse_mint(mint);
-        let script_data_hash = Self::parse_script_data_hash(script_data_hash)?;
-        let collateral = Self::parse_collateral(collateral);
+        let script_data_hash =
+            Self::parse_script_data_hash(script_data_hash).map_err(|e| e.at("script_data_hash"))?;
+        let collateral = Self::parse_collateral(collateral)?;
         let required_signers = Self::parse_required_signers(required_signers);
         let collateral_return = Self::parse_collateral_return(collateral_return);
-        let reference_inputs = Self::parse_reference_inputs(reference_inputs);
-        let voting_procedures = Self::parse_voting_procedures(voting_procedures);
-        let proposal_procedures = Self::parse_proposal_procedures(proposal_procedures);
-        let donation = Self::parse_donation(donation);
+        let reference_inputs = Self::parse_reference_inputs(reference_inputs)?;
+        let voting_procedures = Self::parse_voting_procedures(voting_procedures)?;
+        let proposal_procedures = Self::parse_proposal_procedures(proposal_procedures)?;
+        let donation = Self::parse_donation(donation)?;
 
         Ok(Self {
             inner: TransactionBody {
@@ -99,9 +105,6 @@ impl TransactionBodyWrapper {
         })
     }
 
-    // TODO: Implement error handling for parsing functions
-    // NOTE: multiple parser convert option vec to option non empty set, could reduce to one function with different parameter inputs
-
     pub fn parse_inputs(inputs: Vec<TransactionInputWrapper>) -> Set<TransactionInput> {
         parse_vec_wrapper_to_set(inputs)
     }
@@ -123,101 +126,99 @@ impl TransactionBodyWrapper {
 
     pub fn parse_script_data_hash(
         script_data_hash: Option<String>,
-    ) -> Result<Option<Hash<32>>, String> {
-        match script_data_hash {
-            Some(hash) => Hash::<32>::from_str(&hash)
-                .map(Some)
-                .map_err(|_| "Invalid script data hash length".to_string()),
-            None => Ok(None),
-        }
+    ) -> Result<Option<Hash<32>>, PallasFunError> {
+        script_data_hash
+            .map(|hash| parse_hash(&hash, "script data hash"))
+            .transpose()
     }
 
     pub fn parse_collateral(
         collateral: Option<Vec<TransactionInputWrapper>>,
-    ) -> Option<NonEmptySet<TransactionInput>> {
-        collateral.map(|c| {
-            NonEmptySet::from_vec(c.into_iter().map(|w| w.into_inner()).collect()).unwrap()
-        })
+    ) -> Result<Option<NonEmptySet<TransactionInput>>, PallasFunError> {
+        collateral
+            .map(|c| parse_vec_wrapper_to_non_empty_set(c, "collateral"))
+            .transpose()
     }
 
     pub fn parse_proposal_procedures(
         proposal_procedures: Option<Vec<ProposalProcedureWrapper>>,
-    ) -> Option<NonEmptySet<ProposalProcedure>> {
-        proposal_procedures.map(|pp| {
-            NonEmptySet::from_vec(pp.into_iter().map(|p| p.into_inner()).collect()).unwrap()
-        })
+    ) -> Result<Option<NonEmptySet<ProposalProcedure>>, PallasFunError> {
+        proposal_procedures
+            .map(|pp| parse_vec_wrapper_to_non_empty_set(pp, "proposal procedures"))
+            .transpose()
     }
 
     pub fn parse_reference_inputs(
         reference_inputs: Option<Vec<TransactionInputWrapper>>,
-    ) -> Option<NonEmptySet<TransactionInput>> {
-        reference_inputs.map(|inputs| {
-            NonEmptySet::from_vec(inputs.into_iter().map(|w| w.into_inner()).collect()).unwrap()
-        })
+    ) -> Result<Option<NonEmptySet<TransactionInput>>, PallasFunError> {
+        reference_inputs
+            .map(|inputs| parse_vec_wrapper_to_non_empty_set(inputs, "reference inputs"))
+            .transpose()
     }
 
     pub fn parse_voting_procedures(
-        voting_procedures: Option<
-            Vec<(
-                VoterWrapper,
-                Vec<(GovActionIdWrapper, VotingProcedureWrapper)>,
-            )>,
-        >,
-    ) -> Option<NonEmptyKeyValuePairs<Voter, NonEmptyKeyValuePairs<GovActionId, VotingProcedure>>>
-    {
-        voting_procedures.map(|vp| {
-            NonEmptyKeyValuePairs::from_vec(
-                vp.into_iter()
-                    .map(|(voter_wrapper, procedures)| {
+        voting_procedures: Option<VotingProceduresInput>,
+    ) -> Result<Option<VotingProcedures>, PallasFunError> {
+        let Some(voting_procedures) = voting_procedures else {
+            return Ok(None);
+        };
+
+        let mut voters = Vec::with_capacity(voting_procedures.len());
+        for (i, (voter_wrapper, procedures)) in voting_procedures.into_iter().enumerate() {
+            let procedures = NonEmptyKeyValuePairs::from_vec(
+                procedures
+                    .into_iter()
+                    .map(|(gov_action_id_wrapper, voting_procedure_wrapper)| {
                         (
-                            voter_wrapper.into_inner(),
-                            NonEmptyKeyValuePairs::from_vec(
-                                procedures
-                                    .into_iter()
-                                    .map(|(gov_action_id_wrapper, voting_procedure_wrapper)| {
-                                        (
-                                            gov_action_id_wrapper.into_inner(),
-                                            voting_procedure_wrapper.into_inner(),
-                                        )
-                                    })
-                                    .collect(),
-                            )
-                            .unwrap(),
+                            gov_action_id_wrapper.into_inner(),
+                            voting_procedure_wrapper.into_inner(),
                         )
                     })
                     .collect(),
             )
-            .unwrap()
-        })
+            .ok_or_else(|| {
+                PallasFunError::EmptySet("voting procedures")
+                    .at(format!("voting_procedures[{}]", i))
+            })?;
+            voters.push((voter_wrapper.into_inner(), procedures));
+        }
+
+        NonEmptyKeyValuePairs::from_vec(voters)
+            .map(Some)
+            .ok_or(PallasFunError::EmptySet("voting procedures"))
     }
 
     pub fn parse_withdrawals(
         withdrawals: Option<Vec<(RewardAccountWrapper, u64)>>,
-    ) -> Option<NonEmptyKeyValuePairs<RewardAccount, Coin>> {
-        withdrawals.map(|w| {
-            NonEmptyKeyValuePairs::from_vec(
-                w.into_iter()
-                    .map(|(ra, coin)| (ra.into_inner(), Coin::try_from(coin).unwrap()))
-                    .collect(),
-            )
-            .unwrap()
-        })
+    ) -> Result<Option<NonEmptyKeyValuePairs<RewardAccount, Coin>>, PallasFunError> {
+        withdrawals
+            .map(|w| {
+                NonEmptyKeyValuePairs::from_vec(
+                    w.into_iter()
+                        .map(|(ra, coin)| (ra.into_inner(), coin))
+                        .collect(),
+                )
+                .ok_or(PallasFunError::EmptySet("withdrawals"))
+            })
+            .transpose()
     }
 
     pub fn parse_auxiliary_data_hash(
         auxiliary_data_hash: Option<String>,
-    ) -> Result<Option<Bytes>, String> {
+    ) -> Result<Option<Bytes>, PallasFunError> {
         auxiliary_data_hash
-            .map(|hash| Bytes::from_str(&hash).map_err(|e| e.to_string()))
+            .map(|hash| {
+                Bytes::from_str(&hash).map_err(|e| PallasFunError::HexDecode(e.to_string()))
+            })
             .transpose()
     }
 
     pub fn parse_certificates(
         certificates: Option<Vec<CertificateWrapper>>,
-    ) -> Option<NonEmptySet<Certificate>> {
-        certificates.map(|certs| {
-            NonEmptySet::from_vec(certs.into_iter().map(|c| c.into_inner()).collect()).unwrap()
-        })
+    ) -> Result<Option<NonEmptySet<Certificate>>, PallasFunError> {
+        certificates
+            .map(|certs| parse_vec_wrapper_to_non_empty_set(certs, "certificates"))
+            .transpose()
     }
 
     pub fn parse_mint(mint: Option<MultiassetNonZeroIntWrapper>) -> Option<Multiasset<NonZeroInt>> {
@@ -230,18 +231,26 @@ impl TransactionBodyWrapper {
         required_signers.map(|wrapper| wrapper.into_inner())
     }
 
-    pub fn parse_donation(donation: Option<u64>) -> Option<PositiveCoin> {
-        donation.map(|d| PositiveCoin::try_from(d).ok()).flatten()
+    // donation must be positive, pass `None` for no donation
+    pub fn parse_donation(donation: Option<u64>) -> Result<Option<PositiveCoin>, PallasFunError> {
+        donation
+            .map(|d| PositiveCoin::try_from(d).map_err(|_| PallasFunError::ZeroAmount("donation")))
+            .transpose()
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let transaction_body = TransactionBody::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self {
             inner: transaction_body,
         })
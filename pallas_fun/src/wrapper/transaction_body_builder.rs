@@ -0,0 +1,353 @@
+use std::collections::BTreeSet;
+
+use pallas::ledger::primitives::NetworkId;
+
+use crate::collateral::CollateralSelection;
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, collect_indexed};
+use crate::wrapper::certificate::CertificateWrapper;
+use crate::wrapper::gov_action_id::GovActionIdWrapper;
+use crate::wrapper::multiasset_non_zero_int::MultiassetNonZeroIntWrapper;
+use crate::wrapper::proposal_procedure::ProposalProcedureWrapper;
+use crate::wrapper::required_signers::RequiredSignersWrapper;
+use crate::wrapper::reward_account::RewardAccountWrapper;
+use crate::wrapper::transaction_body::{TransactionBodyWrapper, VotingProceduresInput};
+use crate::wrapper::voter::VoterWrapper;
+use crate::wrapper::voting_procedure::VotingProcedureWrapper;
+use crate::wrapper::{TransactionInputWrapper, TransactionOutputWrapper};
+
+// an output as given to the builder, parsed in `build`
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum OutputInput {
+    Wrapper(TransactionOutputWrapper),
+    Parts {
+        address: String,
+        coin: u64,
+        assets: Vec<(String, Vec<(String, u64)>)>,
+    },
+}
+
+impl OutputInput {
+    fn parse(self) -> Result<TransactionOutputWrapper, PallasFunError> {
+        match self {
+            Self::Wrapper(output) => Ok(output),
+            Self::Parts {
+                address,
+                coin,
+                assets,
+            } => TransactionOutputWrapper::from_parts(address, coin, assets, None, None),
+        }
+    }
+}
+
+// Fluent alternative to `TransactionBodyWrapper::new`. Setters only collect,
+// everything is parsed and checked in `build`.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct TransactionBodyBuilder {
+    inputs: Vec<TransactionInputWrapper>,
+    outputs: Vec<OutputInput>,
+    fee: u64,
+    ttl: Option<u64>,
+    validity_interval_start: Option<u64>,
+    certificates: Vec<CertificateWrapper>,
+    withdrawals: Vec<(RewardAccountWrapper, u64)>,
+    auxiliary_data_hash: Option<String>,
+    mint: Vec<(String, Vec<(String, i64)>)>,
+    script_data_hash: Option<String>,
+    collateral: Vec<TransactionInputWrapper>,
+    required_signers: Vec<String>,
+    network_id: Option<NetworkId>,
+    collateral_return: Option<TransactionOutputWrapper>,
+    total_collateral: Option<u64>,
+    reference_inputs: Vec<TransactionInputWrapper>,
+    voting_procedures: VotingProceduresInput,
+    proposal_procedures: Vec<ProposalProcedureWrapper>,
+    treasury_value: Option<u64>,
+    donation: Option<u64>,
+}
+
+impl TransactionBodyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_input(mut self, input: TransactionInputWrapper) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    pub fn add_output(mut self, output: TransactionOutputWrapper) -> Self {
+        self.outputs.push(OutputInput::Wrapper(output));
+        self
+    }
+
+    // output from hex address and assets, as for a payout built from user
+    // data; errors point at `outputs[i]`
+    pub fn add_output_parts(
+        mut self,
+        address: &str,
+        coin: u64,
+        assets: Vec<(String, Vec<(String, u64)>)>,
+    ) -> Self {
+        self.outputs.push(OutputInput::Parts {
+            address: address.to_string(),
+            coin,
+            assets,
+        });
+        self
+    }
+
+    pub fn set_fee(mut self, fee: u64) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    // (validity_interval_start, ttl) in slots
+    pub fn set_validity(mut self, start: Option<u64>, ttl: Option<u64>) -> Self {
+        self.validity_interval_start = start;
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn add_certificate(mut self, certificate: CertificateWrapper) -> Self {
+        self.certificates.push(certificate);
+        self
+    }
+
+    pub fn add_withdrawal(mut self, reward_account: RewardAccountWrapper, amount: u64) -> Self {
+        self.withdrawals.push((reward_account, amount));
+        self
+    }
+
+    pub fn set_auxiliary_data_hash(mut self, auxiliary_data_hash: &str) -> Self {
+        self.auxiliary_data_hash = Some(auxiliary_data_hash.to_string());
+        self
+    }
+
+    // assets of the same policy are grouped, negative amounts burn. Hex is
+    // lowercased so the case it is written in does not matter.
+    pub fn add_mint(mut self, policy_id: &str, asset_name: &str, amount: i64) -> Self {
+        let policy_id = policy_id.to_ascii_lowercase();
+        let asset = (asset_name.to_ascii_lowercase(), amount);
+        match self
+            .mint
+            .iter_mut()
+            .find(|(policy, _)| *policy == policy_id)
+        {
+            Some((_, assets)) => assets.push(asset),
+            None => self.mint.push((policy_id, vec![asset])),
+        }
+        self
+    }
+
+    pub fn set_script_data_hash(mut self, script_data_hash: &str) -> Self {
+        self.script_data_hash = Some(script_data_hash.to_string());
+        self
+    }
+
+    pub fn add_collateral(mut self, input: TransactionInputWrapper) -> Self {
+        self.collateral.push(input);
+        self
+    }
+
+    pub fn set_collateral_return(mut self, output: TransactionOutputWrapper) -> Self {
+        self.collateral_return = Some(output);
+        self
+    }
+
+    pub fn set_total_collateral(mut self, total_collateral: u64) -> Self {
+        self.total_collateral = Some(total_collateral);
+        self
+    }
+
+    // takes all three collateral fields from `select_collateral` at once
+    pub fn set_collateral(mut self, selection: CollateralSelection) -> Self {
+        self.collateral = selection.collateral;
+        self.collateral_return = selection.collateral_return;
+        self.total_collateral = Some(selection.total_collateral);
+        self
+    }
+
+    pub fn add_required_signer(mut self, address_key_hash: &str) -> Self {
+        self.required_signers.push(address_key_hash.to_string());
+        self
+    }
+
+    pub fn set_network_id(mut self, network_id: NetworkId) -> Self {
+        self.network_id = Some(network_id);
+        self
+    }
+
+    pub fn add_reference_input(mut self, input: TransactionInputWrapper) -> Self {
+        self.reference_inputs.push(input);
+        self
+    }
+
+    // votes of the same voter are grouped
+    pub fn add_vote(
+        mut self,
+        voter: VoterWrapper,
+        gov_action_id: GovActionIdWrapper,
+        voting_procedure: VotingProcedureWrapper,
+    ) -> Self {
+        let vote = (gov_action_id, voting_procedure);
+        match self.voting_procedures.iter_mut().find(|(v, _)| *v == voter) {
+            Some((_, votes)) => votes.push(vote),
+            None => self.voting_procedures.push((voter, vec![vote])),
+        }
+        self
+    }
+
+    pub fn add_proposal(mut self, proposal: ProposalProcedureWrapper) -> Self {
+        self.proposal_procedures.push(proposal);
+        self
+    }
+
+    pub fn set_treasury_value(mut self, treasury_value: u64) -> Self {
+        self.treasury_value = Some(treasury_value);
+        self
+    }
+
+    pub fn set_donation(mut self, donation: u64) -> Self {
+        self.donation = Some(donation);
+        self
+    }
+
+    pub fn build(self) -> Result<TransactionBodyWrapper, PallasFunError> {
+        if self.inputs.is_empty() {
+            return Err(PallasFunError::EmptySet("inputs"));
+        }
+        check_unique_inputs(&self.inputs, "inputs")?;
+        let outputs = collect_indexed("outputs", self.outputs.into_iter().map(OutputInput::parse))?;
+        check_unique_inputs(&self.collateral, "collateral")?;
+        check_unique_inputs(&self.reference_inputs, "reference_inputs")?;
+        // the Conway ledger rejects an input that is also a reference input
+        check_unique_inputs(
+            &[self.inputs.as_slice(), self.reference_inputs.as_slice()].concat(),
+            "reference_inputs",
+        )?;
+        check_unique(
+            self.withdrawals
+                .iter()
+                .map(|(account, _)| account.into_inner()),
+            "withdrawal",
+            |account| hex::encode(account.as_slice()),
+        )?;
+        for (policy_id, assets) in &self.mint {
+            check_unique(
+                assets.iter().map(|(asset_name, _)| asset_name.clone()),
+                "mint asset",
+                |asset_name| format!("{}.{}", policy_id, asset_name),
+            )?;
+        }
+        for (i, (_, votes)) in self.voting_procedures.iter().enumerate() {
+            check_unique(
+                votes
+                    .iter()
+                    .map(|(gov_action_id, _)| gov_action_id.into_inner())
+                    .map(|id| (id.transaction_id, id.action_index)),
+                "vote",
+                |(transaction_id, action_index)| format!("{}#{}", transaction_id, action_index),
+            )
+            .map_err(|e| e.at(format!("voting_procedures[{}]", i)))?;
+        }
+
+        if self.collateral.is_empty() {
+            if self.collateral_return.is_some() {
+                return Err(PallasFunError::InconsistentCollateral(
+                    "collateral return without collateral inputs",
+                ));
+            }
+            if self.total_collateral.is_some() {
+                return Err(PallasFunError::InconsistentCollateral(
+                    "total collateral without collateral inputs",
+                ));
+            }
+        }
+        if self.collateral_return.is_some() && self.total_collateral.is_none() {
+            return Err(PallasFunError::InconsistentCollateral(
+                "collateral return without total collateral",
+            ));
+        }
+        // the collateral percentage is at least 100 (CP-01), so the collateral
+        // always covers the fee
+        if let Some(total_collateral) = self.total_collateral
+            && total_collateral < self.fee
+        {
+            return Err(PallasFunError::InconsistentCollateral(
+                "total collateral below the fee",
+            ));
+        }
+
+        let mint = if self.mint.is_empty() {
+            None
+        } else {
+            Some(MultiassetNonZeroIntWrapper::new(self.mint)?)
+        };
+        let required_signers = if self.required_signers.is_empty() {
+            None
+        } else {
+            Some(
+                RequiredSignersWrapper::new(
+                    self.required_signers.iter().map(String::as_str).collect(),
+                )
+                .map_err(|e| e.at("required_signers"))?,
+            )
+        };
+
+        TransactionBodyWrapper::new(
+            self.inputs,
+            outputs,
+            self.fee,
+            self.ttl,
+            non_empty(self.certificates),
+            non_empty(self.withdrawals),
+            self.auxiliary_data_hash,
+            self.validity_interval_start,
+            mint,
+            self.script_data_hash,
+            non_empty(self.collateral),
+            required_signers,
+            self.network_id,
+            self.collateral_return,
+            self.total_collateral,
+            non_empty(self.reference_inputs),
+            non_empty(self.voting_procedures),
+            non_empty(self.proposal_procedures),
+            self.treasury_value,
+            self.donation,
+        )
+    }
+}
+
+fn non_empty<T>(items: Vec<T>) -> Option<Vec<T>> {
+    if items.is_empty() { None } else { Some(items) }
+}
+
+fn check_unique_inputs(
+    inputs: &[TransactionInputWrapper],
+    field: &str,
+) -> Result<(), PallasFunError> {
+    check_unique(inputs.iter().map(|i| i.into_inner()), "input", |input| {
+        format!("{}#{}", input.transaction_id, input.index)
+    })
+    .map_err(|e| e.at(field))
+}
+
+fn check_unique<T: Ord>(
+    items: impl Iterator<Item = T>,
+    kind: &'static str,
+    describe: impl Fn(&T) -> String,
+) -> Result<(), PallasFunError> {
+    let mut seen = BTreeSet::new();
+    for item in items {
+        if seen.contains(&item) {
+            return Err(PallasFunError::DuplicateEntry {
+                kind,
+                entry: describe(&item),
+            });
+        }
+        seen.insert(item);
+    }
+    Ok(())
+}
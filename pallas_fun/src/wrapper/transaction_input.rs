@@ -4,7 +4,8 @@ pub use pallas::crypto::hash::Hash;
 use pallas::ledger::primitives::{Fragment, TransactionInput};
 use serde::{Deserialize, Serialize};
 
-use crate::utils::IntoInner;
+use crate::error::PallasFunError;
+use crate::utils::{IntoInner, parse_hash};
 
 #[derive(
     Serialize,
@@ -25,27 +26,30 @@ pub struct TransactionInputWrapper {
 }
 
 impl TransactionInputWrapper {
-    pub fn new(transaction_id: &str, index: u64) -> Result<Self, String> {
-        let digest: Hash<32> = transaction_id
-            .parse()
-            .map_err(|_| "Invalid transaction id length".to_string())?;
+    pub fn new(transaction_id: &str, index: u64) -> Result<Self, PallasFunError> {
+        let digest: Hash<32> = parse_hash(transaction_id, "transaction id")?;
 
         Ok(Self {
             inner: TransactionInput {
                 transaction_id: digest,
-                index: index,
+                index,
             },
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let tx_input = TransactionInput::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self { inner: tx_input })
     }
@@ -71,7 +75,7 @@ mod tests {
         let wrapper = TransactionInputWrapper::new(tx_id, index).expect("should create wrapper");
 
         // Encode to hex
-        let encoded = wrapper.encode();
+        let encoded = wrapper.encode().expect("should encode");
 
         // Decode back to object
         let decoded = TransactionInputWrapper::decode(encoded).expect("should decode");
@@ -79,4 +83,20 @@ mod tests {
         // Check equality
         assert_eq!(wrapper.into_inner(), decoded.into_inner());
     }
+
+    #[test]
+    fn test_transaction_input_wrapper_invalid_id() {
+        assert_eq!(
+            TransactionInputWrapper::new("aabb", 0),
+            Err(PallasFunError::InvalidLength {
+                kind: "transaction id",
+                expected: 32,
+                actual: 2,
+            })
+        );
+        assert!(matches!(
+            TransactionInputWrapper::new("zz", 0),
+            Err(PallasFunError::HexDecode(_))
+        ));
+    }
 }
@@ -11,6 +11,7 @@ use pallas::{
     // ledger::primitives::conway::{DatumOption, ScriptRef},
 };
 
+use crate::error::PallasFunError;
 use crate::utils::IntoInner;
 use crate::wrapper::datum_option::DatumOptionWrapper;
 use crate::wrapper::script_ref::ScriptRefWrapper;
@@ -43,9 +44,9 @@ impl TransactionOutputWrapper {
         value: ValueWrapper,
         datum_option: Option<DatumOptionWrapper>,
         script_ref: Option<ScriptRefWrapper>,
-    ) -> Result<Self, String> {
-        let address =
-            Bytes::from_str(&address).map_err(|e| format!("Invalid address bytes: {}", e))?;
+    ) -> Result<Self, PallasFunError> {
+        let address = Bytes::from_str(&address)
+            .map_err(|e| PallasFunError::HexDecode(e.to_string()).at("address"))?;
 
         let pallas_transaction_output =
             PseudoTransactionOutput::PostAlonzo(PseudoPostAlonzoTransactionOutput {
@@ -59,7 +60,20 @@ impl TransactionOutputWrapper {
             inner: pallas_transaction_output,
         })
     }
-    // pub fn new(transaction_output_kind: TransactionOutputKind) -> Result<Self, String> {
+
+    // like `new` with the value given as coin and hex assets, so asset errors
+    // carry the `value.multiasset[policy=…]` path
+    pub fn from_parts(
+        address: String,
+        coin: u64,
+        assets: Vec<(String, Vec<(String, u64)>)>,
+        datum_option: Option<DatumOptionWrapper>,
+        script_ref: Option<ScriptRefWrapper>,
+    ) -> Result<Self, PallasFunError> {
+        let value = ValueWrapper::from_assets(coin, assets).map_err(|e| e.at("value"))?;
+        Self::new(address, value, datum_option, script_ref)
+    }
+    // pub fn new(transaction_output_kind: TransactionOutputKind) -> Result<Self, PallasFunError> {
     //     let pallas_transaction_output = match transaction_output_kind {
     //         TransactionOutputKind::Legacy {
     //             address,
@@ -93,14 +107,32 @@ impl TransactionOutputWrapper {
     //     })
     // }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    // Babbage min-UTxO rule: (160 + serialized size) * ada_per_utxo_byte,
+    // for the output as it is, coin included
+    pub fn min_ada(&self, ada_per_utxo_byte: u64) -> Result<u64, PallasFunError> {
+        let size = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?
+            .len() as u64;
+        (160 + size)
+            .checked_mul(ada_per_utxo_byte)
+            .ok_or(PallasFunError::Overflow("min ada"))
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let tx_input = TransactionOutput::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self { inner: tx_input })
     }
@@ -4,6 +4,7 @@ use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::Value;
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::IntoInner;
 use crate::wrapper::MultiassetPositiveCoinWrapper;
 
@@ -25,14 +26,34 @@ impl ValueWrapper {
         }
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(self.into_inner().encode_fragment().unwrap())
+    // `(policy_id, [(asset_name, amount)])` in hex, no assets gives a coin-only
+    // value
+    pub fn from_assets(
+        coin: u64,
+        assets: Vec<(String, Vec<(String, u64)>)>,
+    ) -> Result<Self, PallasFunError> {
+        if assets.is_empty() {
+            return Ok(Self::new(coin, None));
+        }
+        Ok(Self::new(
+            coin,
+            Some(MultiassetPositiveCoinWrapper::new(assets)?),
+        ))
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
-        let value =
-            Value::decode_fragment(&bytes).map_err(|e| format!("Fragment decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+        let value = Value::decode_fragment(&bytes)
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self { inner: value })
     }
 }
@@ -1,9 +1,14 @@
 use hex;
 use pallas::codec::minicbor::{self, Decode, Encode};
-use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::Voter;
+use pallas::ledger::primitives::{Fragment, PoolKeyhash, StakeCredential};
 use serde::{Deserialize, Serialize};
 
+use crate::cip129::{
+    GovCredentialRole, decode_credential, decode_pool_id, encode_credential,
+    encode_legacy_credential, encode_pool_id,
+};
+use crate::error::PallasFunError;
 use crate::utils::{IntoInner, parse_address_key_hash, parse_script_hash};
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -15,6 +20,12 @@ pub enum VoterKind {
     StakePoolKey { addr_key_hash: String },
 }
 
+// committee and DRep credentials with their role, or the pool key hash
+enum VoterCredential {
+    Gov(GovCredentialRole, StakeCredential),
+    Pool(PoolKeyhash),
+}
+
 #[derive(Serialize, Deserialize, Encode, Decode, Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct VoterWrapper {
     #[n(0)]
@@ -22,7 +33,7 @@ pub struct VoterWrapper {
 }
 
 impl VoterWrapper {
-    pub fn new(voter: VoterKind) -> Result<Self, String> {
+    pub fn new(voter: VoterKind) -> Result<Self, PallasFunError> {
         let inner = match voter {
             VoterKind::ConstitutionalCommitteeKey { script_hash } => {
                 Voter::ConstitutionalCommitteeKey(parse_script_hash(&script_hash)?)
@@ -44,14 +55,81 @@ impl VoterWrapper {
         Ok(Self { inner })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    // CIP-129 `cc_hot1...` or `drep1...`; pools keep their CIP-5 `pool1...`
+    pub fn to_bech32(&self) -> String {
+        match self.credential() {
+            VoterCredential::Gov(role, credential) => encode_credential(role, &credential),
+            VoterCredential::Pool(pool) => encode_pool_id(&pool),
+        }
+    }
+
+    // CIP-105 form, pools are unchanged
+    pub fn to_legacy_bech32(&self) -> String {
+        match self.credential() {
+            VoterCredential::Gov(role, credential) => encode_legacy_credential(role, &credential),
+            VoterCredential::Pool(pool) => encode_pool_id(&pool),
+        }
+    }
+
+    // the voter type is taken from the prefix
+    pub fn from_bech32(bech32: &str) -> Result<Self, PallasFunError> {
+        let hrp = bech32
+            .rsplit_once('1')
+            .map_or(String::new(), |(hrp, _)| hrp.to_ascii_lowercase());
+        let inner = if hrp == "pool" {
+            Voter::StakePoolKey(decode_pool_id(bech32)?)
+        } else if hrp.starts_with("cc_hot") {
+            match decode_credential(GovCredentialRole::CommitteeHot, bech32)? {
+                StakeCredential::AddrKeyhash(hash) => Voter::ConstitutionalCommitteeKey(hash),
+                StakeCredential::ScriptHash(hash) => Voter::ConstitutionalCommitteeScript(hash),
+            }
+        } else if hrp.starts_with("drep") {
+            match decode_credential(GovCredentialRole::DRep, bech32)? {
+                StakeCredential::AddrKeyhash(hash) => Voter::DRepKey(hash),
+                StakeCredential::ScriptHash(hash) => Voter::DRepScript(hash),
+            }
+        } else {
+            return Err(PallasFunError::Bech32(format!(
+                "unexpected prefix {}, expected cc_hot, drep or pool",
+                hrp
+            )));
+        };
+        Ok(Self { inner })
+    }
+
+    fn credential(&self) -> VoterCredential {
+        match &self.inner {
+            Voter::ConstitutionalCommitteeKey(hash) => VoterCredential::Gov(
+                GovCredentialRole::CommitteeHot,
+                StakeCredential::AddrKeyhash(*hash),
+            ),
+            Voter::ConstitutionalCommitteeScript(hash) => VoterCredential::Gov(
+                GovCredentialRole::CommitteeHot,
+                StakeCredential::ScriptHash(*hash),
+            ),
+            Voter::DRepKey(hash) => {
+                VoterCredential::Gov(GovCredentialRole::DRep, StakeCredential::AddrKeyhash(*hash))
+            }
+            Voter::DRepScript(hash) => {
+                VoterCredential::Gov(GovCredentialRole::DRep, StakeCredential::ScriptHash(*hash))
+            }
+            Voter::StakePoolKey(hash) => VoterCredential::Pool(*hash),
+        }
+    }
+
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
-        let voter =
-            Voter::decode_fragment(&bytes).map_err(|e| format!("Fragment decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
+        let voter = Voter::decode_fragment(&bytes)
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
         Ok(Self { inner: voter })
     }
 }
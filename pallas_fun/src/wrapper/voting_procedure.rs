@@ -5,6 +5,7 @@ use pallas::ledger::primitives::Fragment;
 use pallas::ledger::primitives::conway::VotingProcedure;
 use serde::{Deserialize, Serialize};
 
+use crate::error::PallasFunError;
 use crate::utils::IntoInner;
 use crate::wrapper::anchor::AnchorWrapper;
 
@@ -22,7 +23,10 @@ pub struct VotingProcedureWrapper {
 }
 
 impl VotingProcedureWrapper {
-    pub fn new(vote: VoteKind, anchor_wrapper: Option<AnchorWrapper>) -> Result<Self, String> {
+    pub fn new(
+        vote: VoteKind,
+        anchor_wrapper: Option<AnchorWrapper>,
+    ) -> Result<Self, PallasFunError> {
         let vote = match vote {
             VoteKind::Yes => pallas::ledger::primitives::conway::Vote::Yes,
             VoteKind::No => pallas::ledger::primitives::conway::Vote::No,
@@ -38,14 +42,19 @@ impl VotingProcedureWrapper {
         })
     }
 
-    pub fn encode(&self) -> String {
-        hex::encode(&self.into_inner().encode_fragment().unwrap())
+    pub fn encode(&self) -> Result<String, PallasFunError> {
+        let bytes = self
+            .into_inner()
+            .encode_fragment()
+            .map_err(|e| PallasFunError::CborEncode(e.to_string()))?;
+        Ok(hex::encode(bytes))
     }
 
-    pub fn decode(hex_string: String) -> Result<Self, String> {
-        let bytes = hex::decode(hex_string).map_err(|e| format!("Hex decode error: {}", e))?;
+    pub fn decode(hex_string: String) -> Result<Self, PallasFunError> {
+        let bytes =
+            hex::decode(hex_string).map_err(|e| PallasFunError::HexDecode(e.to_string()))?;
         let pallas_voting_procedure = VotingProcedure::decode_fragment(&bytes)
-            .map_err(|e| format!("Fragment decode error: {}", e))?;
+            .map_err(|e| PallasFunError::CborDecode(e.to_string()))?;
 
         Ok(Self {
             inner: pallas_voting_procedure,
@@ -0,0 +1,193 @@
+use std::fs;
+
+use pallas::crypto::key::ed25519::SecretKey;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::governance::{
+    AnchorDocument, AnchorDocumentBody, Author, AuthorWitness, DRepBody, ProposalBody, Reference,
+    ReferenceKind, VoteRationaleBody,
+};
+use pallas_fun::utils::IntoInner;
+use pallas_fun::wrapper::{AnchorWrapper, anchor_content_hash};
+
+mod common;
+
+use common::hash32;
+
+fn proposal() -> AnchorDocument {
+    AnchorDocument {
+        authors: vec![],
+        body: AnchorDocumentBody::Proposal(ProposalBody {
+            title: "Raise the treasury cut".to_string(),
+            r#abstract: "Set tau to 25%".to_string(),
+            motivation: "More funding".to_string(),
+            rationale: "Reserves are shrinking slowly".to_string(),
+            references: vec![Reference {
+                kind: ReferenceKind::Other,
+                label: "Forum thread".to_string(),
+                uri: "https://forum.cardano.org/t/1".to_string(),
+            }],
+        }),
+    }
+}
+
+#[test]
+fn test_anchor_document_hash() {
+    assert_eq!(
+        anchor_content_hash(b"").to_string(),
+        "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+    );
+
+    let document = proposal();
+    let json = document.to_json().expect("should build json");
+    assert_eq!(json["hashAlgorithm"], "blake2b-256");
+    assert_eq!(json["authors"], serde_json::json!([]));
+    assert_eq!(json["body"]["abstract"], "Set tau to 25%");
+    assert_eq!(json["@context"]["body"]["@id"], "CIP108:body");
+    assert_eq!(json["body"]["references"][0]["@type"], "Other");
+
+    // canonical JSON sorts keys, so the bytes start with the context
+    let bytes = document.to_bytes().expect("should serialize");
+    assert!(bytes.starts_with(br#"{"@context":{"@language":"en-us","CIP100":"#));
+    let anchor = document
+        .anchor("https://example.com/proposal.jsonld".to_string())
+        .expect("should build anchor");
+    assert_eq!(
+        anchor.into_inner().content_hash,
+        anchor_content_hash(&bytes)
+    );
+    assert_eq!(anchor.verify(&bytes), Ok(()));
+
+    let drep = AnchorDocument {
+        authors: vec![],
+        body: AnchorDocumentBody::DRep(DRepBody {
+            given_name: "Alice".to_string(),
+            payment_address: None,
+            objectives: Some("Fund tooling".to_string()),
+            motivations: None,
+            qualifications: None,
+            do_not_list: Some(false),
+            references: vec![],
+        }),
+    };
+    let json = drep.to_json().expect("should build json");
+    assert_eq!(json["body"]["givenName"], "Alice");
+    assert_eq!(json["body"]["doNotList"], false);
+    assert!(json["body"].get("motivations").is_none());
+    assert_eq!(
+        json["@context"]["body"]["@context"]["givenName"],
+        "CIP119:givenName"
+    );
+}
+
+#[test]
+fn test_anchor_verify_file() {
+    let bytes = proposal().to_bytes().expect("should serialize");
+    let path = std::env::temp_dir().join("pallas_fun_anchor_verify_file.jsonld");
+    fs::write(&path, &bytes).expect("should write file");
+
+    let anchor =
+        AnchorWrapper::from_content("https://example.com/proposal.jsonld".to_string(), &bytes)
+            .expect("should create anchor");
+    assert_eq!(anchor.verify_file(&path), Ok(()));
+
+    let other = AnchorWrapper::new(
+        "https://example.com/proposal.jsonld".to_string(),
+        hash32(0x00),
+    )
+    .expect("should create anchor");
+    assert!(matches!(
+        other.verify_file(&path),
+        Err(PallasFunError::AnchorHashMismatch { .. })
+    ));
+    assert!(matches!(
+        anchor.verify_file(path.with_extension("missing")),
+        Err(PallasFunError::Io(_))
+    ));
+    fs::remove_file(path).expect("should remove file");
+}
+
+#[test]
+fn test_anchor_document_canonical_nquads() {
+    let rationale = AnchorDocument {
+        authors: vec![],
+        body: AnchorDocumentBody::VoteRationale(VoteRationaleBody {
+            comment: "Yes".to_string(),
+            references: vec![],
+        }),
+    };
+    assert_eq!(
+        rationale
+            .to_canonical_nquads()
+            .expect("should canonicalize rationale"),
+        "_:c14n0 <https://github.com/cardano-foundation/CIPs/blob/master/CIP-0100/README.md#body> _:c14n1 .\n\
+         _:c14n0 <https://github.com/cardano-foundation/CIPs/blob/master/CIP-0100/README.md#hashAlgorithm> \"blake2b-256\"@en-us .\n\
+         _:c14n1 <https://github.com/cardano-foundation/CIPs/blob/master/CIP-0100/README.md#comment> \"Yes\"@en-us .\n"
+    );
+
+    // references are a set: their order changes the published bytes but not
+    // the canonical form
+    let mut document = proposal();
+    let mut reordered = proposal();
+    if let (AnchorDocumentBody::Proposal(body), AnchorDocumentBody::Proposal(other)) =
+        (&mut document.body, &mut reordered.body)
+    {
+        let second = Reference {
+            kind: ReferenceKind::GovernanceMetadata,
+            label: "Say \"no\"\n".to_string(),
+            uri: "https://example.com/no".to_string(),
+        };
+        body.references.push(second.clone());
+        other.references.insert(0, second);
+    }
+    assert_ne!(
+        document.to_bytes().expect("should serialize"),
+        reordered.to_bytes().expect("should serialize")
+    );
+    let nquads = document
+        .to_canonical_nquads()
+        .expect("should canonicalize proposal");
+    assert_eq!(
+        nquads,
+        reordered
+            .to_canonical_nquads()
+            .expect("should canonicalize proposal")
+    );
+    assert_eq!(nquads.lines().count(), 14);
+    assert!(nquads.contains(r#" "Say \"no\"\n"@en-us ."#));
+    assert_eq!(
+        document.body_hash().expect("should hash body"),
+        reordered.body_hash().expect("should hash body")
+    );
+}
+
+#[test]
+fn test_anchor_document_authors() {
+    let mut document = proposal();
+    let body_hash = document.body_hash().expect("should hash body");
+    let key = SecretKey::from([7; 32]);
+    document.authors.push(Author {
+        name: "Alice".to_string(),
+        witness: AuthorWitness {
+            witness_algorithm: "ed25519".to_string(),
+            public_key: key.public_key().to_string(),
+            signature: key.sign(body_hash).to_string(),
+        },
+    });
+
+    // authors are not part of the signed body
+    assert_eq!(document.body_hash(), Ok(body_hash));
+    assert_eq!(document.verify_authors(), Ok(()));
+    let json = document.to_json().expect("should build json");
+    assert_eq!(json["authors"][0]["name"], "Alice");
+    assert_eq!(json["authors"][0]["witness"]["witnessAlgorithm"], "ed25519");
+
+    if let AnchorDocumentBody::Proposal(body) = &mut document.body {
+        body.title = "Lower the treasury cut".to_string();
+    }
+    let err = document.verify_authors().unwrap_err();
+    assert_eq!(err.path(), Some("authors[0]"));
+    assert!(matches!(
+        err.root_cause(),
+        PallasFunError::InvalidAuthorWitness(_)
+    ));
+}
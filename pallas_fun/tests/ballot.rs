@@ -0,0 +1,120 @@
+use pallas::ledger::primitives::Fragment;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::governance::Ballot;
+use pallas_fun::wrapper::{
+    GovActionIdWrapper, TransactionBodyWrapper, VoteKind, VoterKind, VoterWrapper,
+};
+
+mod common;
+
+use common::{hash28, hash32};
+
+fn drep() -> VoterWrapper {
+    VoterWrapper::new(VoterKind::DRepKey {
+        addr_key_hash: hash28(0x11),
+    })
+    .expect("should create voter")
+}
+
+#[test]
+fn test_ballot_from_csv() {
+    let first = format!("{}#0", hash32(0xbb));
+    let second = GovActionIdWrapper::new(&hash32(0xaa), 3)
+        .expect("should create gov action id")
+        .to_bech32()
+        .expect("should encode bech32");
+    let csv = format!(
+        "gov_action_id,vote,anchor_url,anchor_hash\n\
+         {first},No,,\n\
+         {second},yes,https://example.com/rationale.jsonld,{hash}\n\
+         {first},no,,\n",
+        hash = hash32(0x00)
+    );
+
+    let ballot = Ballot::from_csv(drep(), &csv).expect("should parse csv ballot");
+    // deduplicated and sorted by gov action id
+    assert_eq!(ballot.entries.len(), 2);
+    assert_eq!(
+        ballot.entries[0].gov_action_id,
+        GovActionIdWrapper::new(&hash32(0xaa), 3).expect("should create gov action id")
+    );
+    assert_eq!(ballot.entries[0].vote, VoteKind::Yes);
+    assert!(ballot.entries[0].anchor.is_some());
+    assert_eq!(ballot.entries[1].vote, VoteKind::No);
+
+    let procedures = ballot
+        .voting_procedures()
+        .expect("should build voting procedures");
+    assert_eq!(procedures.len(), 1);
+    assert_eq!(procedures[0].1.len(), 2);
+
+    let conflict = format!("{first},yes\n{first},no\n");
+    assert!(matches!(
+        Ballot::from_csv(drep(), &conflict),
+        Err(PallasFunError::DuplicateEntry { kind: "vote", .. })
+    ));
+    let err = Ballot::from_csv(drep(), &format!("{first},maybe\n")).unwrap_err();
+    assert_eq!(err.path(), Some("line 1"));
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::InvalidVote("maybe".to_string())
+    );
+
+    // the header may follow blank lines, but only as the first row
+    let leading = format!("\n\ngov_action_id,vote\n{first},yes\n");
+    let ballot = Ballot::from_csv(drep(), &leading).expect("should skip the header");
+    assert_eq!(ballot.entries.len(), 1);
+    let late = format!("{first},yes\ngov_action_id,vote\n");
+    let err = Ballot::from_csv(drep(), &late).unwrap_err();
+    assert_eq!(err.path(), Some("line 2.gov_action_id"));
+
+    // a comma in the url shifts the hash into a fifth column
+    let comma = format!(
+        "{first},yes,,\n{first},yes,https://example.com/a,b.jsonld,{}\n",
+        hash32(0x00)
+    );
+    let err = Ballot::from_csv(drep(), &comma).unwrap_err();
+    assert_eq!(err.path(), Some("line 2"));
+    assert!(matches!(
+        err.root_cause(),
+        PallasFunError::InvalidLength {
+            expected: 4,
+            actual: 5,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_ballot_split_by_size() {
+    let json = serde_json::to_string(
+        &(0..40)
+            .map(|i| {
+                serde_json::json!({
+                    "gov_action_id": format!("{}#{}", hash32(0xcc), i),
+                    "vote": "abstain",
+                })
+            })
+            .collect::<Vec<_>>(),
+    )
+    .expect("should serialize json");
+    let ballot = Ballot::from_json(drep(), &json).expect("should parse json ballot");
+
+    let max_bytes = 500;
+    let chunks = ballot.split(max_bytes).expect("should split ballot");
+    assert_eq!(chunks.iter().map(|c| c[0].1.len()).sum::<usize>(), 40);
+    assert!(chunks.len() > 1);
+    for chunk in chunks {
+        let encoded = TransactionBodyWrapper::parse_voting_procedures(Some(chunk))
+            .expect("should parse voting procedures")
+            .expect("should have voting procedures")
+            .encode_fragment()
+            .expect("should encode");
+        assert!(encoded.len() <= max_bytes);
+    }
+
+    assert!(matches!(
+        ballot.split(10),
+        Err(PallasFunError::TooLong { kind: "vote", .. })
+    ));
+}
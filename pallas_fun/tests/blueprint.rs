@@ -0,0 +1,90 @@
+use pallas::codec::utils::MaybeIndefArray;
+use pallas::ledger::primitives::{BigInt, BoundedBytes, Constr, PlutusData};
+use pallas_fun::blueprint::Blueprint;
+
+const PLUTUS_JSON: &str = r##"{
+  "preamble": {
+    "title": "acme/hello",
+    "version": "0.0.0",
+    "plutusVersion": "v3",
+    "compiler": { "name": "Aiken", "version": "v1.1.9" }
+  },
+  "validators": [
+    {
+      "title": "hello.hello.spend",
+      "datum": {
+        "title": "datum",
+        "schema": { "$ref": "#/definitions/hello~1Datum" }
+      },
+      "redeemer": {
+        "title": "redeemer",
+        "schema": { "$ref": "#/definitions/Int" }
+      },
+      "compiledCode": "46450101002499",
+      "hash": "f1a2e2f94a40c7b4a18ccb7c02c54d82be3e4418abb4c61f78b5b38e"
+    }
+  ],
+  "definitions": {
+    "ByteArray": { "dataType": "bytes" },
+    "Int": { "dataType": "integer" },
+    "hello/Datum": {
+      "title": "Datum",
+      "anyOf": [
+        {
+          "title": "Datum",
+          "dataType": "constructor",
+          "index": 0,
+          "fields": [
+            { "title": "owner", "$ref": "#/definitions/ByteArray" }
+          ]
+        }
+      ]
+    }
+  }
+}"##;
+
+fn datum(index: u64, owner: Vec<u8>) -> PlutusData {
+    PlutusData::Constr(Constr {
+        tag: 121 + index,
+        any_constructor: None,
+        fields: MaybeIndefArray::Indef(vec![PlutusData::BoundedBytes(BoundedBytes::from(owner))]),
+    })
+}
+
+#[test]
+fn test_blueprint_validator_hash_and_script_ref() {
+    let blueprint = Blueprint::from_json(PLUTUS_JSON).expect("should parse blueprint");
+    let validator = blueprint
+        .validator("hello.hello.spend")
+        .expect("validator exists");
+
+    assert!(validator.verify_hash().is_ok());
+    assert!(validator.to_script_ref().is_ok());
+}
+
+#[test]
+fn test_blueprint_validates_plutus_data() {
+    let blueprint = Blueprint::from_json(PLUTUS_JSON).expect("should parse blueprint");
+    let validator = &blueprint.validators[0];
+
+    assert!(
+        blueprint
+            .inline_datum(validator, &datum(0, vec![0xde, 0xad]))
+            .is_ok()
+    );
+    assert!(
+        blueprint
+            .validate_datum(validator, &datum(1, vec![]))
+            .is_err()
+    );
+    assert!(
+        blueprint
+            .validate_redeemer(validator, &PlutusData::BigInt(BigInt::Int(42.into())))
+            .is_ok()
+    );
+    assert!(
+        blueprint
+            .validate_redeemer(validator, &datum(0, vec![]))
+            .is_err()
+    );
+}
@@ -0,0 +1,110 @@
+use bech32::{ToBase32, Variant};
+use pallas_fun::cip129::GovCredentialRole;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::wrapper::{
+    DRepKind, DRepWrapper, GovActionIdWrapper, StakeCredentialKind, StakeCredentialWrapper,
+    VoterKind, VoterWrapper,
+};
+
+mod common;
+
+use common::{hash28, hash32};
+
+// CIP-129 test vectors
+#[test]
+fn test_cip129_vectors() {
+    let zero = hash28(0x00);
+    let cc_hot = VoterWrapper::new(VoterKind::ConstitutionalCommitteeKey {
+        script_hash: zero.clone(),
+    })
+    .expect("should create voter");
+    assert_eq!(
+        cc_hot.to_bech32(),
+        "cc_hot1qgqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqvcdjk7"
+    );
+    assert_eq!(VoterWrapper::from_bech32(&cc_hot.to_bech32()), Ok(cc_hot));
+
+    let gov_action =
+        GovActionIdWrapper::new(&hash32(0x00), 17).expect("should create gov action id");
+    assert_eq!(
+        gov_action.to_bech32(),
+        Ok("gov_action1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpzklpgpf".to_string())
+    );
+    let gov_action =
+        GovActionIdWrapper::new(&hash32(0x11), 0).expect("should create gov action id");
+    assert_eq!(
+        gov_action.to_bech32(),
+        Ok("gov_action1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygsq6dmejn".to_string())
+    );
+    assert_eq!(
+        GovActionIdWrapper::from_bech32(&gov_action.to_bech32().expect("should encode bech32")),
+        Ok(gov_action)
+    );
+    let large_index =
+        GovActionIdWrapper::new(&hash32(0x11), 300).expect("should create gov action id");
+    assert_eq!(
+        GovActionIdWrapper::from_bech32(&large_index.to_bech32().expect("should encode bech32")),
+        Ok(large_index)
+    );
+    // index 17 padded to two bytes is not the canonical encoding
+    let mut bytes = vec![0x11; 32];
+    bytes.extend_from_slice(&[0x00, 0x11]);
+    let padded = bech32::encode("gov_action", bytes.to_base32(), Variant::Bech32)
+        .expect("should encode bech32");
+    assert!(matches!(
+        GovActionIdWrapper::from_bech32(&padded),
+        Err(PallasFunError::Bech32(_))
+    ));
+}
+
+#[test]
+fn test_cip129_round_trip_and_legacy() {
+    let hash = "a".repeat(56);
+    let drep = DRepWrapper::new(DRepKind::Script {
+        script_hash: hash.clone(),
+    })
+    .expect("should create drep");
+    let bech32 = drep.to_bech32().expect("should encode bech32");
+    let legacy = drep
+        .to_legacy_bech32()
+        .expect("should encode legacy bech32");
+    assert!(bech32.starts_with("drep1"));
+    assert!(legacy.starts_with("drep_script1"));
+    assert_eq!(DRepWrapper::from_bech32(&bech32), Ok(drep.clone()));
+    assert_eq!(DRepWrapper::from_bech32(&legacy), Ok(drep.clone()));
+    assert!(
+        DRepWrapper::new(DRepKind::Abstain)
+            .expect("should create drep")
+            .to_bech32()
+            .is_err()
+    );
+
+    // the same DRep as a voter
+    let voter = VoterWrapper::new(VoterKind::DRepScript {
+        script_hash: hash.clone(),
+    })
+    .expect("should create voter");
+    assert_eq!(voter.to_bech32(), bech32);
+    assert_eq!(VoterWrapper::from_bech32(&legacy), Ok(voter));
+
+    let cold = StakeCredentialWrapper::new(StakeCredentialKind::AddrKeyhash(hash))
+        .expect("should create stake credential");
+    let bech32 = cold.to_bech32(GovCredentialRole::CommitteeCold);
+    assert!(bech32.starts_with("cc_cold1"));
+    assert_eq!(
+        StakeCredentialWrapper::from_bech32(GovCredentialRole::CommitteeCold, &bech32),
+        Ok(cold.clone())
+    );
+    assert_eq!(
+        StakeCredentialWrapper::from_bech32(
+            GovCredentialRole::CommitteeCold,
+            &cold.to_legacy_bech32(GovCredentialRole::CommitteeCold)
+        ),
+        Ok(cold)
+    );
+    // a cold credential is not a hot one
+    assert!(matches!(
+        StakeCredentialWrapper::from_bech32(GovCredentialRole::CommitteeHot, &bech32),
+        Err(PallasFunError::Bech32(_))
+    ));
+}
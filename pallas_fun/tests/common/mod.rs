@@ -0,0 +1,26 @@
+// Fixtures shared by the integration tests, each test crate uses some of them
+#![allow(dead_code)]
+
+use pallas_fun::protocol_params::ProtocolParams;
+use pallas_fun::wrapper::{StakeCredentialKind, StakeCredentialWrapper};
+
+// mainnet parameters from `cardano-cli query protocol-parameters`
+pub fn params() -> ProtocolParams {
+    ProtocolParams::from_cardano_cli_file("tests/data/cardano_cli_protocol_parameters.json")
+        .expect("should load cardano-cli parameters")
+}
+
+// 28 byte hash of `n` repeated, e.g. a key hash or policy id
+pub fn hash28(n: u8) -> String {
+    format!("{:02x}", n).repeat(28)
+}
+
+// 32 byte hash of `n` repeated, e.g. a transaction id
+pub fn hash32(n: u8) -> String {
+    format!("{:02x}", n).repeat(32)
+}
+
+pub fn key_credential(n: u8) -> StakeCredentialWrapper {
+    StakeCredentialWrapper::new(StakeCredentialKind::AddrKeyhash(hash28(n)))
+        .expect("should create stake credential")
+}
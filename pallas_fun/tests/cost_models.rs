@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use pallas_fun::cost_models::{CostModelChange, NamedCostModels, PlutusVersion};
+use pallas_fun::error::PallasFunError;
+use pallas_fun::wrapper::CostModelsWrapper;
+
+fn cost_models(v1_len: usize, v3_len: usize) -> CostModelsWrapper {
+    CostModelsWrapper {
+        plutus_v1: Some((0..v1_len as i64).collect()),
+        plutus_v2: None,
+        plutus_v3: Some((0..v3_len as i64).collect()),
+    }
+}
+
+#[test]
+fn test_cost_models_named_round_trip() {
+    assert_eq!(PlutusVersion::V1.parameter_names().len(), 166);
+    assert_eq!(PlutusVersion::V2.parameter_names().len(), 185);
+    assert_eq!(PlutusVersion::V3.parameter_names().len(), 297);
+
+    // PlutusV3 as of the Chang hard fork
+    let wrapper = cost_models(166, 251);
+    let named = wrapper.to_named().expect("should name parameters");
+    let v1 = named.plutus_v1.as_ref().expect("should have plutus v1");
+    assert_eq!(v1["addInteger-cpu-arguments-intercept"], 0);
+    assert_eq!(v1["verifyEd25519Signature-memory-arguments"], 165);
+    assert_eq!(
+        named.plutus_v3.as_ref().expect("should have plutus v3")["byteStringToInteger-memory-arguments-slope"],
+        250
+    );
+    assert_eq!(CostModelsWrapper::from_named(&named), Ok(wrapper));
+
+    let err = cost_models(167, 0).to_named().unwrap_err();
+    assert_eq!(err.path(), Some("PlutusV1"));
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::CostModelLength {
+            language: "PlutusV1",
+            expected: 166,
+            actual: 167,
+        }
+    );
+}
+
+#[test]
+fn test_cost_models_from_named_rejects_unknown_and_gaps() {
+    let named = |entries: &[(&str, i64)]| NamedCostModels {
+        plutus_v1: Some(
+            entries
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect::<BTreeMap<_, _>>(),
+        ),
+        ..Default::default()
+    };
+
+    let unknown = CostModelsWrapper::from_named(&named(&[("addInteger-cpu", 1)])).unwrap_err();
+    assert_eq!(
+        unknown.root_cause(),
+        &PallasFunError::UnknownParameter("addInteger-cpu".to_string())
+    );
+
+    let gap = CostModelsWrapper::from_named(&named(&[
+        ("addInteger-cpu-arguments-intercept", 1),
+        ("addInteger-memory-arguments-intercept", 2),
+    ]))
+    .unwrap_err();
+    assert_eq!(
+        gap.root_cause(),
+        &PallasFunError::MissingField("addInteger-cpu-arguments-slope".to_string())
+    );
+}
+
+#[test]
+fn test_cost_models_diff_by_name() {
+    let current = cost_models(166, 251);
+    let mut proposed = cost_models(166, 252);
+    proposed.plutus_v1.as_mut().expect("should have plutus v1")[2] = 100;
+
+    assert_eq!(
+        current.diff(&proposed),
+        Ok(vec![
+            CostModelChange {
+                version: PlutusVersion::V1,
+                name: "addInteger-memory-arguments-intercept",
+                current: Some(2),
+                proposed: Some(100),
+            },
+            CostModelChange {
+                version: PlutusVersion::V3,
+                name: "andByteString-cpu-arguments-intercept",
+                current: None,
+                proposed: Some(251),
+            },
+        ])
+    );
+}
@@ -0,0 +1,135 @@
+use pallas_fun::delegation::DelegationState;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::utils::parse_pool_key_hash;
+use pallas_fun::wrapper::{CertificateKind, CertificateWrapper, DRepKind, DRepWrapper};
+
+mod common;
+
+use common::{hash28, key_credential, params};
+
+const KEY_DEPOSIT: u64 = 2_000_000;
+
+fn certificate(kind: CertificateKind) -> CertificateWrapper {
+    CertificateWrapper::new(kind).expect("should create certificate")
+}
+
+fn state() -> DelegationState {
+    let mut state = DelegationState::default();
+    state
+        .stake_credentials
+        .insert(key_credential(1), KEY_DEPOSIT);
+    state
+        .pools
+        .insert(parse_pool_key_hash(&hash28(10)).expect("should parse pool key hash"));
+    state
+}
+
+#[test]
+fn test_delegation_state_apply() {
+    let certificates = [
+        certificate(CertificateKind::StakeRegDeleg {
+            stake_credential_wrapper: key_credential(2),
+            pool_key_hash: hash28(10),
+            amount: KEY_DEPOSIT,
+        }),
+        certificate(CertificateKind::RegDRepCert {
+            drep_cred: key_credential(3),
+            amount: 500_000_000,
+            anchor_wrapper: None,
+        }),
+        // the DRep registered above can receive delegations
+        certificate(CertificateKind::VoteDeleg {
+            stake_credential_wrapper: key_credential(2),
+            drep_wrapper: DRepWrapper::new(DRepKind::Key {
+                addr_key_hash: hash28(0x03),
+            })
+            .expect("should create drep"),
+        }),
+        certificate(CertificateKind::UnReg {
+            stake_credential_wrapper: key_credential(1),
+            amount: KEY_DEPOSIT,
+        }),
+        certificate(CertificateKind::PoolRetirement {
+            pool_key_hash: hash28(10),
+            epoch: 518,
+        }),
+    ];
+
+    let next = state()
+        .apply(&certificates, &params(), 500)
+        .expect("should apply certificates");
+    assert_eq!(
+        next.stake_credentials.keys().collect::<Vec<_>>(),
+        vec![&key_credential(2)]
+    );
+    assert_eq!(next.dreps.get(&key_credential(3)), Some(&500_000_000));
+}
+
+#[test]
+fn test_delegation_state_rejects_invalid_certificates() {
+    let reject = |certificates: &[CertificateWrapper]| {
+        state().apply(certificates, &params(), 500).unwrap_err()
+    };
+
+    let register = certificate(CertificateKind::Reg {
+        stake_credential_wrapper: key_credential(2),
+        amount: KEY_DEPOSIT,
+    });
+    let err = reject(&[register.clone(), register]);
+    assert_eq!(err.path(), Some("certificates[1]"));
+    assert!(matches!(
+        err.root_cause(),
+        PallasFunError::DuplicateEntry {
+            kind: "stake registration",
+            ..
+        }
+    ));
+
+    let err = reject(&[certificate(CertificateKind::StakeDelegation {
+        stake_credential_wrapper: key_credential(1),
+        pool_key_hash: hash28(11),
+    })]);
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::Unregistered {
+            kind: "pool",
+            entry: hash28(11),
+        }
+    );
+
+    let err = reject(&[certificate(CertificateKind::UnReg {
+        stake_credential_wrapper: key_credential(1),
+        amount: 1_000_000,
+    })]);
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::DepositMismatch {
+            kind: "stake refund",
+            expected: KEY_DEPOSIT,
+            actual: 1_000_000,
+        }
+    );
+
+    // maximum_epoch is 18
+    let err = reject(&[certificate(CertificateKind::PoolRetirement {
+        pool_key_hash: hash28(10),
+        epoch: 519,
+    })]);
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::InvalidRetirementEpoch {
+            epoch: 519,
+            min: 501,
+            max: 518,
+        }
+    );
+
+    let err = reject(&[certificate(CertificateKind::UpdateDRepCert {
+        drep_cred: key_credential(3),
+        anchor_wrapper: None,
+    })]);
+    assert!(matches!(
+        err.root_cause(),
+        PallasFunError::Unregistered { kind: "DRep", .. }
+    ));
+}
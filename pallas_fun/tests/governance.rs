@@ -0,0 +1,433 @@
+use pallas::ledger::primitives::NetworkId;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::governance::{
+    CommitteeUpdateSummary, EnactedActions, ParamGroup, ParamUpdateClassification, ParamUpdateDiff,
+    RatificationThresholds, TreasuryWithdrawalSummary, can_follow, check_guardrails,
+};
+use pallas_fun::wrapper::{
+    CostModelsWrapper, DRepVotingThresholdsWrapper, GovActionIdWrapper, GovActionKind,
+    GovActionWrapper, PoolVotingThresholdsWrapper, ProtocolParamUpdateBuilder,
+    RewardAccountWrapper,
+};
+
+mod common;
+
+use common::{hash28, hash32, key_credential, params};
+
+fn drep_thresholds() -> DRepVotingThresholdsWrapper {
+    DRepVotingThresholdsWrapper {
+        motion_no_confidence: (67, 100),
+        committee_normal: (67, 100),
+        committee_no_confidence: (3, 5),
+        update_constitution: (3, 4),
+        hard_fork_initiation: (3, 5),
+        pp_network_group: (67, 100),
+        pp_economic_group: (67, 100),
+        pp_technical_group: (67, 100),
+        pp_governance_group: (3, 4),
+        treasury_withdrawal: (67, 100),
+    }
+}
+
+fn pool_thresholds() -> PoolVotingThresholdsWrapper {
+    PoolVotingThresholdsWrapper {
+        motion_no_confidence: (51, 100),
+        committee_normal: (51, 100),
+        committee_no_confidence: (51, 100),
+        hard_fork_initiation: (51, 100),
+        security_voting_threshold: (51, 100),
+    }
+}
+
+#[test]
+fn test_param_update_classification() {
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_max_tx_ex_units(16_500_000, 10_000_000_000)
+        .set_drep_deposit(400_000_000)
+        .set_key_deposit(1_000_000)
+        .build()
+        .expect("should build");
+
+    let classification = ParamUpdateClassification::new(&update);
+    let fields: Vec<&str> = classification.changes.iter().map(|c| c.field).collect();
+    assert_eq!(
+        fields,
+        vec!["key_deposit", "max_tx_ex_units", "drep_deposit"]
+    );
+    assert_eq!(
+        classification.groups,
+        vec![
+            ParamGroup::Network,
+            ParamGroup::Economic,
+            ParamGroup::Governance
+        ]
+    );
+    assert!(!classification.security_vote_required);
+    assert_eq!(
+        classification.thresholds(&drep_thresholds(), &pool_thresholds()),
+        RatificationThresholds {
+            drep: (3, 4),
+            spo: None,
+        }
+    );
+}
+
+#[test]
+fn test_param_update_security_group() {
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_max_block_body_size(98_304)
+        .build()
+        .expect("should build");
+
+    let classification = ParamUpdateClassification::new(&update);
+    assert_eq!(classification.groups, vec![ParamGroup::Network]);
+    assert!(classification.security_vote_required);
+    assert_eq!(
+        classification.thresholds(&drep_thresholds(), &pool_thresholds()),
+        RatificationThresholds {
+            drep: (67, 100),
+            spo: Some((51, 100)),
+        }
+    );
+}
+
+#[test]
+fn test_guardrails_accept_valid_update() {
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_max_block_body_size(98_304)
+        .set_max_tx_ex_units(16_500_000, 10_000_000_000)
+        .set_drep_voting_thresholds(drep_thresholds())
+        .set_pool_voting_thresholds(pool_thresholds())
+        .build()
+        .expect("should build");
+
+    assert_eq!(check_guardrails(&update, &params()), vec![]);
+}
+
+#[test]
+fn test_guardrails_report_violations() {
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_minfee_a(10)
+        .set_max_transaction_size(200_000)
+        .set_treasury_growth_rate(1, 2)
+        .set_drep_voting_thresholds(DRepVotingThresholdsWrapper {
+            pp_governance_group: (2, 3),
+            ..drep_thresholds()
+        })
+        .build()
+        .expect("should build");
+
+    let rules: Vec<&str> = check_guardrails(&update, &params())
+        .iter()
+        .map(|v| v.rule)
+        .collect();
+    assert_eq!(
+        rules,
+        vec!["TFB-01", "MTS-01", "MTS-04", "TC-02", "VT-GOV-01"]
+    );
+
+    // a malformed cost model is reported alongside the other violations
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_minfee_a(10)
+        .set_cost_models(CostModelsWrapper {
+            plutus_v1: Some(vec![0; 10]),
+            plutus_v2: None,
+            plutus_v3: None,
+        })
+        .build()
+        .expect("should build update");
+    let violations = check_guardrails(&update, &params());
+    assert_eq!(
+        violations.iter().map(|v| v.rule).collect::<Vec<_>>(),
+        vec!["TFB-01", "CMO-LEN"]
+    );
+    assert_eq!(
+        violations[1].field,
+        "cost_models_for_script_languages.plutus_v1"
+    );
+}
+
+#[test]
+fn test_guardrails_maximum_epoch() {
+    let rules = |maximum_epoch| {
+        let update = ProtocolParamUpdateBuilder::new()
+            .set_maximum_epoch(maximum_epoch)
+            .build()
+            .expect("should build update");
+        check_guardrails(&update, &params())
+            .iter()
+            .map(|v| v.rule)
+            .collect::<Vec<_>>()
+    };
+    assert!(rules(300).is_empty());
+    assert!(rules(21).is_empty());
+    assert_eq!(rules(20), vec!["EMAX-03"]);
+    assert_eq!(rules(366), vec!["EMAX-01"]);
+}
+
+#[test]
+fn test_param_update_diff() {
+    let current = params();
+    let mut plutus_v1 = current
+        .cost_models_for_script_languages
+        .plutus_v1
+        .clone()
+        .expect("should have ");
+    plutus_v1[1] = 421;
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_key_deposit(2_000_000)
+        .set_expansion_rate(1, 300)
+        .set_execution_costs((577, 10_000), (700, 10_000_000))
+        .set_cost_models(CostModelsWrapper {
+            plutus_v1: Some(plutus_v1),
+            plutus_v2: None,
+            plutus_v3: None,
+        })
+        .build()
+        .expect("should build");
+
+    let diff = ParamUpdateDiff::new(&current, &update).expect("should diff parameters");
+    assert_eq!(diff.changed().count(), 3);
+    assert_eq!(
+        diff.to_string(),
+        "\
+key_deposit                                            2000000 -> 2000000 (unchanged)
+expansion_rate                                           0.003 -> 0.0033333333
+cost_models.PlutusV1.addInteger-cpu-arguments-slope        420 -> 421
+execution_costs.mem_price                               0.0577 -> 0.0577 (unchanged)
+execution_costs.step_price                           0.0000721 -> 0.00007
+"
+    );
+}
+
+fn gov_action_id(index: u32) -> GovActionIdWrapper {
+    GovActionIdWrapper::new(&hash32(0xab), index).expect("should create gov action id")
+}
+
+fn hard_fork(previous: Option<GovActionIdWrapper>, version: (u64, u64)) -> GovActionWrapper {
+    GovActionWrapper::new(GovActionKind::HardForkInitiation {
+        gov_action_id_wrapper: previous,
+        protocol_version: version,
+    })
+    .expect("should create gov action")
+}
+
+#[test]
+fn test_prerequisite_chain() {
+    let enacted = EnactedActions {
+        hard_fork: Some(gov_action_id(0)),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        enacted.check_prerequisite(&hard_fork(Some(gov_action_id(0)), (11, 0)), (10, 0)),
+        Ok(())
+    );
+    assert_eq!(
+        enacted.check_prerequisite(&hard_fork(None, (11, 0)), (10, 0)),
+        Err(PallasFunError::PrerequisiteMismatch {
+            purpose: "hard fork",
+            expected: Some(format!("{}#0", hash32(0xab))),
+            actual: None,
+        })
+    );
+
+    // no confidence and committee updates share the committee chain
+    let no_confidence = GovActionWrapper::new(GovActionKind::NoConfidence {
+        gov_action_id_wrapper: Some(gov_action_id(1)),
+    })
+    .expect("should create gov action");
+    assert!(matches!(
+        enacted.check_prerequisite(&no_confidence, (10, 0)),
+        Err(PallasFunError::PrerequisiteMismatch {
+            purpose: "committee",
+            expected: None,
+            ..
+        })
+    ));
+    assert_eq!(
+        enacted.check_prerequisite(
+            &GovActionWrapper::new(GovActionKind::Information).expect("should create gov action"),
+            (10, 0)
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_hard_fork_successor_version() {
+    assert!(can_follow((10, 0), (11, 0)));
+    assert!(can_follow((10, 0), (10, 1)));
+    assert!(!can_follow((10, 0), (12, 0)));
+    assert!(!can_follow((10, 2), (11, 1)));
+    assert!(!can_follow((10, 0), (10, 0)));
+
+    assert_eq!(
+        EnactedActions::default().check_prerequisite(&hard_fork(None, (12, 0)), (10, 0)),
+        Err(PallasFunError::InvalidHardForkVersion {
+            current: (10, 0),
+            proposed: (12, 0),
+        })
+    );
+}
+
+fn treasury_withdrawal(withdrawals: &[(&str, u64)]) -> GovActionWrapper {
+    GovActionWrapper::new(GovActionKind::TreasuryWithdrawals {
+        withdrawals: withdrawals
+            .iter()
+            .map(|(account, amount)| {
+                (
+                    RewardAccountWrapper::new(account).expect("should create reward account"),
+                    *amount,
+                )
+            })
+            .collect(),
+        script_hash: None,
+    })
+    .expect("should create gov action")
+}
+
+#[test]
+fn test_treasury_withdrawal_total() {
+    let first = format!("e1{}", hash28(0x11));
+    let second = format!("f1{}", hash28(0x22));
+    let action = treasury_withdrawal(&[(&first, 1_000), (&second, 2_500)]);
+
+    let summary = TreasuryWithdrawalSummary::new(&action, Some(NetworkId::Mainnet))
+        .expect("should summarize withdrawals");
+    assert_eq!(
+        summary,
+        TreasuryWithdrawalSummary {
+            accounts: 2,
+            total: 3_500,
+        }
+    );
+    assert!(summary.is_feasible(3_500));
+    assert!(!summary.is_feasible(3_499));
+
+    let err = TreasuryWithdrawalSummary::new(&action, Some(NetworkId::Testnet)).unwrap_err();
+    assert_eq!(err.path(), Some("withdrawals[0]"));
+    assert_eq!(
+        TreasuryWithdrawalSummary::new(
+            &GovActionWrapper::new(GovActionKind::Information).expect("should create gov action"),
+            None
+        ),
+        Err(PallasFunError::UnexpectedGovAction("treasury withdrawals"))
+    );
+}
+
+#[test]
+fn test_treasury_withdrawal_rejects_zero_duplicates_and_overflow() {
+    let first = format!("e0{}", hash28(0x11));
+    let second = format!("e0{}", hash28(0x22));
+
+    let zero = treasury_withdrawal(&[(&first, 1), (&second, 0)]);
+    let err = TreasuryWithdrawalSummary::new(&zero, None).unwrap_err();
+    assert_eq!(err.path(), Some("withdrawals[1]"));
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::ZeroAmount("treasury withdrawal")
+    );
+
+    let duplicate = treasury_withdrawal(&[(&first, 1), (&first, 2)]);
+    assert!(matches!(
+        TreasuryWithdrawalSummary::new(&duplicate, None)
+            .unwrap_err()
+            .root_cause(),
+        PallasFunError::DuplicateEntry { .. }
+    ));
+
+    let overflow = treasury_withdrawal(&[(&first, u64::MAX), (&second, 1)]);
+    assert_eq!(
+        TreasuryWithdrawalSummary::new(&overflow, None),
+        Err(PallasFunError::Overflow("treasury withdrawal total"))
+    );
+}
+
+fn update_committee(removed: &[u8], added: &[(u8, u64)], quorum: (u64, u64)) -> GovActionWrapper {
+    GovActionWrapper::new(GovActionKind::UpdateCommittee {
+        gov_action_id_wrapper: None,
+        cold_credentials: removed.iter().map(|n| key_credential(*n)).collect(),
+        hot_credential: added
+            .iter()
+            .map(|(n, epoch)| (key_credential(*n), *epoch))
+            .collect(),
+        unit_interval: quorum,
+    })
+    .expect("should create gov action")
+}
+
+#[test]
+fn test_committee_update_summary() {
+    // minimum size 7, term limit 146
+    let params = params();
+    let members: Vec<_> = (1..=7).map(key_credential).collect();
+
+    let action = update_committee(&[1, 2], &[(8, 600), (9, 646)], (2, 3));
+    assert_eq!(
+        CommitteeUpdateSummary::new(&action, &members, 500, &params),
+        Ok(CommitteeUpdateSummary {
+            added: 2,
+            removed: 2,
+            size: 7,
+            quorum: (2, 3),
+        })
+    );
+
+    let shrink = update_committee(&[1], &[], (2, 3));
+    assert_eq!(
+        CommitteeUpdateSummary::new(&shrink, &members, 500, &params),
+        Err(PallasFunError::CommitteeTooSmall { size: 6, min: 7 })
+    );
+}
+
+#[test]
+fn test_committee_update_rejects_invalid_members() {
+    let params = params();
+    let members: Vec<_> = (1..=7).map(key_credential).collect();
+
+    let too_late = update_committee(&[], &[(8, 600), (9, 647)], (2, 3));
+    let err = CommitteeUpdateSummary::new(&too_late, &members, 500, &params).unwrap_err();
+    assert_eq!(err.path(), Some("members[1]"));
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::InvalidCommitteeExpiry {
+            expiry: 647,
+            min: 501,
+            max: 646,
+        }
+    );
+
+    let overlap = update_committee(&[3], &[(3, 600)], (2, 3));
+    assert!(matches!(
+        CommitteeUpdateSummary::new(&overlap, &members, 500, &params)
+            .unwrap_err()
+            .root_cause(),
+        PallasFunError::DuplicateEntry { .. }
+    ));
+
+    let quorum = update_committee(&[], &[], (4, 3));
+    assert_eq!(
+        CommitteeUpdateSummary::new(&quorum, &members, 500, &params)
+            .unwrap_err()
+            .path(),
+        Some("quorum")
+    );
+
+    // a decoded 0/0 quorum bypasses the constructor check
+    let encoded = update_committee(&[], &[], (0, 1))
+        .encode()
+        .expect("should encode");
+    assert!(encoded.ends_with("d81e820001"));
+    let zero = GovActionWrapper::decode(encoded.replace("d81e820001", "d81e820000"))
+        .expect("should decode gov action");
+    assert!(matches!(
+        CommitteeUpdateSummary::new(&zero, &members, 500, &params)
+            .unwrap_err()
+            .root_cause(),
+        PallasFunError::InvalidRational {
+            numerator: 0,
+            denominator: 0
+        }
+    ));
+}
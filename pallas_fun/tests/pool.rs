@@ -0,0 +1,131 @@
+use pallas_fun::error::PallasFunError;
+use pallas_fun::pool::{PoolMetadataDocument, check_pool_registration, load_pool_metadata};
+use pallas_fun::utils::IntoInner;
+use pallas_fun::wrapper::{
+    CertificateKind, CertificateWrapper, PoolMetadataWrapper, RelayKind, RelayWrapper,
+    RewardAccountWrapper,
+};
+
+mod common;
+
+use common::{hash28, hash32, params};
+
+const OWNER: &str = "276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+
+fn pool_registration(cost: u64, margin: (u64, u64), owners: Vec<String>) -> CertificateWrapper {
+    CertificateWrapper::new(CertificateKind::PoolRegistration {
+        operator: hash28(0xaa),
+        vrf_keyhash: hash32(0xbb),
+        pledge: 1_000_000_000,
+        cost,
+        margin_nominator: margin.0,
+        margin_denominator: margin.1,
+        reward_account_wrapper: RewardAccountWrapper::new(&format!("e1{}", OWNER))
+            .expect("should create reward account"),
+        pool_owners: owners,
+        relay_wrappers: vec![
+            RelayWrapper::new(RelayKind::SingleHostName(
+                Some(3001),
+                "relay.example.com".to_string(),
+            ))
+            .expect("should create relay"),
+        ],
+        pool_metadata_wrapper: None,
+    })
+    .expect("should create certificate")
+}
+
+#[test]
+fn test_pool_registration_checks() {
+    let params = params();
+    let owners = vec![OWNER.to_string()];
+    assert_eq!(
+        check_pool_registration(
+            &pool_registration(170_000_000, (1, 100), owners.clone()),
+            &params
+        ),
+        Ok(())
+    );
+
+    let err = check_pool_registration(
+        &pool_registration(169_999_999, (1, 100), owners.clone()),
+        &params,
+    )
+    .unwrap_err();
+    assert_eq!(err.path(), Some("cost"));
+
+    let err = check_pool_registration(&pool_registration(170_000_000, (3, 2), owners), &params)
+        .unwrap_err();
+    assert_eq!(err.path(), Some("margin"));
+
+    // a decoded 0/0 margin bypasses the constructor check
+    let encoded = pool_registration(170_000_000, (0, 1), vec![OWNER.to_string()])
+        .encode()
+        .expect("should encode");
+    assert_eq!(encoded.matches("d81e820001").count(), 1);
+    let zero = CertificateWrapper::decode(encoded.replace("d81e820001", "d81e820000"))
+        .expect("should decode certificate");
+    let err = check_pool_registration(&zero, &params).unwrap_err();
+    assert_eq!(err.path(), Some("margin"));
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::InvalidRational {
+            numerator: 0,
+            denominator: 0
+        }
+    );
+
+    let err = check_pool_registration(
+        &pool_registration(170_000_000, (1, 100), vec![hash28(0xcc)]),
+        &params,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::MissingOwner(OWNER.to_string())
+    );
+
+    // 65 byte DNS name
+    let dns_name = format!("{}.example.com", "a".repeat(53));
+    assert!(matches!(
+        RelayWrapper::new(RelayKind::MultiHostName(dns_name)),
+        Err(PallasFunError::TooLong { max: 64, .. })
+    ));
+}
+
+#[test]
+fn test_pool_metadata_file() {
+    let (document, pool_metadata) = load_pool_metadata(
+        "https://example.com/poolMetadata.json".to_string(),
+        "tests/data/pool_metadata.json",
+    )
+    .expect("should load pool metadata");
+    assert_eq!(document.ticker, "EXMPL");
+    assert_eq!(
+        pool_metadata.into_inner().hash.to_string(),
+        "238009adb65f38ecd209ba20cb7b1a6e048974e6231224d567a239b3900b1788"
+    );
+
+    let long_url = format!("https://example.com/{}", "a".repeat(45));
+    assert!(matches!(
+        PoolMetadataWrapper::from_content(long_url, b"{}"),
+        Err(PallasFunError::TooLong { max: 64, .. })
+    ));
+
+    let ticker = br#"{"name":"P","description":"","ticker":"TOOLONG","homepage":"https://p.io"}"#;
+    assert!(matches!(
+        PoolMetadataDocument::from_json(ticker),
+        Err(PallasFunError::InvalidPoolMetadata {
+            field: "ticker",
+            ..
+        })
+    ));
+    let homepage = br#"{"name":"P","description":"","ticker":"POOL","homepage":"p.io"}"#;
+    assert!(matches!(
+        PoolMetadataDocument::from_json(homepage),
+        Err(PallasFunError::InvalidPoolMetadata {
+            field: "homepage",
+            ..
+        })
+    ));
+}
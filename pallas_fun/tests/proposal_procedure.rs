@@ -0,0 +1,114 @@
+use pallas::ledger::primitives::NetworkId;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::utils::IntoInner;
+use pallas_fun::wrapper::{
+    AnchorWrapper, ConstitutionWrapper, GovActionKind, GovActionWrapper, ProposalProcedureBuilder,
+    RewardAccountWrapper,
+};
+
+mod common;
+
+use common::{hash32, params};
+
+const REWARD_ACCOUNT: &str = "e1276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+
+fn anchor(url: &str) -> AnchorWrapper {
+    AnchorWrapper::new(url.to_string(), hash32(0x00)).expect("should create anchor")
+}
+
+fn withdrawal(script_hash: Option<String>) -> GovActionWrapper {
+    GovActionWrapper::new(GovActionKind::TreasuryWithdrawals {
+        withdrawals: vec![(
+            RewardAccountWrapper::new(REWARD_ACCOUNT).expect("should create reward account"),
+            1_000,
+        )],
+        script_hash,
+    })
+    .expect("should create gov action")
+}
+
+#[test]
+fn test_proposal_procedure_builder() {
+    let guardrail = "fa24fb305126805cf2164c161d852a0e7330cf988f1fe558cf7d4a64".to_string();
+    let constitution = ConstitutionWrapper::new(
+        anchor("https://example.com/constitution.txt"),
+        Some(guardrail.clone()),
+    )
+    .expect("should create constitution");
+
+    let builder = ProposalProcedureBuilder::new(&params())
+        .set_network_id(NetworkId::Mainnet)
+        .set_reward_account(
+            RewardAccountWrapper::new(REWARD_ACCOUNT).expect("should create reward account"),
+        )
+        .set_anchor(anchor("https://example.com/proposal.json"))
+        .set_constitution(&constitution);
+
+    let proposal = builder
+        .clone()
+        .set_gov_action(withdrawal(Some(guardrail.clone())))
+        .build()
+        .expect("should build proposal");
+    assert_eq!(proposal.into_inner().deposit, 100_000_000_000);
+
+    let err = builder
+        .set_gov_action(withdrawal(None))
+        .build()
+        .unwrap_err();
+    assert_eq!(err.path(), Some("gov_action"));
+    assert_eq!(
+        err.root_cause(),
+        &PallasFunError::GuardrailScriptMismatch {
+            expected: Some(guardrail),
+            actual: None,
+        }
+    );
+}
+
+#[test]
+fn test_proposal_procedure_builder_rejects_account_and_anchor() {
+    let builder = ProposalProcedureBuilder::new(&params()).set_gov_action(
+        GovActionWrapper::new(GovActionKind::Information).expect("should create gov action"),
+    );
+
+    // mainnet account on testnet
+    let err = builder
+        .clone()
+        .set_network_id(NetworkId::Testnet)
+        .set_reward_account(
+            RewardAccountWrapper::new(REWARD_ACCOUNT).expect("should create reward account"),
+        )
+        .set_anchor(anchor("https://example.com/proposal.json"))
+        .build()
+        .unwrap_err();
+    assert_eq!(err.path(), Some("reward_account"));
+
+    // payment address header
+    let err = builder
+        .clone()
+        .set_reward_account(
+            RewardAccountWrapper::new(&REWARD_ACCOUNT.replacen('e', "6", 1))
+                .expect("should create reward account"),
+        )
+        .set_anchor(anchor("https://example.com/proposal.json"))
+        .build()
+        .unwrap_err();
+    assert_eq!(err.path(), Some("reward_account"));
+
+    let long_url = format!("https://example.com/{}", "a".repeat(120));
+    assert_eq!(
+        AnchorWrapper::new(long_url, hash32(0x00)),
+        Err(PallasFunError::TooLong {
+            kind: "anchor url",
+            max: 128,
+            actual: 140,
+        })
+    );
+    let err = builder
+        .set_reward_account(
+            RewardAccountWrapper::new(REWARD_ACCOUNT).expect("should create reward account"),
+        )
+        .build()
+        .unwrap_err();
+    assert_eq!(err, PallasFunError::MissingField("anchor".to_string()));
+}
@@ -0,0 +1,85 @@
+use pallas::ledger::primitives::RationalNumber;
+use pallas_fun::error::PallasFunError;
+use pallas_fun::utils::{IntoInner, format_decimal_rational, parse_decimal_rational};
+use pallas_fun::wrapper::{ProtocolParamUpdateBuilder, ProtocolParamUpdateWrapper};
+
+const CARDANO_CLI_JSON: &str = include_str!("data/cardano_cli_protocol_parameters.json");
+
+#[test]
+fn test_protocol_param_update_from_cardano_cli_json() {
+    let update = ProtocolParamUpdateBuilder::from_cardano_cli_json(CARDANO_CLI_JSON)
+        .expect("should load")
+        .build()
+        .expect("should build")
+        .into_inner();
+
+    assert_eq!(update.minfee_a, Some(44));
+    assert_eq!(update.minfee_b, Some(155381));
+    assert_eq!(update.max_tx_ex_units.map(|u| u.mem), Some(14_000_000));
+    assert_eq!(update.max_block_ex_units.map(|u| u.mem), Some(62_000_000));
+    assert_eq!(
+        update.execution_costs.map(|p| p.step_price),
+        Some(RationalNumber {
+            numerator: 721,
+            denominator: 10_000_000,
+        })
+    );
+    assert_eq!(
+        update.drep_voting_thresholds.map(|t| t.pp_governance_group),
+        Some(RationalNumber {
+            numerator: 3,
+            denominator: 4,
+        })
+    );
+}
+
+#[test]
+fn test_protocol_param_update_diff() {
+    let current = ProtocolParamUpdateBuilder::from_cardano_cli_json(CARDANO_CLI_JSON)
+        .expect("should load cardano-cli parameters")
+        .build()
+        .expect("should build");
+    let proposed = ProtocolParamUpdateBuilder::from_update(&current)
+        .set_max_tx_ex_units(16_500_000, 10_000_000_000)
+        .set_drep_deposit(500_000_000)
+        .build()
+        .expect("should build");
+
+    let diff = ProtocolParamUpdateWrapper::diff(&current, &proposed).expect("should diff updates");
+    let expected = ProtocolParamUpdateBuilder::new()
+        .set_max_tx_ex_units(16_500_000, 10_000_000_000)
+        .build()
+        .expect("should build");
+    assert_eq!(diff, expected);
+
+    assert_eq!(
+        ProtocolParamUpdateWrapper::diff(&current, &current),
+        Err(PallasFunError::EmptySet("protocol parameter update"))
+    );
+    assert_eq!(
+        ProtocolParamUpdateBuilder::new().build(),
+        Err(PallasFunError::EmptySet("protocol parameter update"))
+    );
+}
+
+#[test]
+fn test_parse_decimal_rational() {
+    assert_eq!(parse_decimal_rational("0.0577"), Ok((577, 10_000)));
+    assert_eq!(parse_decimal_rational("7.21e-5"), Ok((721, 10_000_000)));
+    assert_eq!(parse_decimal_rational("3.0e-3"), Ok((3, 1_000)));
+    assert_eq!(parse_decimal_rational("15"), Ok((15, 1)));
+    assert!(parse_decimal_rational("-0.5").is_err());
+
+    let overflow = Err(PallasFunError::Overflow("decimal"));
+    assert_eq!(parse_decimal_rational("1e-2147483648"), overflow);
+    assert_eq!(parse_decimal_rational("1e2147483647"), overflow);
+    assert_eq!(parse_decimal_rational(&"9".repeat(40)), overflow);
+}
+
+#[test]
+fn test_format_decimal_rational() {
+    assert_eq!(format_decimal_rational(721, 10_000_000), "0.0000721");
+    assert_eq!(format_decimal_rational(3, 2), "1.5");
+    assert_eq!(format_decimal_rational(15, 1), "15");
+    assert_eq!(format_decimal_rational(2, 3), "0.6666666667");
+}
@@ -0,0 +1,69 @@
+use pallas::ledger::primitives::{ExUnits, RationalNumber};
+use pallas_fun::protocol_params::ProtocolParams;
+use pallas_fun::wrapper::ProtocolParamUpdateBuilder;
+
+mod common;
+
+use common::params;
+
+#[test]
+fn test_protocol_params_loaders_agree() {
+    let params = params();
+    assert_eq!(params.protocol_version, (10, 0));
+    assert_eq!(
+        params.execution_costs.step_price,
+        RationalNumber {
+            numerator: 721,
+            denominator: 10_000_000,
+        }
+    );
+
+    let blockfrost =
+        ProtocolParams::from_blockfrost_file("tests/data/blockfrost_epoch_parameters.json")
+            .expect("should load Blockfrost parameters");
+    let koios = ProtocolParams::from_koios_file("tests/data/koios_epoch_params.json")
+        .expect("should load Koios parameters");
+
+    assert_eq!(blockfrost, params);
+    assert_eq!(koios, params);
+}
+
+#[test]
+fn test_protocol_params_apply_update() {
+    let params = params();
+    let update = ProtocolParamUpdateBuilder::new()
+        .set_max_tx_ex_units(16_500_000, 10_000_000_000)
+        .set_cost_models(pallas_fun::wrapper::CostModelsWrapper {
+            plutus_v1: None,
+            plutus_v2: None,
+            plutus_v3: Some(vec![1, 2, 3]),
+        })
+        .build()
+        .expect("should build");
+
+    let next = params.apply(&update);
+    assert_eq!(
+        next.max_tx_ex_units,
+        ExUnits {
+            mem: 16_500_000,
+            steps: 10_000_000_000,
+        }
+    );
+    // languages missing from the update are kept
+    assert_eq!(
+        next.cost_models_for_script_languages.plutus_v1,
+        params.cost_models_for_script_languages.plutus_v1
+    );
+    assert_eq!(
+        next.cost_models_for_script_languages.plutus_v3,
+        Some(vec![1, 2, 3])
+    );
+
+    assert_eq!(params.diff(&next), Ok(update));
+}
+
+#[test]
+fn test_protocol_params_missing_field() {
+    let json = r#"{ "txFeePerByte": 44 }"#;
+    assert!(ProtocolParams::from_cardano_cli_json(json).is_err());
+}
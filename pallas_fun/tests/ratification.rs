@@ -0,0 +1,218 @@
+use pallas_fun::governance::{
+    CommitteeMember, CommitteeSnapshot, DRepStake, GovernanceSnapshot, PoolStake,
+    simulate_ratification,
+};
+use pallas_fun::protocol_params::ProtocolParams;
+use pallas_fun::wrapper::{
+    AnchorWrapper, DRepKind, DRepWrapper, GovActionIdWrapper, GovActionKind, GovActionWrapper,
+    ProposalProcedureWrapper, RewardAccountWrapper, VoteKind, VoterKind, VoterWrapper,
+    VotingProcedureWrapper, VotingProceduresInput,
+};
+
+mod common;
+
+use common::{hash28, hash32};
+
+const REWARD_ACCOUNT: &str = "e1276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3";
+
+fn drep(n: u8) -> VoterWrapper {
+    VoterWrapper::new(VoterKind::DRepKey {
+        addr_key_hash: hash28(n),
+    })
+    .expect("should create voter")
+}
+
+fn pool(n: u8) -> VoterWrapper {
+    VoterWrapper::new(VoterKind::StakePoolKey {
+        addr_key_hash: hash28(n),
+    })
+    .expect("should create voter")
+}
+
+fn committee_member(n: u8) -> VoterWrapper {
+    VoterWrapper::new(VoterKind::ConstitutionalCommitteeKey {
+        script_hash: hash28(n),
+    })
+    .expect("should create voter")
+}
+
+fn proposal(index: u32, kind: GovActionKind) -> (GovActionIdWrapper, ProposalProcedureWrapper) {
+    let id = GovActionIdWrapper::new(&hash32(0xab), index).expect("should create gov action id");
+    let proposal = ProposalProcedureWrapper::new(
+        100_000_000_000,
+        RewardAccountWrapper::new(REWARD_ACCOUNT).expect("should create reward account"),
+        GovActionWrapper::new(kind).expect("should create gov action"),
+        AnchorWrapper::new(
+            "https://example.com/proposal.json".to_string(),
+            hash32(0x00),
+        )
+        .expect("should create anchor"),
+    )
+    .expect("should create proposal procedure");
+    (id, proposal)
+}
+
+fn vote(voter: VoterWrapper, index: u32, vote: VoteKind) -> VotingProceduresInput {
+    let id = GovActionIdWrapper::new(&hash32(0xab), index).expect("should create gov action id");
+    vec![(
+        voter,
+        vec![(
+            id,
+            VotingProcedureWrapper::new(vote, None).expect("should create voting procedure"),
+        )],
+    )]
+}
+
+fn snapshot() -> GovernanceSnapshot {
+    GovernanceSnapshot {
+        dreps: vec![
+            DRepStake {
+                drep: drep(1),
+                stake: 60,
+                active: true,
+            },
+            DRepStake {
+                drep: drep(2),
+                stake: 20,
+                active: true,
+            },
+            DRepStake {
+                drep: drep(3),
+                stake: 100,
+                active: false,
+            },
+        ],
+        always_abstain_stake: 50,
+        always_no_confidence_stake: 10,
+        pools: vec![
+            PoolStake {
+                pool: pool(11),
+                stake: 40,
+                reward_account_drep: None,
+            },
+            PoolStake {
+                pool: pool(12),
+                stake: 30,
+                reward_account_drep: Some(
+                    DRepWrapper::new(DRepKind::NoConfidence).expect("should create drep"),
+                ),
+            },
+            PoolStake {
+                pool: pool(13),
+                stake: 30,
+                reward_account_drep: Some(
+                    DRepWrapper::new(DRepKind::Abstain).expect("should create drep"),
+                ),
+            },
+        ],
+        committee: Some(CommitteeSnapshot {
+            members: vec![
+                CommitteeMember {
+                    hot: Some(committee_member(21)),
+                    expired: false,
+                },
+                CommitteeMember {
+                    hot: Some(committee_member(22)),
+                    expired: false,
+                },
+                CommitteeMember {
+                    hot: Some(committee_member(23)),
+                    expired: true,
+                },
+            ],
+            threshold: (2, 3),
+        }),
+    }
+}
+
+fn params() -> ProtocolParams {
+    let mut params = common::params();
+    params.min_committee_size = 2;
+    params
+}
+
+#[test]
+fn test_ratification_treasury_withdrawal() {
+    let proposals = vec![proposal(
+        0,
+        GovActionKind::TreasuryWithdrawals {
+            withdrawals: vec![(
+                RewardAccountWrapper::new(REWARD_ACCOUNT).expect("should create reward account"),
+                1_000,
+            )],
+            script_hash: None,
+        },
+    )];
+    let mut votes = [
+        vote(drep(1), 0, VoteKind::Yes),
+        vote(committee_member(21), 0, VoteKind::Yes),
+        vote(committee_member(22), 0, VoteKind::Yes),
+        // expired members abstain whatever they vote
+        vote(committee_member(23), 0, VoteKind::No),
+    ]
+    .concat();
+
+    // 60 yes against 20 from a DRep that did not vote and 10 always no
+    // confidence; the inactive DRep and always abstain do not count
+    let outcome = &simulate_ratification(&proposals, &votes, &snapshot(), &params())[0];
+    let drep_tally = outcome.drep.as_ref().expect("should have drep");
+    assert_eq!(
+        (drep_tally.yes, drep_tally.no, drep_tally.abstain),
+        (60, 30, 150)
+    );
+    assert!(!drep_tally.passed);
+    let committee = outcome.committee.as_ref().expect("should have committee");
+    assert_eq!((committee.yes, committee.no, committee.abstain), (2, 0, 1));
+    assert!(committee.passed);
+    assert_eq!(outcome.spo, None);
+    assert!(!outcome.ratified);
+
+    votes.extend(vote(drep(2), 0, VoteKind::Abstain));
+    let outcome = &simulate_ratification(&proposals, &votes, &snapshot(), &params())[0];
+    assert!(outcome.ratified);
+
+    let mut no_committee = snapshot();
+    no_committee.committee = None;
+    let outcome = &simulate_ratification(&proposals, &votes, &no_committee, &params())[0];
+    assert!(
+        !outcome
+            .committee
+            .as_ref()
+            .expect("should have committee")
+            .passed
+    );
+    assert!(!outcome.ratified);
+}
+
+#[test]
+fn test_ratification_no_confidence_and_info() {
+    let proposals = vec![
+        proposal(
+            0,
+            GovActionKind::NoConfidence {
+                gov_action_id_wrapper: None,
+            },
+        ),
+        proposal(1, GovActionKind::Information),
+    ];
+    let votes = [
+        vote(drep(1), 0, VoteKind::Yes),
+        vote(pool(11), 0, VoteKind::Yes),
+        vote(drep(1), 1, VoteKind::Yes),
+        vote(drep(2), 1, VoteKind::Yes),
+    ]
+    .concat();
+
+    let outcomes = simulate_ratification(&proposals, &votes, &snapshot(), &params());
+    let no_confidence = &outcomes[0];
+    assert_eq!(no_confidence.committee, None);
+    // always no confidence stake votes yes
+    let drep_tally = no_confidence.drep.as_ref().expect("should have drep");
+    assert_eq!((drep_tally.yes, drep_tally.no), (70, 20));
+    // pool 12 delegates to always no confidence, pool 13 to always abstain
+    let spo = no_confidence.spo.as_ref().expect("should have spo");
+    assert_eq!((spo.yes, spo.no, spo.abstain), (70, 0, 30));
+    assert!(no_confidence.ratified);
+
+    assert!(!outcomes[1].ratified);
+}
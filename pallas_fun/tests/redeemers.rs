@@ -0,0 +1,30 @@
+use pallas_fun::wrapper::{RedeemersEncoding, RedeemersWrapper};
+
+// [[0, 0, 121([]), [1, 2]]] in definite, indefinite and map form
+const LIST_DEF: &str = "81840000d87980820102";
+const LIST_INDEF: &str = "9f840000d87980820102ff";
+const MAP: &str = "a182000082d87980820102";
+
+#[test]
+fn test_redeemers_wrapper_round_trip_preserves_encoding() {
+    for (hex_string, encoding) in [
+        (LIST_DEF, RedeemersEncoding::List),
+        (LIST_INDEF, RedeemersEncoding::List),
+        (MAP, RedeemersEncoding::Map),
+    ] {
+        let wrapper = RedeemersWrapper::decode(hex_string.to_string()).expect("should decode");
+        assert_eq!(wrapper.encoding(), encoding);
+        assert_eq!(wrapper.encode().expect("should encode"), hex_string);
+    }
+}
+
+#[test]
+fn test_redeemers_wrapper_switch_encoding() {
+    let list = RedeemersWrapper::decode(LIST_DEF.to_string()).expect("should decode");
+    let map = list
+        .with_encoding(RedeemersEncoding::Map)
+        .expect("should convert");
+
+    assert_eq!(map.encode().expect("should encode"), MAP);
+    assert_eq!(map.redeemers(), list.redeemers());
+}
@@ -11,7 +11,7 @@ fn test_required_signers_wrapper_encode_decode() {
     ];
     let wrapper = RequiredSignersWrapper::new(keyhashes).expect("should create wrapper");
 
-    let encoded = wrapper.encode();
+    let encoded = wrapper.encode().expect("should encode");
     let decoded = RequiredSignersWrapper::decode(encoded).expect("should decode");
 
     assert_eq!(wrapper, decoded);
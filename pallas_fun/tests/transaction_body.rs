@@ -0,0 +1,224 @@
+use pallas_fun::error::PallasFunError;
+use pallas_fun::utils::IntoInner;
+use pallas_fun::wrapper::{
+    TransactionBodyBuilder, TransactionBodyWrapper, TransactionInputWrapper,
+    TransactionOutputWrapper, ValueWrapper,
+};
+
+fn input() -> TransactionInputWrapper {
+    TransactionInputWrapper::new(
+        "aabbccddeeff00112233445566778899aabbccddeeff00112233445566778899",
+        0,
+    )
+    .expect("valid input")
+}
+
+fn output() -> TransactionOutputWrapper {
+    TransactionOutputWrapper::new(
+        "61276fd18711931e2c0e21430192dbeac0e458093cd9d1fcd7210f64b3".to_string(),
+        ValueWrapper::new(2_000_000, None),
+        None,
+        None,
+    )
+    .expect("valid output")
+}
+
+fn body(
+    collateral: Option<Vec<TransactionInputWrapper>>,
+    donation: Option<u64>,
+) -> Result<TransactionBodyWrapper, PallasFunError> {
+    TransactionBodyWrapper::new(
+        vec![input()],
+        vec![output()],
+        200_000,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        collateral,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        donation,
+    )
+}
+
+#[test]
+fn test_transaction_body_wrapper_encode_decode() {
+    let wrapper = body(Some(vec![input()]), Some(1)).expect("should create wrapper");
+
+    let encoded = wrapper.encode().expect("should encode");
+    let decoded = TransactionBodyWrapper::decode(encoded).expect("should decode");
+
+    assert_eq!(wrapper, decoded);
+}
+
+#[test]
+fn test_transaction_body_wrapper_empty_set_and_zero_donation() {
+    assert_eq!(
+        body(Some(vec![]), None),
+        Err(PallasFunError::EmptySet("collateral"))
+    );
+    assert_eq!(
+        body(None, Some(0)),
+        Err(PallasFunError::ZeroAmount("donation"))
+    );
+}
+
+#[test]
+fn test_transaction_body_wrapper_empty_withdrawals_path() {
+    let err = TransactionBodyWrapper::new(
+        vec![input()],
+        vec![output()],
+        200_000,
+        None,
+        None,
+        Some(vec![]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err.path(), Some("withdrawals"));
+    assert_eq!(err.root_cause(), &PallasFunError::EmptySet("withdrawals"));
+}
+
+#[test]
+fn test_transaction_body_builder_matches_new() {
+    let built = TransactionBodyBuilder::new()
+        .add_input(input())
+        .add_output(output())
+        .set_fee(200_000)
+        .add_collateral(input())
+        .set_donation(1)
+        .build()
+        .expect("should build");
+
+    assert_eq!(Ok(built), body(Some(vec![input()]), Some(1)));
+}
+
+#[test]
+fn test_transaction_body_builder_validation() {
+    assert_eq!(
+        TransactionBodyBuilder::new().add_output(output()).build(),
+        Err(PallasFunError::EmptySet("inputs"))
+    );
+
+    let duplicate = TransactionBodyBuilder::new()
+        .add_input(input())
+        .add_input(input())
+        .build()
+        .unwrap_err();
+    assert_eq!(duplicate.path(), Some("inputs"));
+    assert!(matches!(
+        duplicate.root_cause(),
+        PallasFunError::DuplicateEntry { kind: "input", .. }
+    ));
+
+    assert!(matches!(
+        TransactionBodyBuilder::new()
+            .add_input(input())
+            .set_collateral_return(output())
+            .build(),
+        Err(PallasFunError::InconsistentCollateral(_))
+    ));
+
+    let zero_mint = TransactionBodyBuilder::new()
+        .add_input(input())
+        .add_mint(
+            "00000000000000000000000000000000000000000000000000000000",
+            "aa",
+            0,
+        )
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        zero_mint.root_cause(),
+        &PallasFunError::ZeroAmount("mint amount")
+    );
+}
+
+#[test]
+fn test_transaction_body_builder_collateral_and_reference_inputs() {
+    let overlap = TransactionBodyBuilder::new()
+        .add_input(input())
+        .add_reference_input(input())
+        .build()
+        .unwrap_err();
+    assert_eq!(overlap.path(), Some("reference_inputs"));
+    assert!(matches!(
+        overlap.root_cause(),
+        PallasFunError::DuplicateEntry { kind: "input", .. }
+    ));
+
+    let collateral = || {
+        TransactionBodyBuilder::new()
+            .add_input(input())
+            .set_fee(200_000)
+            .add_collateral(input())
+    };
+    assert_eq!(
+        collateral().set_collateral_return(output()).build(),
+        Err(PallasFunError::InconsistentCollateral(
+            "collateral return without total collateral"
+        ))
+    );
+    assert_eq!(
+        collateral().set_total_collateral(199_999).build(),
+        Err(PallasFunError::InconsistentCollateral(
+            "total collateral below the fee"
+        ))
+    );
+    assert!(
+        collateral()
+            .set_collateral_return(output())
+            .set_total_collateral(300_000)
+            .build()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_transaction_body_builder_mint_policy_case() {
+    let mixed_case = || {
+        TransactionBodyBuilder::new()
+            .add_input(input())
+            .add_mint(&"AB".repeat(28), "AA", 1)
+    };
+    let built = mixed_case()
+        .add_mint(&"ab".repeat(28), "bb", 2)
+        .build()
+        .expect("should build");
+    assert_eq!(built.into_inner().mint.expect("should mint").len(), 1);
+
+    let duplicate = mixed_case()
+        .add_mint(&"ab".repeat(28), "aa", 2)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        duplicate,
+        PallasFunError::DuplicateEntry {
+            kind: "mint asset",
+            entry: format!("{}.aa", "ab".repeat(28)),
+        }
+    );
+}